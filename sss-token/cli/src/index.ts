@@ -146,13 +146,12 @@ program
   .option("--symbol <symbol>", "Token symbol")
   .option("--decimals <decimals>", "Token decimals", "6")
   .option("--uri <uri>", "Metadata URI")
-  .option("--mint <mint>", "Existing mint address (optional)")
   .option("-y, --yes", "Skip confirmation prompts", false)
   .action(async (options) => {
     try {
       spinner.start("Initializing stablecoin...");
       
-      const { sdk, connection, wallet } = await getSDK();
+      const { sdk } = await getSDK();
       
       let initParams: {
         name: string;
@@ -216,32 +215,13 @@ program
         initParams.uri = answers.uri;
       }
       
-      spinner.text = "Creating mint...";
-      
-      // Create mint or use existing
-      let mint: PublicKey;
-      if (options.mint) {
-        mint = new PublicKey(options.mint);
-      } else {
-        // Create new mint with Token-2022
-        const { createMint } = await import("@solana/spl-token");
-        const keypair = loadKeypair(config.keypairPath);
-        mint = await createMint(
-          connection,
-          keypair,
-          wallet.publicKey,
-          wallet.publicKey, // freeze authority
-          initParams.decimals,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
-      }
-      
-      spinner.text = "Initializing stablecoin config...";
+      spinner.text = "Initializing stablecoin...";
       
+      // The program creates the mint, so it must be a fresh keypair
+      const mintKeypair = Keypair.generate();
+      const mint = mintKeypair.publicKey;
       const keypair = loadKeypair(config.keypairPath);
-      const tx = await sdk.initialize(mint, keypair, initParams);
+      const tx = await sdk.initialize(mintKeypair, keypair, initParams);
       
       // Save config
       const configPath = path.join(process.cwd(), ".sss-token.json");
@@ -298,15 +278,13 @@ program
         TOKEN_2022_PROGRAM_ID
       );
       
-      // Get minter info
+      // The minter signs; the mint authority is the program PDA
       const minterKeypair = options.minter ? loadKeypair(options.minter) : loadKeypair(config.keypairPath);
       
       spinner.text = "Minting tokens...";
-      const keypair = loadKeypair(config.keypairPath);
       const tx = await sdk.mintTokens(
         mint,
-        keypair,
-        minterKeypair.publicKey,
+        minterKeypair,
         tokenAccount.address,
         { amount }
       );
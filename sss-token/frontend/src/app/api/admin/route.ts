@@ -154,7 +154,7 @@ export async function POST(request: NextRequest) {
         if (!targetAddress || !amount) return NextResponse.json({ success: false, error: 'targetAddress and amount required' }, { status: 400 });
         const tokenAccount = await getOrCreateAssociatedTokenAccount(connection, authorityKeypair, mint, new PublicKey(targetAddress), undefined, undefined, undefined, TOKEN_2022_PROGRAM_ID);
         const minterKeypair = loadKeypair('minter');
-        signature = await sdk.mintTokens(mint, authorityKeypair, minterKeypair.publicKey, tokenAccount.address, { amount: new BN(amount) });
+        signature = await sdk.mintTokens(mint, minterKeypair, tokenAccount.address, { amount: new BN(amount) });
         break;
       }
       case 'burn': {
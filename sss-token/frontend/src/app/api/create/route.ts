@@ -3,17 +3,7 @@ import {
   Connection,
   Keypair,
   PublicKey,
-  Transaction,
-  SystemProgram,
-  sendAndConfirmTransaction,
 } from '@solana/web3.js';
-import {
-  TOKEN_2022_PROGRAM_ID,
-  ExtensionType,
-  getMintLen,
-  createInitializePermanentDelegateInstruction,
-  createInitializeMintInstruction,
-} from '@solana/spl-token';
 import { AnchorProvider } from '@coral-xyz/anchor';
 import path from 'path';
 import fs from 'fs';
@@ -64,7 +54,7 @@ export async function POST(request: NextRequest) {
   try {
     // Load SDK dynamically
     const sdkModule = loadSDK();
-    const { SSSTokenClient, findPermanentDelegatePDA, findFreezeAuthorityPDA, SSS_TOKEN_PROGRAM_ID } = sdkModule;
+    const { SSSTokenClient } = sdkModule;
     
     if (!SSSTokenClient) {
       return NextResponse.json(
@@ -125,79 +115,18 @@ export async function POST(request: NextRequest) {
       }
     }
 
-    // Create mint keypair
+    // Create mint keypair (the program creates the mint during initialize)
     const mintKeypair = Keypair.generate();
-    const programId = new PublicKey(SSS_TOKEN_PROGRAM_ID);
-
-    // Derive PDAs for SSS-2
-    const { pda: permanentDelegate } = enablePermanentDelegate 
-      ? findPermanentDelegatePDA(mintKeypair.publicKey, programId)
-      : { pda: undefined };
-    
-    const { pda: freezeAuthority } = preset === 'sss-2'
-      ? findFreezeAuthorityPDA(mintKeypair.publicKey, programId)
-      : { pda: authority.publicKey };
-
     console.log(`Creating mint: ${mintKeypair.publicKey.toString()}`);
     console.log(`Preset: ${preset}, Permanent Delegate: ${enablePermanentDelegate}`);
 
-    // Build mint creation transaction
-    const extensions = enablePermanentDelegate ? [ExtensionType.PermanentDelegate] : [];
-    const mintLen = getMintLen(extensions);
-    const lamports = await connection.getMinimumBalanceForRentExemption(mintLen);
-
-    const transaction = new Transaction();
-
-    // Create mint account
-    transaction.add(
-      SystemProgram.createAccount({
-        fromPubkey: authority.publicKey,
-        newAccountPubkey: mintKeypair.publicKey,
-        space: mintLen,
-        lamports,
-        programId: TOKEN_2022_PROGRAM_ID,
-      })
-    );
-
-    // Initialize permanent delegate extension if enabled
-    if (enablePermanentDelegate && permanentDelegate) {
-      transaction.add(
-        createInitializePermanentDelegateInstruction(
-          mintKeypair.publicKey,
-          permanentDelegate,
-          TOKEN_2022_PROGRAM_ID
-        )
-      );
-    }
-
-    // Initialize mint
-    transaction.add(
-      createInitializeMintInstruction(
-        mintKeypair.publicKey,
-        decimals,
-        authority.publicKey, // mint authority
-        preset === 'sss-2' ? freezeAuthority! : authority.publicKey, // freeze authority
-        TOKEN_2022_PROGRAM_ID
-      )
-    );
-
-    // Send transaction
-    const mintSignature = await sendAndConfirmTransaction(
-      connection,
-      transaction,
-      [authority, mintKeypair],
-      { commitment: 'confirmed' }
-    );
-
-    console.log(`Mint created: ${mintSignature}`);
-
     // Initialize stablecoin config via SDK
     const wallet = new NodeWallet(authority);
     const provider = new AnchorProvider(connection, wallet as any, {});
     const sdk = new SSSTokenClient({ provider });
 
     const initSignature = await sdk.initialize(
-      mintKeypair.publicKey,
+      mintKeypair,
       authority,
       {
         name,
@@ -1,10 +1,73 @@
+// Anchor generates CPI helpers with one parameter per instruction argument
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::{self, MintTo, Burn as BurnCpi, FreezeAccount as FreezeAccountCpi, ThawAccount as ThawAccountCpi, TransferChecked, Token2022};
-use anchor_spl::token_interface::{Mint, TokenAccount};
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::token_2022::{self, InitializeMint2, MintTo, Burn as BurnCpi, FreezeAccount as FreezeAccountCpi, ThawAccount as ThawAccountCpi, Token2022};
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::{Field, TokenMetadata};
+use anchor_spl::token_interface::{
+    self as token_interface, DefaultAccountStateInitialize, MetadataPointerInitialize, Mint,
+    PermanentDelegateInitialize, TokenAccount, TokenMetadataInitialize, TokenMetadataUpdateField,
+    TransferHookInitialize,
+};
+use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
+use spl_token_2022::onchain::invoke_transfer_checked;
+use spl_token_2022::state::{AccountState, Mint as MintState};
 
 // Program ID
 declare_id!("Hf1s4EvjS79S6kcHdKhaZHVQsnsjqMbJgBEFZfaGDPmw");
 
+// Pause bitmask flags (StablecoinConfig.pause_mask)
+pub const PAUSE_MINT: u8 = 1 << 0;
+pub const PAUSE_BURN: u8 = 1 << 1;
+pub const PAUSE_TRANSFER: u8 = 1 << 2;
+pub const PAUSE_SEIZE: u8 = 1 << 3;
+pub const PAUSE_BLACKLIST: u8 = 1 << 4;
+pub const PAUSE_FREEZE: u8 = 1 << 5;
+// Freezing is a compliance response, so a blanket pause leaves it available
+pub const PAUSE_ALL: u8 = PAUSE_MINT | PAUSE_BURN | PAUSE_TRANSFER | PAUSE_SEIZE | PAUSE_BLACKLIST;
+pub const PAUSE_FLAGS: u8 = PAUSE_ALL | PAUSE_FREEZE;
+
+/// Transfer hook program that SSS-2 mints are pointed at
+pub const TRANSFER_HOOK_PROGRAM_ID: Pubkey = pubkey!("8Zaw1qJer6QPw15yg2bBgkAEB5rP73QisHVv6djANpSB");
+
+// Limits for custom TokenMetadata fields (additional_metadata)
+pub const MAX_METADATA_FIELDS: usize = 16;
+pub const MAX_METADATA_KEY_LEN: usize = 32;
+pub const MAX_METADATA_VALUE_LEN: usize = 200;
+
+/// Maximum number of users per batch blacklist instruction
+pub const MAX_BLACKLIST_BATCH: usize = 20;
+
+/// Maximum seizure case id length (used as a PDA seed)
+pub const MAX_CASE_ID_LEN: usize = 32;
+
+/// Maximum number of source accounts per `seize_many`
+pub const MAX_SEIZE_ACCOUNTS: usize = 10;
+
+/// Layout version stamped on every program account
+/// Accounts created before versioning read back as version 0 and must go through `migrate_config`
+pub const ACCOUNT_VERSION: u8 = 1;
+
+/// Longest delay that can be configured for queued admin actions (30 days)
+pub const MAX_ADMIN_DELAY: i64 = 30 * 24 * 60 * 60;
+
+// Limits for the admin council and the instructions its proposals carry
+pub const MAX_COUNCIL_MEMBERS: usize = 10;
+pub const MAX_PROPOSAL_ACCOUNTS: usize = 16;
+pub const MAX_PROPOSAL_DATA_LEN: usize = 512;
+pub const MAX_COUNCIL_PROPOSAL_TTL: i64 = 30 * 24 * 60 * 60;
+
+/// Basis-point denominator for `collateral_ratio_bps` (10_000 = reserves equal supply)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+// Limits for mint requests
+pub const MAX_MINT_APPROVALS: usize = 5;
+pub const MAX_FIAT_REFERENCE_LEN: usize = 32; // used as a PDA seed
+pub const MAX_MINT_REQUEST_TTL: i64 = 7 * 24 * 60 * 60;
+
 // ============================================
 // ERROR DEFINITIONS
 // ============================================
@@ -31,6 +94,60 @@ pub enum StablecoinError {
     NotBlacklisted,
     #[msg("Invalid amount")]
     InvalidAmount,
+    #[msg("Minter period allowance exceeded")]
+    PeriodAllowanceExceeded,
+    #[msg("Max supply exceeded")]
+    SupplyCapExceeded,
+    #[msg("Burner is not active")]
+    BurnerInactive,
+    #[msg("Burn limit exceeded")]
+    BurnLimitExceeded,
+    #[msg("No pending authority transfer")]
+    NoPendingAuthority,
+    #[msg("Invalid pause mask")]
+    InvalidPauseMask,
+    #[msg("Too many custom metadata fields")]
+    TooManyMetadataFields,
+    #[msg("Metadata field not found")]
+    MetadataFieldNotFound,
+    #[msg("Blacklist entry has not expired")]
+    BlacklistEntryNotExpired,
+    #[msg("Seizure requires a blacklisted owner or a frozen source account")]
+    SeizureNotPermitted,
+    #[msg("Destination is not the configured treasury")]
+    InvalidTreasury,
+    #[msg("Release exceeds the escrowed amount for this case")]
+    ReleaseExceedsEscrow,
+    #[msg("Account layout is out of date - run migrate_config")]
+    OutdatedAccountVersion,
+    #[msg("Admin delay is set - this change must go through queue_admin_action")]
+    TimelockRequired,
+    #[msg("Admin action delay has not elapsed")]
+    TimelockNotElapsed,
+    #[msg("Council proposal has not reached its approval threshold")]
+    ThresholdNotMet,
+    #[msg("Council member has already approved this proposal")]
+    AlreadyApproved,
+    #[msg("Reserve attestation is missing or stale")]
+    StaleAttestation,
+    #[msg("Mint would exceed attested reserves")]
+    ReservesExceeded,
+    #[msg("Minting must go through an approved mint request")]
+    MintRequestRequired,
+    #[msg("Mint request has expired")]
+    MintRequestExpired,
+    #[msg("Mint request has not expired")]
+    MintRequestNotExpired,
+    #[msg("Burner is already active")]
+    BurnerAlreadyActive,
+    #[msg("Fiat reference has already backed an executed mint request")]
+    FiatReferenceUsed,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Council proposal has expired")]
+    CouncilProposalExpired,
+    #[msg("Instruction cannot be executed through a council proposal")]
+    ProposalInstructionNotAllowed,
 }
 
 // ============================================
@@ -47,7 +164,7 @@ pub struct StablecoinConfig {
     pub symbol: String,
     pub uri: String,
     pub decimals: u8,
-    pub paused: bool,
+    pub pause_mask: u8, // PAUSE_* flags
     pub bump: u8,
 
     // Module flags
@@ -59,6 +176,34 @@ pub struct StablecoinConfig {
     pub blacklister: Pubkey,
     pub pauser: Pubkey,
     pub seizer: Pubkey,
+
+    // Issuance ceiling (0 = uncapped)
+    pub max_supply: u64,
+
+    // Token account for ReleaseTarget::Treasury releases (default = unset)
+    pub treasury: Pubkey,
+
+    // Layout version (ACCOUNT_VERSION) and space for future fields
+    pub version: u8,
+
+    // Timelock for sensitive admin changes (0 = changes apply immediately)
+    pub admin_delay: i64,
+    pub guardian: Pubkey, // May cancel queued admin actions (default = unset)
+    pub next_admin_action_id: u64,
+
+    // Proof-of-reserves gating for minting
+    pub reserve_gating: bool,
+    pub max_attestation_age: i64, // seconds, 0 = no staleness limit
+    pub collateral_ratio_bps: u16, // 0 is treated as BPS_DENOMINATOR (1:1)
+
+    // Mint request workflow
+    pub mint_approvals_required: u8, // 0 is treated as 1
+    pub mint_requests_only: bool, // Disables mint_tokens
+
+    // Two-step master authority handover (see propose_master_authority)
+    pub pending_master_authority: Option<Pubkey>,
+
+    pub reserved: [u8; 3],
 }
 
 impl StablecoinConfig {
@@ -69,17 +214,107 @@ impl StablecoinConfig {
         + 4 + 10  // symbol (max 10 chars)
         + 4 + 200 // uri (max 200 chars)
         + 1  // decimals
-        + 1  // paused
+        + 1  // pause_mask
         + 1  // bump
         + 1  // enable_permanent_delegate
         + 1  // enable_transfer_hook
         + 1  // default_account_frozen
         + 32 // blacklister
         + 32 // pauser
-        + 32; // seizer
+        + 32 // seizer
+        + 8  // max_supply
+        + 32 // treasury
+        + 1  // version
+        + 8  // admin_delay
+        + 32 // guardian
+        + 8  // next_admin_action_id
+        + 1  // reserve_gating
+        + 8  // max_attestation_age
+        + 2  // collateral_ratio_bps
+        + 1  // mint_approvals_required
+        + 1  // mint_requests_only
+        + 1 + 32 // pending_master_authority
+        + 3; // reserved
+
+    /// Whether any of the given PAUSE_* flags is set
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.pause_mask & flags != 0
+    }
+
+    /// Whether sensitive admin changes must be queued instead of applied directly
+    pub fn is_timelocked(&self) -> bool {
+        self.admin_delay > 0
+    }
+
+    /// Whether `new_max_supply` loosens the current cap (0 = uncapped)
+    pub fn raises_max_supply(&self, new_max_supply: u64) -> bool {
+        self.max_supply != 0 && (new_max_supply == 0 || new_max_supply > self.max_supply)
+    }
+
+    /// Approvals a mint request needs before it can be executed
+    pub fn mint_approval_threshold(&self) -> usize {
+        self.mint_approvals_required.max(1) as usize
+    }
+
+    /// Check a mint approval policy before it is applied
+    pub fn validate_mint_approval_policy(approvals_required: u8) -> Result<()> {
+        require!(
+            approvals_required > 0 && approvals_required as usize <= MAX_MINT_APPROVALS,
+            StablecoinError::InvalidAmount
+        );
+        Ok(())
+    }
+
+    /// Check a proof-of-reserves policy before it is applied
+    pub fn validate_reserve_policy(max_attestation_age: i64, collateral_ratio_bps: u16) -> Result<()> {
+        require!(max_attestation_age >= 0, StablecoinError::InvalidAmount);
+        require!(
+            collateral_ratio_bps == 0 || collateral_ratio_bps as u64 >= BPS_DENOMINATOR,
+            StablecoinError::InvalidAmount
+        );
+        Ok(())
+    }
+
+    /// Enforce proof-of-reserves on a mint that takes supply from `supply` to `supply + amount`
+    /// No-op unless `reserve_gating` is on
+    pub fn check_reserves(
+        &self,
+        attestation: Option<&ReserveAttestation>,
+        supply: u64,
+        amount: u64,
+        now: i64,
+    ) -> Result<()> {
+        if !self.reserve_gating {
+            return Ok(());
+        }
+
+        let attestation = attestation.ok_or(StablecoinError::StaleAttestation)?;
+        require!(
+            !attestation.is_stale(now, self.max_attestation_age),
+            StablecoinError::StaleAttestation
+        );
+
+        let new_supply = supply.checked_add(amount).ok_or(StablecoinError::ReservesExceeded)?;
+        require!(
+            new_supply <= attestation.backed_supply(self.collateral_ratio_bps),
+            StablecoinError::ReservesExceeded
+        );
+        Ok(())
+    }
+}
+
+/// Clock source used to measure a minter's allowance window
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AllowancePeriod {
+    /// Window length is measured in seconds of `Clock::unix_timestamp`
+    Seconds,
+    /// Window length is measured in `Clock::epoch`
+    Epochs,
 }
 
 /// Minter information with quota tracking
+/// `quota`/`minted` form the lifetime cap; `period_limit`/`period_minted` form an
+/// optional allowance that resets every `period_length` seconds or epochs
 /// PDA seeds: ["minter", config.key(), minter_authority.key()]
 #[account]
 pub struct MinterInfo {
@@ -87,6 +322,16 @@ pub struct MinterInfo {
     pub quota: u64,
     pub minted: u64,
     pub bump: u8,
+
+    // Period allowance (disabled when period_limit == 0)
+    pub period: AllowancePeriod,
+    pub period_length: u64,
+    pub period_limit: u64,
+    pub period_minted: u64,
+    pub period_start: u64,
+
+    pub version: u8,
+    pub reserved: [u8; 32],
 }
 
 impl MinterInfo {
@@ -94,10 +339,161 @@ impl MinterInfo {
         + 32 // authority
         + 8  // quota
         + 8  // minted
-        + 1; // bump
+        + 1  // bump
+        + 1  // period
+        + 8  // period_length
+        + 8  // period_limit
+        + 8  // period_minted
+        + 8  // period_start
+        + 1  // version
+        + 32; // reserved
+
+    /// Whether a period allowance is configured
+    pub fn has_period_allowance(&self) -> bool {
+        self.period_limit > 0 && self.period_length > 0
+    }
+
+    /// Current position on the clock the allowance window is measured in
+    pub fn period_now(&self, clock: &Clock) -> u64 {
+        match self.period {
+            AllowancePeriod::Seconds => clock.unix_timestamp.max(0) as u64,
+            AllowancePeriod::Epochs => clock.epoch,
+        }
+    }
+
+    /// Whether replacing the current allowance with the given one could let the minter
+    /// mint more within a window: lifting or widening it, shortening the window, or
+    /// switching clocks. Adding an allowance where there was none never loosens.
+    pub fn loosens_allowance(&self, period: AllowancePeriod, period_length: u64, period_limit: u64) -> bool {
+        if !self.has_period_allowance() {
+            return false;
+        }
+        period_limit == 0
+            || period != self.period
+            || period_limit > self.period_limit
+            || period_length < self.period_length
+    }
+
+    /// Replace the period allowance; the new window starts now with `period_minted` used
+    pub fn set_allowance(
+        &mut self,
+        period: AllowancePeriod,
+        period_length: u64,
+        period_limit: u64,
+        period_minted: u64,
+        clock: &Clock,
+    ) {
+        self.period = period;
+        self.period_length = period_length;
+        self.period_limit = period_limit;
+        self.period_minted = period_minted;
+        self.period_start = self.period_now(clock);
+    }
+
+    /// Start a new allowance window if the current one has elapsed
+    /// Windows stay aligned to the original `period_start`
+    pub fn roll_period(&mut self, clock: &Clock) {
+        if !self.has_period_allowance() {
+            return;
+        }
+
+        let now = self.period_now(clock);
+        let elapsed = now.saturating_sub(self.period_start);
+        if elapsed >= self.period_length {
+            self.period_start = now - elapsed % self.period_length;
+            self.period_minted = 0;
+        }
+    }
+}
+
+/// Role kinds that can be granted through RoleAssignment PDAs
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoleKind {
+    Blacklister,
+    Pauser,
+    Seizer,
+    Metadata,
+    Legal,
+    Attestor,
+    MintApprover,
+}
+
+impl RoleKind {
+    /// Seed byte used in the RoleAssignment PDA
+    pub fn seed(&self) -> &'static [u8] {
+        match self {
+            RoleKind::Blacklister => &[0],
+            RoleKind::Pauser => &[1],
+            RoleKind::Seizer => &[2],
+            RoleKind::Metadata => &[3],
+            RoleKind::Legal => &[4],
+            RoleKind::Attestor => &[5],
+            RoleKind::MintApprover => &[6],
+        }
+    }
+}
+
+/// Grant of a role to a single holder
+/// The legacy role fields on StablecoinConfig are still honoured alongside these
+/// PDA seeds: ["role", config.key(), role_kind, holder.key()]
+#[account]
+pub struct RoleAssignment {
+    pub config: Pubkey,
+    pub role: RoleKind,
+    pub holder: Pubkey,
+    pub granted_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 32],
+}
+
+impl RoleAssignment {
+    pub const LEN: usize = 8  // discriminator
+        + 32 // config
+        + 1  // role
+        + 32 // holder
+        + 8  // granted_at
+        + 1  // bump
+        + 1  // version
+        + 32; // reserved
+}
+
+/// Burner information with burn limit and redemption accounting
+/// PDA seeds: ["burner", config.key(), burner_authority.key()]
+#[account]
+pub struct BurnerInfo {
+    pub authority: Pubkey,
+    pub burn_limit: Option<u64>, // None = unlimited
+    pub burned: u64,
+    pub active: bool,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 32],
+}
+
+impl BurnerInfo {
+    pub const LEN: usize = 8  // discriminator
+        + 32 // authority
+        + 1 + 8 // burn_limit
+        + 8  // burned
+        + 1  // active
+        + 1  // bump
+        + 1  // version
+        + 32; // reserved
+}
+
+/// Structured reason for a blacklist entry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlacklistReason {
+    Sanctions,
+    CourtOrder,
+    Fraud,
+    InternalReview,
+    Other,
 }
 
 /// Blacklist entry for SSS-2 compliance
+/// An entry with `expires_at` in the past no longer blocks transfers and can be purged by anyone
 /// PDA seeds: ["blacklist", config.key(), user_address.key()]
 #[account]
 pub struct BlacklistEntry {
@@ -105,6 +501,12 @@ pub struct BlacklistEntry {
     pub reason: String,
     pub timestamp: i64,
     pub bump: u8,
+    pub reason_code: BlacklistReason,
+    pub case_id: String,
+    pub added_by: Pubkey,
+    pub expires_at: Option<i64>, // None = until removed
+    pub version: u8,
+    pub reserved: [u8; 32],
 }
 
 impl BlacklistEntry {
@@ -112,775 +514,5900 @@ impl BlacklistEntry {
         + 32 // user
         + 4 + 100 // reason (max 100 chars)
         + 8  // timestamp
-        + 1; // bump
+        + 1  // bump
+        + 1  // reason_code
+        + 4 + 64 // case_id (max 64 chars)
+        + 32 // added_by
+        + 1 + 8 // expires_at
+        + 1  // version
+        + 32; // reserved
+
+    /// Whether the entry has lapsed at `now` (unix timestamp)
+    pub fn is_expired(&self, now: i64) -> bool {
+        matches!(self.expires_at, Some(expires_at) if expires_at <= now)
+    }
 }
 
-// ============================================
-// INSTRUCTIONS
-// ============================================
+/// Record of funds seized into escrow for a legal case
+/// PDA seeds: ["seizure_case", config.key(), case_id]
+#[account]
+pub struct SeizureCase {
+    pub config: Pubkey,
+    pub case_id: String,
+    pub source_owner: Pubkey,
+    pub source_token: Pubkey,
+    pub seizer: Pubkey,
+    pub amount: u64,
+    pub released: u64,
+    pub seized_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 32],
+}
 
-#[program]
-pub mod sss_token {
-    use super::*;
+impl SeizureCase {
+    pub const LEN: usize = 8  // discriminator
+        + 32 // config
+        + 4 + 32 // case_id (max 32 chars)
+        + 32 // source_owner
+        + 32 // source_token
+        + 32 // seizer
+        + 8  // amount
+        + 8  // released
+        + 8  // seized_at
+        + 1  // bump
+        + 1  // version
+        + 32; // reserved
+
+    /// Amount still held in escrow for this case
+    pub fn remaining(&self) -> Result<u64> {
+        self.amount
+            .checked_sub(self.released)
+            .ok_or_else(|| error!(StablecoinError::MathOverflow))
+    }
+}
 
-    /// Initialize a new stablecoin with specified configuration
-    pub fn initialize(
-        ctx: Context<Initialize>,
-        name: String,
-        symbol: String,
-        uri: String,
-        decimals: u8,
-        enable_permanent_delegate: bool,
-        enable_transfer_hook: bool,
-        default_account_frozen: bool,
-    ) -> Result<()> {
-        let config = &mut ctx.accounts.config;
-        let _clock = Clock::get()?;
+/// Where `release_seized_funds` pays escrowed funds
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReleaseTarget {
+    /// The configured treasury (`set_treasury`)
+    Treasury,
+    /// Any other token account of the mint, e.g. a court-ordered return
+    Claimant,
+}
 
-        require!(name.len() <= 100, StablecoinError::InvalidAccount);
-        require!(symbol.len() <= 10, StablecoinError::InvalidAccount);
-        require!(uri.len() <= 200, StablecoinError::InvalidAccount);
+/// Latest attested fiat reserves backing the token, posted by an Attestor role holder
+/// `reserves` is in the mint's base units
+/// PDA seeds: ["reserves", config.key()]
+#[account]
+pub struct ReserveAttestation {
+    pub config: Pubkey,
+    pub reserves: u64,
+    pub as_of: i64, // When the reserves were measured
+    pub report_uri_hash: [u8; 32], // Hash of the off-chain attestation report
+    pub attestor: Pubkey,
+    pub posted_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 32],
+}
 
-        config.master_authority = ctx.accounts.authority.key();
-        config.mint = ctx.accounts.mint.key();
-        config.name = name;
-        config.symbol = symbol;
-        config.uri = uri;
-        config.decimals = decimals;
-        config.paused = false;
-        config.bump = ctx.bumps.config;
+impl ReserveAttestation {
+    pub const LEN: usize = 8  // discriminator
+        + 32 // config
+        + 8  // reserves
+        + 8  // as_of
+        + 32 // report_uri_hash
+        + 32 // attestor
+        + 8  // posted_at
+        + 1  // bump
+        + 1  // version
+        + 32; // reserved
+
+    /// Check that a new attestation measured at `as_of` may replace this one at `now`
+    /// It must not be dated in the future and must be strictly newer
+    pub fn validate_update(&self, as_of: i64, now: i64) -> Result<()> {
+        require!(as_of <= now, StablecoinError::InvalidAmount);
+        require!(as_of > self.as_of, StablecoinError::StaleAttestation);
+        Ok(())
+    }
 
-        config.enable_permanent_delegate = enable_permanent_delegate;
-        config.enable_transfer_hook = enable_transfer_hook;
-        config.default_account_frozen = default_account_frozen;
+    /// Whether the attestation is older than `max_age` seconds at `now` (0 = never stale)
+    pub fn is_stale(&self, now: i64, max_age: i64) -> bool {
+        max_age > 0 && now.saturating_sub(self.as_of) > max_age
+    }
 
-        config.blacklister = ctx.accounts.authority.key();
-        config.pauser = ctx.accounts.authority.key();
-        config.seizer = ctx.accounts.authority.key();
+    /// Highest supply the reserves cover at `collateral_ratio_bps`
+    pub fn backed_supply(&self, collateral_ratio_bps: u16) -> u64 {
+        let ratio = (collateral_ratio_bps as u64).max(BPS_DENOMINATOR);
+        (self.reserves as u128 * BPS_DENOMINATOR as u128 / ratio as u128) as u64
+    }
+}
 
-        msg!("Stablecoin initialized: {}", config.symbol);
-        Ok(())
+/// Issuance request that mints once enough MintApprover role holders sign off
+/// `fiat_reference` identifies the incoming fiat payment; once a request is executed its
+/// reference is recorded in a UsedFiatReference PDA, so it can back at most one issuance
+/// PDA seeds: ["mint_request", config.key(), fiat_reference]
+#[account]
+pub struct MintRequest {
+    pub config: Pubkey,
+    pub minter: Pubkey,
+    pub recipient: Pubkey, // Token account to mint into
+    pub amount: u64,
+    pub fiat_reference: String,
+    pub approvals: Vec<Pubkey>,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 32],
+}
+
+impl MintRequest {
+    pub const LEN: usize = 8  // discriminator
+        + 32 // config
+        + 32 // minter
+        + 32 // recipient
+        + 8  // amount
+        + 4 + MAX_FIAT_REFERENCE_LEN // fiat_reference
+        + 4 + 32 * MAX_MINT_APPROVALS // approvals
+        + 8  // created_at
+        + 8  // expires_at
+        + 1  // bump
+        + 1  // version
+        + 32; // reserved
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
     }
 
-    /// Mint tokens to a recipient account
-    pub fn mint_tokens(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
-        let config = &ctx.accounts.config;
-        let minter_info = &mut ctx.accounts.minter_info;
+    /// Recorded approvals whose approver still holds the MintApprover role
+    pub fn live_approvals(&self, current_approvers: &[Pubkey]) -> Vec<Pubkey> {
+        self.approvals
+            .iter()
+            .filter(|approver| current_approvers.contains(approver))
+            .copied()
+            .collect()
+    }
+}
 
-        require!(!config.paused, StablecoinError::TokenPaused);
-        require!(
-            minter_info.minted + amount <= minter_info.quota,
-            StablecoinError::QuotaExceeded
-        );
+/// Record of a fiat reference consumed by an executed mint request
+/// Outlives the closed MintRequest so the same payment cannot be minted against twice
+/// PDA seeds: ["fiat_reference", config.key(), fiat_reference]
+#[account]
+pub struct UsedFiatReference {
+    pub config: Pubkey,
+    pub minter: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub executed_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 32],
+}
 
-        minter_info.minted += amount;
+impl UsedFiatReference {
+    pub const LEN: usize = 8  // discriminator
+        + 32 // config
+        + 32 // minter
+        + 32 // recipient
+        + 8  // amount
+        + 8  // executed_at
+        + 1  // bump
+        + 1  // version
+        + 32; // reserved
+}
 
-        let cpi_accounts = MintTo {
-            mint: ctx.accounts.mint.to_account_info(),
-            to: ctx.accounts.token_account.to_account_info(),
-            authority: ctx.accounts.mint_authority.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token_2022::mint_to(cpi_ctx, amount)?;
+/// Sensitive admin change that waits out `StablecoinConfig.admin_delay`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdminAction {
+    /// Same as `add_minter`
+    AddMinter { minter: Pubkey, quota: u64 },
+    /// Same as `update_minter_quota`
+    UpdateMinterQuota { minter: Pubkey, new_quota: u64 },
+    /// Same as `update_minter_allowance`
+    UpdateMinterAllowance { minter: Pubkey, period: AllowancePeriod, period_length: u64, period_limit: u64 },
+    /// Same as `add_burner`
+    AddBurner { burner: Pubkey, burn_limit: Option<u64> },
+    /// Same as `update_roles`
+    UpdateRoles { blacklister: Pubkey, pauser: Pubkey, seizer: Pubkey },
+    /// Same as `propose_master_authority`; the new authority still has to accept
+    TransferAuthority { new_master_authority: Pubkey },
+    /// Change `admin_delay` itself
+    SetAdminDelay { delay: i64 },
+    /// Change who may cancel queued actions (`Pubkey::default()` = none)
+    SetGuardian { guardian: Pubkey },
+    /// Same as `set_reserve_policy`
+    SetReservePolicy { reserve_gating: bool, max_attestation_age: i64, collateral_ratio_bps: u16 },
+    /// Same as `set_mint_approval_policy`
+    SetMintApprovalPolicy { approvals_required: u8, requests_only: bool },
+    /// Same as `grant_role`
+    GrantRole { role: RoleKind, holder: Pubkey },
+    /// Same as `update_max_supply`
+    UpdateMaxSupply { new_max_supply: u64 },
+    /// Same as `set_treasury`
+    SetTreasury { treasury: Pubkey },
+}
 
-        msg!("Minted {} tokens to {}", amount, ctx.accounts.token_account.key());
-        Ok(())
-    }
+/// Admin action waiting for its delay to elapse
+/// PDA seeds: ["admin_action", config.key(), id.to_le_bytes()]
+#[account]
+pub struct PendingAdminAction {
+    pub config: Pubkey,
+    pub id: u64,
+    pub action: AdminAction,
+    pub proposer: Pubkey,
+    pub queued_at: i64,
+    pub eta: i64, // Earliest execution time
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 32],
+}
 
-    /// Burn tokens from an account
-    pub fn burn_tokens(ctx: Context<BurnTokens>, amount: u64) -> Result<()> {
-        let config = &ctx.accounts.config;
+impl PendingAdminAction {
+    pub const LEN: usize = 8  // discriminator
+        + 32 // config
+        + 8  // id
+        + 1 + 96 // action (largest variant: UpdateRoles)
+        + 32 // proposer
+        + 8  // queued_at
+        + 8  // eta
+        + 1  // bump
+        + 1  // version
+        + 32; // reserved
+}
 
-        require!(!config.paused, StablecoinError::TokenPaused);
+/// Built-in M-of-N council that can act as the master authority
+/// The council signs through its authority PDA, ["council_authority", config.key()]:
+/// make that PDA the master authority (propose + accept through a proposal) and fund it
+/// with SOL if council-executed instructions need to pay rent.
+/// PDA seeds: ["council", config.key()]
+#[account]
+pub struct AdminCouncil {
+    pub config: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub proposal_count: u64,
+    pub bump: u8,
+    pub authority_bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 32],
+}
 
-        let cpi_accounts = BurnCpi {
-            mint: ctx.accounts.mint.to_account_info(),
-            from: ctx.accounts.token_account.to_account_info(),
-            authority: ctx.accounts.burner.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token_2022::burn(cpi_ctx, amount)?;
+impl AdminCouncil {
+    pub const LEN: usize = 8  // discriminator
+        + 32 // config
+        + 4 + 32 * MAX_COUNCIL_MEMBERS // members
+        + 1  // threshold
+        + 8  // proposal_count
+        + 1  // bump
+        + 1  // authority_bump
+        + 1  // version
+        + 32; // reserved
 
-        msg!("Burned {} tokens from {}", amount, ctx.accounts.token_account.key());
-        Ok(())
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
     }
 
-    /// Freeze a token account using the freeze authority keypair
-    pub fn freeze_token_account(ctx: Context<FreezeTokenAccount>) -> Result<()> {
-        let cpi_accounts = FreezeAccountCpi {
-            account: ctx.accounts.token_account.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-            authority: ctx.accounts.freeze_authority.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token_2022::freeze_account(cpi_ctx)?;
-
-        msg!("Frozen account {}", ctx.accounts.token_account.key());
+    /// Check a member set and threshold: 1 <= threshold <= members <= MAX_COUNCIL_MEMBERS,
+    /// without duplicate or default keys
+    pub fn validate_members(members: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= MAX_COUNCIL_MEMBERS,
+            StablecoinError::InvalidAmount
+        );
+        require!(
+            threshold > 0 && threshold as usize <= members.len(),
+            StablecoinError::InvalidAmount
+        );
+        for (i, member) in members.iter().enumerate() {
+            require!(
+                *member != Pubkey::default() && !members[..i].contains(member),
+                StablecoinError::InvalidAccount
+            );
+        }
         Ok(())
     }
+}
 
-    /// Freeze a token account using PDA-based freeze authority
-    /// This is for mints where the freeze authority is set to the program's PDA
-    pub fn freeze_token_account_pda(ctx: Context<FreezeTokenAccountPda>) -> Result<()> {
-        let freeze_authority_bump = ctx.bumps.freeze_authority;
-        let mint_key = ctx.accounts.mint.key();
+/// Account meta of an instruction carried by a CouncilProposal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProposalAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
 
-        // Sign with the freeze authority PDA
-        let freeze_authority_seeds = &[
-            b"freeze_authority".as_ref(),
-            mint_key.as_ref(),
-            &[freeze_authority_bump],
-        ];
-        let freeze_authority_signer = &[&freeze_authority_seeds[..]];
+/// sss-token admin instruction waiting for council approvals
+/// `data` is the serialized instruction (discriminator + args) and `accounts` its metas;
+/// the council authority PDA signs it on execution. Only the instructions in
+/// `CouncilProposal::EXECUTABLE` can be proposed, and the proposal can no longer be
+/// approved or executed from `expires_at`
+/// PDA seeds: ["council_proposal", council.key(), id.to_le_bytes()]
+#[account]
+pub struct CouncilProposal {
+    pub council: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub accounts: Vec<ProposalAccount>,
+    pub data: Vec<u8>,
+    pub approvals: Vec<Pubkey>,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 32],
+}
 
-        let cpi_accounts = FreezeAccountCpi {
-            account: ctx.accounts.token_account.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-            authority: ctx.accounts.freeze_authority.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, freeze_authority_signer);
-        token_2022::freeze_account(cpi_ctx)?;
+impl CouncilProposal {
+    pub const LEN: usize = 8  // discriminator
+        + 32 // council
+        + 8  // id
+        + 32 // proposer
+        + 4 + (32 + 1 + 1) * MAX_PROPOSAL_ACCOUNTS // accounts
+        + 4 + MAX_PROPOSAL_DATA_LEN // data
+        + 4 + 32 * MAX_COUNCIL_MEMBERS // approvals
+        + 8  // created_at
+        + 8  // expires_at
+        + 1  // bump
+        + 1  // version
+        + 32; // reserved
+
+    /// Instructions a proposal may carry: the admin instructions signed by the master
+    /// authority (the council authority PDA once the council holds it) and `update_council`.
+    /// Signing-role instructions (mint, burn, pause, blacklist, seize) and the proposal
+    /// flow itself are not executable
+    pub const EXECUTABLE: [&'static [u8]; 28] = [
+        crate::instruction::AddMinter::DISCRIMINATOR,
+        crate::instruction::UpdateMinterQuota::DISCRIMINATOR,
+        crate::instruction::UpdateMinterAllowance::DISCRIMINATOR,
+        crate::instruction::RemoveMinter::DISCRIMINATOR,
+        crate::instruction::AddBurner::DISCRIMINATOR,
+        crate::instruction::RemoveBurner::DISCRIMINATOR,
+        crate::instruction::GrantRole::DISCRIMINATOR,
+        crate::instruction::RevokeRole::DISCRIMINATOR,
+        crate::instruction::UpdateRoles::DISCRIMINATOR,
+        crate::instruction::InitializeEscrow::DISCRIMINATOR,
+        crate::instruction::ReleaseSeizedFunds::DISCRIMINATOR,
+        crate::instruction::SetTreasury::DISCRIMINATOR,
+        crate::instruction::UpdateMetadata::DISCRIMINATOR,
+        crate::instruction::SetMetadataField::DISCRIMINATOR,
+        crate::instruction::RemoveMetadataField::DISCRIMINATOR,
+        crate::instruction::UpdateMaxSupply::DISCRIMINATOR,
+        crate::instruction::TransferAuthority::DISCRIMINATOR,
+        crate::instruction::ProposeMasterAuthority::DISCRIMINATOR,
+        crate::instruction::AcceptMasterAuthority::DISCRIMINATOR,
+        crate::instruction::CancelMasterAuthorityTransfer::DISCRIMINATOR,
+        crate::instruction::MigrateConfig::DISCRIMINATOR,
+        crate::instruction::QueueAdminAction::DISCRIMINATOR,
+        crate::instruction::ExecuteAdminAction::DISCRIMINATOR,
+        crate::instruction::CancelAdminAction::DISCRIMINATOR,
+        crate::instruction::UpdateCouncil::DISCRIMINATOR,
+        crate::instruction::SetReservePolicy::DISCRIMINATOR,
+        crate::instruction::SetMintApprovalPolicy::DISCRIMINATOR,
+        crate::instruction::RejectMintRequest::DISCRIMINATOR,
+    ];
+
+    /// Whether `data` encodes an instruction listed in `EXECUTABLE`
+    pub fn is_executable(data: &[u8]) -> bool {
+        data.len() >= 8 && Self::EXECUTABLE.contains(&&data[..8])
+    }
 
-        msg!("Frozen account {} using PDA authority", ctx.accounts.token_account.key());
-        Ok(())
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
     }
 
-    /// Thaw a token account using PDA-based freeze authority
-    /// This is for mints where the freeze authority is set to the program's PDA
-    pub fn thaw_token_account_pda(ctx: Context<ThawTokenAccountPda>) -> Result<()> {
-        let freeze_authority_bump = ctx.bumps.freeze_authority;
-        let mint_key = ctx.accounts.mint.key();
+    /// Approvals from keys that are still council members
+    pub fn approval_count(&self, council: &AdminCouncil) -> usize {
+        self.approvals.iter().filter(|member| council.is_member(member)).count()
+    }
 
-        // Sign with the freeze authority PDA
-        let freeze_authority_seeds = &[
-            b"freeze_authority".as_ref(),
-            mint_key.as_ref(),
-            &[freeze_authority_bump],
-        ];
-        let freeze_authority_signer = &[&freeze_authority_seeds[..]];
+    /// The sss-token instruction this proposal executes
+    pub fn instruction(&self) -> Instruction {
+        Instruction {
+            program_id: crate::ID,
+            accounts: self
+                .accounts
+                .iter()
+                .map(|account| AccountMeta {
+                    pubkey: account.pubkey,
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: self.data.clone(),
+        }
+    }
+}
 
-        let cpi_accounts = ThawAccountCpi {
-            account: ctx.accounts.token_account.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-            authority: ctx.accounts.freeze_authority.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, freeze_authority_signer);
-        token_2022::thaw_account(cpi_ctx)?;
+/// StablecoinConfig as written before accounts were versioned (version 0)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct StablecoinConfigV0 {
+    pub master_authority: Pubkey,
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub decimals: u8,
+    pub paused: bool,
+    pub bump: u8,
+    pub enable_permanent_delegate: bool,
+    pub enable_transfer_hook: bool,
+    pub default_account_frozen: bool,
+    pub blacklister: Pubkey,
+    pub pauser: Pubkey,
+    pub seizer: Pubkey,
+}
 
-        msg!("Thawed account {} using PDA authority", ctx.accounts.token_account.key());
-        Ok(())
+impl StablecoinConfigV0 {
+    pub const LEN: usize = 8 + 32 + 32 + (4 + 100) + (4 + 10) + (4 + 200) + 6 + 3 * 32;
+}
+
+impl From<StablecoinConfigV0> for StablecoinConfig {
+    fn from(legacy: StablecoinConfigV0) -> Self {
+        Self {
+            master_authority: legacy.master_authority,
+            mint: legacy.mint,
+            name: legacy.name,
+            symbol: legacy.symbol,
+            uri: legacy.uri,
+            decimals: legacy.decimals,
+            // The old pause switch only stopped minting and burning
+            pause_mask: if legacy.paused { PAUSE_MINT | PAUSE_BURN } else { 0 },
+            bump: legacy.bump,
+            enable_permanent_delegate: legacy.enable_permanent_delegate,
+            enable_transfer_hook: legacy.enable_transfer_hook,
+            default_account_frozen: legacy.default_account_frozen,
+            blacklister: legacy.blacklister,
+            pauser: legacy.pauser,
+            seizer: legacy.seizer,
+            max_supply: 0,
+            treasury: Pubkey::default(),
+            version: 0,
+            admin_delay: 0,
+            guardian: Pubkey::default(),
+            next_admin_action_id: 0,
+            reserve_gating: false,
+            max_attestation_age: 0,
+            collateral_ratio_bps: 0,
+            mint_approvals_required: 0,
+            mint_requests_only: false,
+            pending_master_authority: None,
+            reserved: [0; 3],
+        }
     }
+}
 
-    /// Thaw a token account using the freeze authority keypair
-    pub fn thaw_token_account(ctx: Context<ThawTokenAccount>) -> Result<()> {
-        let cpi_accounts = ThawAccountCpi {
-            account: ctx.accounts.token_account.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-            authority: ctx.accounts.freeze_authority.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token_2022::thaw_account(cpi_ctx)?;
+/// MinterInfo as written before accounts were versioned (version 0)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MinterInfoV0 {
+    pub authority: Pubkey,
+    pub quota: u64,
+    pub minted: u64,
+    pub bump: u8,
+}
 
-        msg!("Thawed account {}", ctx.accounts.token_account.key());
-        Ok(())
-    }
+impl MinterInfoV0 {
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 1;
+}
 
-    /// Pause all token operations
-    pub fn pause(ctx: Context<Pause>) -> Result<()> {
-        let config = &mut ctx.accounts.config;
-        config.paused = true;
-        msg!("Token paused");
-        Ok(())
+impl From<MinterInfoV0> for MinterInfo {
+    fn from(legacy: MinterInfoV0) -> Self {
+        Self {
+            authority: legacy.authority,
+            quota: legacy.quota,
+            minted: legacy.minted,
+            bump: legacy.bump,
+            period: AllowancePeriod::Seconds,
+            period_length: 0,
+            period_limit: 0,
+            period_minted: 0,
+            period_start: 0,
+            version: 0,
+            reserved: [0; 32],
+        }
     }
+}
 
-    /// Unpause all token operations
-    pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
-        let config = &mut ctx.accounts.config;
-        config.paused = false;
-        msg!("Token unpaused");
-        Ok(())
+/// BlacklistEntry as written before accounts were versioned (version 0)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BlacklistEntryV0 {
+    pub user: Pubkey,
+    pub reason: String,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+impl BlacklistEntryV0 {
+    pub const LEN: usize = 8 + 32 + (4 + 100) + 8 + 1;
+}
+
+impl From<BlacklistEntryV0> for BlacklistEntry {
+    fn from(legacy: BlacklistEntryV0) -> Self {
+        Self {
+            user: legacy.user,
+            reason: legacy.reason,
+            timestamp: legacy.timestamp,
+            bump: legacy.bump,
+            reason_code: BlacklistReason::Other,
+            case_id: String::new(),
+            added_by: Pubkey::default(), // not recorded before version 1
+            expires_at: None,
+            version: 0,
+            reserved: [0; 32],
+        }
     }
+}
 
-    /// Add a minter with specified quota
-    pub fn add_minter(ctx: Context<AddMinter>, quota: u64) -> Result<()> {
-        let minter_info = &mut ctx.accounts.minter_info;
+/// Program accounts carrying a `version` field, upgraded in place by `migrate_config`
+pub trait Versioned: AccountSerialize + AccountDeserialize + Discriminator + Sized {
+    /// Size of the current layout, including the discriminator
+    const SPACE: usize;
 
-        minter_info.authority = ctx.accounts.minter.key();
-        minter_info.quota = quota;
-        minter_info.minted = 0;
-        minter_info.bump = ctx.bumps.minter_info;
+    fn version_mut(&mut self) -> &mut u8;
 
-        msg!("Added minter {} with quota {}", ctx.accounts.minter.key(), quota);
-        Ok(())
+    /// Decode the version 0 layout from the bytes following the discriminator
+    /// Account types introduced after versioning have no such layout
+    fn decode_legacy(_data: &[u8]) -> Result<Self> {
+        err!(StablecoinError::OutdatedAccountVersion)
     }
+}
 
-    /// Update minter quota
-    pub fn update_minter_quota(ctx: Context<UpdateMinterQuota>, new_quota: u64) -> Result<()> {
-        let minter_info = &mut ctx.accounts.minter_info;
-        minter_info.quota = new_quota;
+impl Versioned for StablecoinConfig {
+    const SPACE: usize = Self::LEN;
 
-        msg!("Updated minter quota to {}", new_quota);
-        Ok(())
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
     }
 
-    /// Remove a minter
-    pub fn remove_minter(ctx: Context<RemoveMinter>) -> Result<()> {
-        let minter_info = &mut ctx.accounts.minter_info;
-        minter_info.quota = 0;
-
-        msg!("Removed minter {}", ctx.accounts.minter.key());
-        Ok(())
+    fn decode_legacy(mut data: &[u8]) -> Result<Self> {
+        Ok(StablecoinConfigV0::deserialize(&mut data)?.into())
     }
+}
 
-    /// Update roles (blacklister, pauser, seizer)
-    pub fn update_roles(
-        ctx: Context<UpdateRoles>,
-        new_blacklister: Pubkey,
-        new_pauser: Pubkey,
-        new_seizer: Pubkey,
-    ) -> Result<()> {
-        let config = &mut ctx.accounts.config;
+impl Versioned for MinterInfo {
+    const SPACE: usize = Self::LEN;
 
-        config.blacklister = new_blacklister;
-        config.pauser = new_pauser;
-        config.seizer = new_seizer;
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
+    }
 
-        msg!("Updated roles");
-        Ok(())
+    fn decode_legacy(mut data: &[u8]) -> Result<Self> {
+        Ok(MinterInfoV0::deserialize(&mut data)?.into())
     }
+}
 
-    /// Add an address to the blacklist (SSS-2)
-    pub fn add_to_blacklist(ctx: Context<AddToBlacklist>, reason: String) -> Result<()> {
-        let config = &ctx.accounts.config;
+impl Versioned for RoleAssignment {
+    const SPACE: usize = Self::LEN;
 
-        require!(config.enable_transfer_hook, StablecoinError::ComplianceNotEnabled);
-        require!(reason.len() <= 100, StablecoinError::InvalidAccount);
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
+    }
+}
 
-        let blacklist_entry = &mut ctx.accounts.blacklist_entry;
-        let clock = Clock::get()?;
+impl Versioned for BurnerInfo {
+    const SPACE: usize = Self::LEN;
 
-        blacklist_entry.user = ctx.accounts.user.key();
-        blacklist_entry.reason = reason;
-        blacklist_entry.timestamp = clock.unix_timestamp;
-        blacklist_entry.bump = ctx.bumps.blacklist_entry;
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
+    }
+}
 
-        msg!("Added {} to blacklist: {}", ctx.accounts.user.key(), blacklist_entry.reason);
-        Ok(())
+impl Versioned for BlacklistEntry {
+    const SPACE: usize = Self::LEN;
+
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
     }
 
-    /// Remove an address from the blacklist (SSS-2)
-    /// The `close = blacklister` constraint on the account handles closing automatically
-    pub fn remove_from_blacklist(ctx: Context<RemoveFromBlacklist>) -> Result<()> {
-        let config = &ctx.accounts.config;
+    fn decode_legacy(mut data: &[u8]) -> Result<Self> {
+        Ok(BlacklistEntryV0::deserialize(&mut data)?.into())
+    }
+}
 
-        require!(config.enable_transfer_hook, StablecoinError::ComplianceNotEnabled);
+impl Versioned for SeizureCase {
+    const SPACE: usize = Self::LEN;
 
-        msg!("Removed {} from blacklist", ctx.accounts.user.key());
-        Ok(())
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
     }
+}
 
-    /// Seize tokens from a frozen account (SSS-2)
-    /// Uses the permanent delegate PDA to transfer from frozen accounts
-    /// Note: This first thaws the account using the freeze authority PDA,
-    /// then transfers using the permanent delegate PDA
-    pub fn seize(ctx: Context<Seize>, amount: u64) -> Result<()> {
-        let config = &ctx.accounts.config;
+impl Versioned for PendingAdminAction {
+    const SPACE: usize = Self::LEN;
 
-        require!(
-            config.enable_permanent_delegate,
-            StablecoinError::PermanentDelegateNotEnabled
-        );
-        require!(amount > 0, StablecoinError::InvalidAmount);
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
+    }
+}
 
-        let permanent_delegate_bump = ctx.bumps.permanent_delegate;
-        let freeze_authority_bump = ctx.bumps.freeze_authority;
-        let mint_key = ctx.accounts.mint.key();
+impl Versioned for ReserveAttestation {
+    const SPACE: usize = Self::LEN;
 
-        // Step 1: Thaw the source account using the freeze authority PDA
-        let freeze_authority_seeds = &[
-            b"freeze_authority".as_ref(),
-            mint_key.as_ref(),
-            &[freeze_authority_bump],
-        ];
-        let freeze_authority_signer = &[&freeze_authority_seeds[..]];
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
+    }
+}
 
-        let thaw_accounts = ThawAccountCpi {
-            account: ctx.accounts.source_token.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-            authority: ctx.accounts.freeze_authority.to_account_info(),
-        };
-        let thaw_program = ctx.accounts.token_program.to_account_info();
-        let thaw_ctx = CpiContext::new_with_signer(thaw_program, thaw_accounts, freeze_authority_signer);
-        token_2022::thaw_account(thaw_ctx)?;
+impl Versioned for MintRequest {
+    const SPACE: usize = Self::LEN;
 
-        msg!("Thawed account {} for seizure", ctx.accounts.source_token.key());
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
+    }
+}
 
-        // Step 2: Transfer using the permanent delegate PDA
-        let delegate_seeds = &[
-            b"permanent_delegate".as_ref(),
-            mint_key.as_ref(),
-            &[permanent_delegate_bump],
-        ];
-        let delegate_signer = &[&delegate_seeds[..]];
+impl Versioned for UsedFiatReference {
+    const SPACE: usize = Self::LEN;
 
-        let transfer_accounts = TransferChecked {
-            from: ctx.accounts.source_token.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-            to: ctx.accounts.dest_token.to_account_info(),
-            authority: ctx.accounts.permanent_delegate.to_account_info(),
-        };
-        let transfer_program = ctx.accounts.token_program.to_account_info();
-        let transfer_ctx = CpiContext::new_with_signer(transfer_program, transfer_accounts, delegate_signer);
-        token_2022::transfer_checked(transfer_ctx, amount, config.decimals)?;
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
+    }
+}
 
-        msg!("Seized {} tokens from {} using permanent delegate", amount, ctx.accounts.source_token.key());
-        Ok(())
+impl Versioned for AdminCouncil {
+    const SPACE: usize = Self::LEN;
+
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
     }
+}
 
-    /// Transfer master authority
-    pub fn transfer_authority(
-        ctx: Context<TransferAuthority>,
-        new_master_authority: Pubkey,
-    ) -> Result<()> {
-        let config = &mut ctx.accounts.config;
-        config.master_authority = new_master_authority;
+impl Versioned for CouncilProposal {
+    const SPACE: usize = Self::LEN;
 
-        msg!("Transferred master authority to {}", new_master_authority);
-        Ok(())
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
     }
 }
 
 // ============================================
-// CONTEXT STRUCTS
+// EVENTS
 // ============================================
 
-#[derive(Accounts)]
-#[instruction(
-    name: String,
-    symbol: String,
-    uri: String,
-    decimals: u8,
-    enable_permanent_delegate: bool,
-    enable_transfer_hook: bool,
-    default_account_frozen: bool,
-)]
-pub struct Initialize<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = StablecoinConfig::LEN,
-        seeds = [b"config", mint.key().as_ref()],
-        bump
-    )]
-    pub config: Account<'info, StablecoinConfig>,
+#[event]
+pub struct StablecoinInitialized {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub decimals: u8,
+    pub enable_permanent_delegate: bool,
+    pub enable_transfer_hook: bool,
+    pub default_account_frozen: bool,
+    pub max_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokensMinted {
+    pub mint: Pubkey,
+    pub minter: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+    pub minter_total_minted: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokensBurned {
+    pub mint: Pubkey,
+    pub burner: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedemptionBurned {
+    pub mint: Pubkey,
+    pub burner: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+    pub redemption_id: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountFrozen {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub token_account: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountThawed {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub token_account: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Paused {
+    pub mint: Pubkey,
+    pub pauser: Pubkey,
+    pub mask: u8,
+    pub pause_mask: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Unpaused {
+    pub mint: Pubkey,
+    pub pauser: Pubkey,
+    pub mask: u8,
+    pub pause_mask: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinterAdded {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub minter: Pubkey,
+    pub quota: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinterQuotaUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub minter: Pubkey,
+    pub old_quota: u64,
+    pub new_quota: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinterAllowanceUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub minter: Pubkey,
+    pub period: AllowancePeriod,
+    pub period_length: u64,
+    pub period_limit: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinterRemoved {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub minter: Pubkey,
+    pub minted: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BurnerAdded {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub burner: Pubkey,
+    pub burn_limit: Option<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BurnerRemoved {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub burner: Pubkey,
+    pub burned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub role: RoleKind,
+    pub holder: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub role: RoleKind,
+    pub holder: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RolesUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub blacklister: Pubkey,
+    pub pauser: Pubkey,
+    pub seizer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Blacklisted {
+    pub mint: Pubkey,
+    pub blacklister: Pubkey,
+    pub user: Pubkey,
+    pub reason_code: BlacklistReason,
+    pub reason: String,
+    pub case_id: String,
+    pub expires_at: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BlacklistRemoved {
+    pub mint: Pubkey,
+    pub blacklister: Pubkey,
+    pub user: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BlacklistBatchUpdated {
+    pub mint: Pubkey,
+    pub blacklister: Pubkey,
+    pub added: bool,
+    pub requested: u32,
+    pub applied: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BlacklistEntryPurged {
+    pub mint: Pubkey,
+    pub caller: Pubkey,
+    pub user: Pubkey,
+    pub expired_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Seized {
+    pub mint: Pubkey,
+    pub seizer: Pubkey,
+    pub source_token: Pubkey,
+    pub dest_token: Pubkey, // the escrow token account
+    pub amount: u64,
+    pub timestamp: i64,
+    pub case_id: String,
+}
+
+#[event]
+pub struct EscrowInitialized {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub escrow: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeizedFundsReleased {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub case_id: String,
+    pub destination: Pubkey,
+    pub target: ReleaseTarget,
+    pub amount: u64,
+    pub remaining: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MaxSupplyUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub old_max_supply: u64,
+    pub new_max_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub old_treasury: Pubkey,
+    pub new_treasury: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub mint: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub cancelled_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MetadataUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MetadataFieldSet {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub key: String,
+    pub value: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MetadataFieldRemoved {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub key: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminActionQueued {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub eta: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminActionExecuted {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminActionCancelled {
+    pub mint: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouncilCreated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub council_authority: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouncilUpdated {
+    pub mint: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouncilProposalCreated {
+    pub mint: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub data: Vec<u8>,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouncilProposalApproved {
+    pub mint: Pubkey,
+    pub proposal_id: u64,
+    pub member: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouncilProposalExecuted {
+    pub mint: Pubkey,
+    pub proposal_id: u64,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouncilProposalCancelled {
+    pub mint: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReserveAttested {
+    pub mint: Pubkey,
+    pub attestor: Pubkey,
+    pub reserves: u64,
+    pub as_of: i64,
+    pub report_uri_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReservePolicyUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub reserve_gating: bool,
+    pub max_attestation_age: i64,
+    pub collateral_ratio_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintRequestCreated {
+    pub mint: Pubkey,
+    pub request: Pubkey,
+    pub minter: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub fiat_reference: String,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintRequestApproved {
+    pub mint: Pubkey,
+    pub request: Pubkey,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub approvals_required: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintRequestExecuted {
+    pub mint: Pubkey,
+    pub request: Pubkey,
+    pub minter: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub fiat_reference: String,
+    pub approvers: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintRequestRejected {
+    pub mint: Pubkey,
+    pub request: Pubkey,
+    pub rejected_by: Pubkey,
+    pub fiat_reference: String,
+    pub expired: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintApprovalPolicyUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub approvals_required: u8,
+    pub requests_only: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigMigrated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub accounts_migrated: u32,
+    pub timestamp: i64,
+}
+
+// ============================================
+// HELPERS
+// ============================================
+
+/// Read the TokenMetadata stored in the mint's variable-length extension
+fn read_token_metadata(mint: &AccountInfo) -> Result<TokenMetadata> {
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<MintState>::unpack(&data)?;
+    Ok(state.get_variable_len_extension::<TokenMetadata>()?)
+}
+
+/// Top the mint up to the rent-exempt minimum for `new_metadata`
+/// Token-2022 reallocates the mint on metadata writes but does not move lamports
+fn fund_metadata_resize<'info>(
+    payer: &Signer<'info>,
+    mint: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    old_metadata: &TokenMetadata,
+    new_metadata: &TokenMetadata,
+) -> Result<()> {
+    let new_len = mint.data_len() - old_metadata.tlv_size_of()? + new_metadata.tlv_size_of()?;
+    let required = Rent::get()?.minimum_balance(new_len);
+    let current = mint.lamports();
+
+    if required > current {
+        let transfer_accounts = Transfer {
+            from: payer.to_account_info(),
+            to: mint.clone(),
+        };
+        let transfer_ctx = CpiContext::new(system_program.to_account_info(), transfer_accounts);
+        system_program::transfer(transfer_ctx, required - current)?;
+    }
+    Ok(())
+}
+
+/// Issuance checks shared by `mint_tokens` and `execute_mint_request`
+/// Enforces the pause flag, the minter's quota and period allowance, the supply cap and
+/// proof-of-reserves, then records `amount` on the minter
+fn apply_mint_limits(
+    config: &StablecoinConfig,
+    minter_info: &mut MinterInfo,
+    attestation: Option<&ReserveAttestation>,
+    supply: u64,
+    amount: u64,
+) -> Result<()> {
+    require!(!config.is_paused(PAUSE_MINT), StablecoinError::TokenPaused);
+    let minted = minter_info
+        .minted
+        .checked_add(amount)
+        .ok_or(StablecoinError::QuotaExceeded)?;
+    require!(minted <= minter_info.quota, StablecoinError::QuotaExceeded);
+
+    // Enforce the global issuance ceiling
+    if config.max_supply > 0 {
+        let new_supply = supply
+            .checked_add(amount)
+            .ok_or(StablecoinError::SupplyCapExceeded)?;
+        require!(new_supply <= config.max_supply, StablecoinError::SupplyCapExceeded);
+    }
+
+    // Enforce proof-of-reserves when enabled
+    let clock = Clock::get()?;
+    config.check_reserves(attestation, supply, amount, clock.unix_timestamp)?;
+
+    // Reset the allowance window if it has elapsed, then enforce it
+    if minter_info.has_period_allowance() {
+        minter_info.roll_period(&clock);
+        let period_minted = minter_info
+            .period_minted
+            .checked_add(amount)
+            .ok_or(StablecoinError::PeriodAllowanceExceeded)?;
+        require!(
+            period_minted <= minter_info.period_limit,
+            StablecoinError::PeriodAllowanceExceeded
+        );
+        minter_info.period_minted = period_minted;
+    }
+
+    minter_info.minted = minted;
+    Ok(())
+}
+
+/// Whether a seizure source owner's BlacklistEntry was passed and is still in force at `now`
+fn is_actively_blacklisted(blacklist_entry: &Option<Account<BlacklistEntry>>, now: i64) -> bool {
+    blacklist_entry.as_ref().is_some_and(|entry| !entry.is_expired(now))
+}
+
+/// Holders of the MintApprover role among `role_assignments` (RoleAssignment PDAs of `config`)
+/// Revoked roles are closed, so they can no longer be passed in
+fn current_mint_approvers(config: &Pubkey, role_assignments: &[AccountInfo]) -> Result<Vec<Pubkey>> {
+    let mut approvers = Vec::with_capacity(role_assignments.len());
+    for account in role_assignments {
+        require_keys_eq!(*account.owner, crate::ID, StablecoinError::InvalidAccount);
+        let role_assignment = RoleAssignment::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        require!(
+            role_assignment.config == *config
+                && role_assignment.role == RoleKind::MintApprover
+                && role_assignment.version == ACCOUNT_VERSION,
+            StablecoinError::InvalidAccount
+        );
+        approvers.push(role_assignment.holder);
+    }
+    Ok(approvers)
+}
+
+/// Recorded approvers that provably lost the MintApprover role: their RoleAssignment PDA
+/// is among `accounts` and has been closed. Approvers without a matching account are kept.
+fn revoked_mint_approvers(config: &Pubkey, approvals: &[Pubkey], accounts: &[AccountInfo]) -> Vec<Pubkey> {
+    approvals
+        .iter()
+        .filter(|approver| {
+            let (role_assignment, _) = Pubkey::find_program_address(
+                &[b"role", config.as_ref(), RoleKind::MintApprover.seed(), approver.as_ref()],
+                &crate::ID,
+            );
+            accounts
+                .iter()
+                .any(|account| account.key() == role_assignment && account.data_is_empty())
+        })
+        .copied()
+        .collect()
+}
+
+/// Mint `amount` into `to`, signed by the mint authority PDA
+fn mint_with_authority_pda<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    mint_authority: &AccountInfo<'info>,
+    mint_authority_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let mint_key = mint.key();
+    let mint_authority_seeds = &[
+        b"mint_authority".as_ref(),
+        mint_key.as_ref(),
+        &[mint_authority_bump],
+    ];
+    let mint_authority_signer = &[&mint_authority_seeds[..]];
+
+    let cpi_accounts = MintTo {
+        mint: mint.clone(),
+        to: to.clone(),
+        authority: mint_authority.clone(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, mint_authority_signer);
+    token_2022::mint_to(cpi_ctx, amount)
+}
+
+/// Create a program-owned PDA of `space` bytes, mirroring Anchor's `init`
+/// The PDA may already hold lamports sent by anyone; `payer` tops it up to rent-exempt
+fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let rent_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+
+    if current_lamports == 0 {
+        let create_accounts = CreateAccount {
+            from: payer.clone(),
+            to: account.clone(),
+        };
+        let create_ctx = CpiContext::new_with_signer(system_program.clone(), create_accounts, signer_seeds);
+        system_program::create_account(create_ctx, rent_lamports, space as u64, &crate::ID)?;
+    } else {
+        if rent_lamports > current_lamports {
+            let transfer_accounts = Transfer {
+                from: payer.clone(),
+                to: account.clone(),
+            };
+            let transfer_ctx = CpiContext::new(system_program.clone(), transfer_accounts);
+            system_program::transfer(transfer_ctx, rent_lamports - current_lamports)?;
+        }
+        let allocate_accounts = Allocate {
+            account_to_allocate: account.clone(),
+        };
+        let allocate_ctx = CpiContext::new_with_signer(system_program.clone(), allocate_accounts, signer_seeds);
+        system_program::allocate(allocate_ctx, space as u64)?;
+
+        let assign_accounts = Assign {
+            account_to_assign: account.clone(),
+        };
+        let assign_ctx = CpiContext::new_with_signer(system_program.clone(), assign_accounts, signer_seeds);
+        system_program::assign(assign_ctx, &crate::ID)?;
+    }
+    Ok(())
+}
+
+/// Deserialize a program account and grow it to the current `T` layout
+/// Accounts smaller than the current layout predate versioning and are decoded
+/// from their version 0 layout; `payer` covers the extra rent
+fn load_for_migration<'info, T: Versioned>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<T> {
+    require_keys_eq!(*account.owner, crate::ID, StablecoinError::InvalidAccount);
+
+    if account.data_len() >= T::SPACE {
+        let data = account.try_borrow_data()?;
+        return T::try_deserialize(&mut &data[..]);
+    }
+
+    let value = {
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == *T::DISCRIMINATOR,
+            StablecoinError::InvalidAccount
+        );
+        T::decode_legacy(&data[8..])?
+    };
+
+    let required = Rent::get()?.minimum_balance(T::SPACE);
+    let current = account.lamports();
+    if required > current {
+        let transfer_accounts = Transfer {
+            from: payer.clone(),
+            to: account.clone(),
+        };
+        let transfer_ctx = CpiContext::new(system_program.clone(), transfer_accounts);
+        system_program::transfer(transfer_ctx, required - current)?;
+    }
+    account.resize(T::SPACE)?;
+    Ok(value)
+}
+
+/// Stamp a loaded account with ACCOUNT_VERSION and write it back
+/// Returns false if the account was already current
+fn store_migrated<T: Versioned>(account: &AccountInfo, mut value: T) -> Result<bool> {
+    if *value.version_mut() >= ACCOUNT_VERSION {
+        return Ok(false);
+    }
+    *value.version_mut() = ACCOUNT_VERSION;
+    value.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+    Ok(true)
+}
+
+/// Check that `account` is the PDA derived from `seeds` and `bump`
+fn require_pda(account: &AccountInfo, seeds: &[&[u8]], bump: u8) -> Result<()> {
+    let bump = [bump];
+    let mut seeds = seeds.to_vec();
+    seeds.push(&bump);
+    let expected = Pubkey::create_program_address(&seeds, &crate::ID)
+        .map_err(|_| error!(StablecoinError::InvalidAccount))?;
+    require_keys_eq!(expected, account.key(), StablecoinError::InvalidAccount);
+    Ok(())
+}
+
+// ============================================
+// INSTRUCTIONS
+// ============================================
+
+#[program]
+pub mod sss_token {
+    use super::*;
+
+    /// Initialize a new stablecoin with specified configuration
+    /// Creates the Token-2022 mint and enables the extensions selected by the preset flags:
+    /// - MetadataPointer + TokenMetadata (always)
+    /// - PermanentDelegate -> ["permanent_delegate", mint] PDA (enable_permanent_delegate)
+    /// - TransferHook -> transfer_hook program (enable_transfer_hook)
+    /// - DefaultAccountState::Frozen (default_account_frozen)
+    /// The mint authority is always the ["mint_authority", mint] PDA and
+    /// the freeze authority is always the ["freeze_authority", mint] PDA
+    /// With enable_transfer_hook, transfers fail until
+    /// transfer_hook::initialize_extra_account_meta_list has run for the mint
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        name: String,
+        symbol: String,
+        uri: String,
+        decimals: u8,
+        enable_permanent_delegate: bool,
+        enable_transfer_hook: bool,
+        default_account_frozen: bool,
+        max_supply: u64,
+    ) -> Result<()> {
+        require!(name.len() <= 100, StablecoinError::InvalidAccount);
+        require!(symbol.len() <= 10, StablecoinError::InvalidAccount);
+        require!(uri.len() <= 200, StablecoinError::InvalidAccount);
+
+        let mint_key = ctx.accounts.mint.key();
+        let config_key = ctx.accounts.config.key();
+        let token_program = ctx.accounts.token_program.to_account_info();
+        let mint_info = ctx.accounts.mint.to_account_info();
+
+        // Step 1: Create the mint account sized for the selected extensions.
+        // TokenMetadata is variable-length and reallocated by Token-2022 on initialize,
+        // so the account is funded up front for the metadata TLV entry as well.
+        let mut extensions = vec![ExtensionType::MetadataPointer];
+        if enable_permanent_delegate {
+            extensions.push(ExtensionType::PermanentDelegate);
+        }
+        if enable_transfer_hook {
+            extensions.push(ExtensionType::TransferHook);
+        }
+        if default_account_frozen {
+            extensions.push(ExtensionType::DefaultAccountState);
+        }
+        let mint_len = ExtensionType::try_calculate_account_len::<MintState>(&extensions)?;
+
+        let metadata = TokenMetadata {
+            update_authority: OptionalNonZeroPubkey(config_key),
+            mint: mint_key,
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            additional_metadata: vec![],
+        };
+        let metadata_len = metadata.tlv_size_of()?;
+        let lamports = Rent::get()?.minimum_balance(mint_len + metadata_len);
+
+        let create_accounts = CreateAccount {
+            from: ctx.accounts.authority.to_account_info(),
+            to: mint_info.clone(),
+        };
+        let create_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), create_accounts);
+        system_program::create_account(create_ctx, lamports, mint_len as u64, &token_2022::ID)?;
+
+        // Step 2: Initialize extensions (must happen before InitializeMint2)
+        let pointer_accounts = MetadataPointerInitialize {
+            token_program_id: token_program.clone(),
+            mint: mint_info.clone(),
+        };
+        let pointer_ctx = CpiContext::new(token_program.clone(), pointer_accounts);
+        token_interface::metadata_pointer_initialize(pointer_ctx, Some(config_key), Some(mint_key))?;
+
+        if enable_permanent_delegate {
+            let delegate_accounts = PermanentDelegateInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint_info.clone(),
+            };
+            let delegate_ctx = CpiContext::new(token_program.clone(), delegate_accounts);
+            token_interface::permanent_delegate_initialize(delegate_ctx, &ctx.accounts.permanent_delegate.key())?;
+        }
+
+        if enable_transfer_hook {
+            let hook_accounts = TransferHookInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint_info.clone(),
+            };
+            let hook_ctx = CpiContext::new(token_program.clone(), hook_accounts);
+            token_interface::transfer_hook_initialize(hook_ctx, Some(config_key), Some(TRANSFER_HOOK_PROGRAM_ID))?;
+        }
+
+        if default_account_frozen {
+            let state_accounts = DefaultAccountStateInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint_info.clone(),
+            };
+            let state_ctx = CpiContext::new(token_program.clone(), state_accounts);
+            token_interface::default_account_state_initialize(state_ctx, &AccountState::Frozen)?;
+        }
+
+        // Step 3: Initialize the mint with the mint and freeze authority PDAs
+        let init_accounts = InitializeMint2 {
+            mint: mint_info.clone(),
+        };
+        let init_ctx = CpiContext::new(token_program.clone(), init_accounts);
+        token_2022::initialize_mint2(
+            init_ctx,
+            decimals,
+            &ctx.accounts.mint_authority.key(),
+            Some(&ctx.accounts.freeze_authority.key()),
+        )?;
+
+        // Step 4: Write the TokenMetadata into the mint itself
+        // Token-2022 requires the mint authority to sign, so sign with the mint authority PDA
+        let mint_authority_seeds = &[
+            b"mint_authority".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.bumps.mint_authority],
+        ];
+        let mint_authority_signer = &[&mint_authority_seeds[..]];
+
+        let metadata_accounts = TokenMetadataInitialize {
+            program_id: token_program.clone(),
+            metadata: mint_info.clone(),
+            update_authority: ctx.accounts.config.to_account_info(),
+            mint_authority: ctx.accounts.mint_authority.to_account_info(),
+            mint: mint_info,
+        };
+        let metadata_ctx = CpiContext::new_with_signer(token_program, metadata_accounts, mint_authority_signer);
+        token_interface::token_metadata_initialize(metadata_ctx, name.clone(), symbol.clone(), uri.clone())?;
+
+        let config = &mut ctx.accounts.config;
+
+        config.master_authority = ctx.accounts.authority.key();
+        config.pending_master_authority = None;
+        config.mint = mint_key;
+        config.name = name;
+        config.symbol = symbol;
+        config.uri = uri;
+        config.decimals = decimals;
+        config.pause_mask = 0;
+        config.bump = ctx.bumps.config;
+
+        config.enable_permanent_delegate = enable_permanent_delegate;
+        config.enable_transfer_hook = enable_transfer_hook;
+        config.default_account_frozen = default_account_frozen;
+
+        config.blacklister = ctx.accounts.authority.key();
+        config.pauser = ctx.accounts.authority.key();
+        config.seizer = ctx.accounts.authority.key();
+
+        config.max_supply = max_supply;
+        config.treasury = Pubkey::default();
+        config.version = ACCOUNT_VERSION;
+
+        msg!("Stablecoin initialized: {}", config.symbol);
+
+        emit_cpi!(StablecoinInitialized {
+            mint: mint_key,
+            authority: ctx.accounts.authority.key(),
+            decimals,
+            enable_permanent_delegate,
+            enable_transfer_hook,
+            default_account_frozen,
+            max_supply,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Mint tokens to a recipient account
+    /// The minter must sign; the mint authority PDA signs the Token-2022 CPI
+    /// Disabled when `config.mint_requests_only` is set
+    pub fn mint_tokens(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
+        let config = &ctx.accounts.config;
+        require!(!config.mint_requests_only, StablecoinError::MintRequestRequired);
+
+        apply_mint_limits(
+            config,
+            &mut ctx.accounts.minter_info,
+            ctx.accounts.reserve_attestation.as_deref(),
+            ctx.accounts.mint.supply,
+            amount,
+        )?;
+
+        let mint_key = ctx.accounts.mint.key();
+        mint_with_authority_pda(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.token_account.to_account_info(),
+            &ctx.accounts.mint_authority.to_account_info(),
+            ctx.bumps.mint_authority,
+            amount,
+        )?;
+
+        msg!("Minted {} tokens to {}", amount, ctx.accounts.token_account.key());
+
+        emit_cpi!(TokensMinted {
+            mint: mint_key,
+            minter: ctx.accounts.minter.key(),
+            token_account: ctx.accounts.token_account.key(),
+            amount,
+            minter_total_minted: ctx.accounts.minter_info.minted,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Burn tokens from an account
+    /// Restricted to registered burners; the burned amount is recorded on BurnerInfo
+    pub fn burn_tokens(ctx: Context<BurnTokens>, amount: u64) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let burner_info = &mut ctx.accounts.burner_info;
+
+        require!(!config.is_paused(PAUSE_BURN), StablecoinError::TokenPaused);
+        require!(burner_info.active, StablecoinError::BurnerInactive);
+        let burned = burner_info
+            .burned
+            .checked_add(amount)
+            .ok_or(StablecoinError::BurnLimitExceeded)?;
+        if let Some(burn_limit) = burner_info.burn_limit {
+            require!(burned <= burn_limit, StablecoinError::BurnLimitExceeded);
+        }
+
+        burner_info.burned = burned;
+
+        let cpi_accounts = BurnCpi {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.token_account.to_account_info(),
+            authority: ctx.accounts.burner.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token_2022::burn(cpi_ctx, amount)?;
+
+        msg!("Burned {} tokens from {}", amount, ctx.accounts.token_account.key());
+
+        emit_cpi!(TokensBurned {
+            mint: ctx.accounts.mint.key(),
+            burner: ctx.accounts.burner.key(),
+            token_account: ctx.accounts.token_account.key(),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Burn tokens from any holder's account for an issuer-side redemption (SSS-2)
+    /// Uses the permanent delegate PDA, so the holder does not need to sign
+    pub fn burn_from(ctx: Context<BurnFrom>, amount: u64, redemption_id: String) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let burner_info = &mut ctx.accounts.burner_info;
+
+        require!(!config.is_paused(PAUSE_BURN), StablecoinError::TokenPaused);
+        require!(
+            config.enable_permanent_delegate,
+            StablecoinError::PermanentDelegateNotEnabled
+        );
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        require!(redemption_id.len() <= 64, StablecoinError::InvalidAccount);
+        require!(burner_info.active, StablecoinError::BurnerInactive);
+        let burned = burner_info
+            .burned
+            .checked_add(amount)
+            .ok_or(StablecoinError::BurnLimitExceeded)?;
+        if let Some(burn_limit) = burner_info.burn_limit {
+            require!(burned <= burn_limit, StablecoinError::BurnLimitExceeded);
+        }
+
+        burner_info.burned = burned;
+
+        let permanent_delegate_bump = ctx.bumps.permanent_delegate;
+        let mint_key = ctx.accounts.mint.key();
+
+        // Sign with the permanent delegate PDA
+        let delegate_seeds = &[
+            b"permanent_delegate".as_ref(),
+            mint_key.as_ref(),
+            &[permanent_delegate_bump],
+        ];
+        let delegate_signer = &[&delegate_seeds[..]];
+
+        let cpi_accounts = BurnCpi {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.token_account.to_account_info(),
+            authority: ctx.accounts.permanent_delegate.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, delegate_signer);
+        token_2022::burn(cpi_ctx, amount)?;
+
+        msg!(
+            "Burned {} tokens from {} for redemption {}",
+            amount,
+            ctx.accounts.token_account.key(),
+            redemption_id
+        );
+
+        emit_cpi!(RedemptionBurned {
+            mint: mint_key,
+            burner: ctx.accounts.burner.key(),
+            token_account: ctx.accounts.token_account.key(),
+            amount,
+            redemption_id,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Freeze a token account
+    /// Alias of `freeze_token_account_pda`, kept for existing clients: the mint's freeze
+    /// authority is always the freeze authority PDA, so a keypair can no longer sign for it
+    pub fn freeze_token_account(ctx: Context<FreezeTokenAccountPda>) -> Result<()> {
+        freeze_token_account_pda(ctx)
+    }
+
+    /// Freeze a token account using PDA-based freeze authority
+    /// This is for mints where the freeze authority is set to the program's PDA
+    pub fn freeze_token_account_pda(ctx: Context<FreezeTokenAccountPda>) -> Result<()> {
+        require!(!ctx.accounts.config.is_paused(PAUSE_FREEZE), StablecoinError::TokenPaused);
+
+        let freeze_authority_bump = ctx.bumps.freeze_authority;
+        let mint_key = ctx.accounts.mint.key();
+
+        // Sign with the freeze authority PDA
+        let freeze_authority_seeds = &[
+            b"freeze_authority".as_ref(),
+            mint_key.as_ref(),
+            &[freeze_authority_bump],
+        ];
+        let freeze_authority_signer = &[&freeze_authority_seeds[..]];
+
+        let cpi_accounts = FreezeAccountCpi {
+            account: ctx.accounts.token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.freeze_authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, freeze_authority_signer);
+        token_2022::freeze_account(cpi_ctx)?;
+
+        msg!("Frozen account {} using PDA authority", ctx.accounts.token_account.key());
+
+        emit_cpi!(AccountFrozen {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.seizer.key(),
+            token_account: ctx.accounts.token_account.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Thaw a token account using PDA-based freeze authority
+    /// This is for mints where the freeze authority is set to the program's PDA
+    pub fn thaw_token_account_pda(ctx: Context<ThawTokenAccountPda>) -> Result<()> {
+        require!(!ctx.accounts.config.is_paused(PAUSE_FREEZE), StablecoinError::TokenPaused);
+
+        let freeze_authority_bump = ctx.bumps.freeze_authority;
+        let mint_key = ctx.accounts.mint.key();
+
+        // Sign with the freeze authority PDA
+        let freeze_authority_seeds = &[
+            b"freeze_authority".as_ref(),
+            mint_key.as_ref(),
+            &[freeze_authority_bump],
+        ];
+        let freeze_authority_signer = &[&freeze_authority_seeds[..]];
+
+        let cpi_accounts = ThawAccountCpi {
+            account: ctx.accounts.token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.freeze_authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, freeze_authority_signer);
+        token_2022::thaw_account(cpi_ctx)?;
+
+        msg!("Thawed account {} using PDA authority", ctx.accounts.token_account.key());
+
+        emit_cpi!(AccountThawed {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.seizer.key(),
+            token_account: ctx.accounts.token_account.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Thaw a token account
+    /// Alias of `thaw_token_account_pda`, kept for existing clients (see `freeze_token_account`)
+    pub fn thaw_token_account(ctx: Context<ThawTokenAccountPda>) -> Result<()> {
+        thaw_token_account_pda(ctx)
+    }
+
+    /// Pause the operations selected by `mask` (PAUSE_* flags)
+    /// Flags already set stay set; PAUSE_ALL stops everything except freeze/thaw
+    pub fn pause(ctx: Context<Pause>, mask: u8) -> Result<()> {
+        require!(mask != 0 && mask & !PAUSE_FLAGS == 0, StablecoinError::InvalidPauseMask);
+
+        let config = &mut ctx.accounts.config;
+        config.pause_mask |= mask;
+        msg!("Token paused: mask {:#04x}, now {:#04x}", mask, config.pause_mask);
+
+        emit_cpi!(Paused {
+            mint: ctx.accounts.mint.key(),
+            pauser: ctx.accounts.pauser.key(),
+            mask,
+            pause_mask: ctx.accounts.config.pause_mask,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Unpause the operations selected by `mask` (PAUSE_* flags)
+    pub fn unpause(ctx: Context<Unpause>, mask: u8) -> Result<()> {
+        require!(mask != 0 && mask & !PAUSE_FLAGS == 0, StablecoinError::InvalidPauseMask);
+
+        let config = &mut ctx.accounts.config;
+        config.pause_mask &= !mask;
+        msg!("Token unpaused: mask {:#04x}, now {:#04x}", mask, config.pause_mask);
+
+        emit_cpi!(Unpaused {
+            mint: ctx.accounts.mint.key(),
+            pauser: ctx.accounts.pauser.key(),
+            mask,
+            pause_mask: ctx.accounts.config.pause_mask,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Add a minter with specified quota
+    /// Once an admin delay is set this goes through `queue_admin_action` instead
+    pub fn add_minter(ctx: Context<AddMinter>, quota: u64) -> Result<()> {
+        require!(!ctx.accounts.config.is_timelocked(), StablecoinError::TimelockRequired);
+
+        let minter_info = &mut ctx.accounts.minter_info;
+
+        minter_info.authority = ctx.accounts.minter.key();
+        minter_info.quota = quota;
+        minter_info.minted = 0;
+        minter_info.bump = ctx.bumps.minter_info;
+
+        minter_info.period = AllowancePeriod::Seconds;
+        minter_info.period_length = 0;
+        minter_info.period_limit = 0;
+        minter_info.period_minted = 0;
+        minter_info.period_start = 0;
+        minter_info.version = ACCOUNT_VERSION;
+
+        msg!("Added minter {} with quota {}", ctx.accounts.minter.key(), quota);
+
+        emit_cpi!(MinterAdded {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            minter: ctx.accounts.minter.key(),
+            quota,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Update minter quota
+    /// Once an admin delay is set, increases go through `queue_admin_action`;
+    /// lowering a quota always applies immediately
+    pub fn update_minter_quota(ctx: Context<UpdateMinterQuota>, new_quota: u64) -> Result<()> {
+        let minter_info = &mut ctx.accounts.minter_info;
+        let old_quota = minter_info.quota;
+        if new_quota > old_quota {
+            require!(!ctx.accounts.config.is_timelocked(), StablecoinError::TimelockRequired);
+        }
+        minter_info.quota = new_quota;
+
+        msg!("Updated minter quota to {}", new_quota);
+
+        emit_cpi!(MinterQuotaUpdated {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            minter: ctx.accounts.minter.key(),
+            old_quota,
+            new_quota,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Set or clear a minter's period allowance
+    /// `period_limit = 0` disables the allowance; the lifetime quota still applies.
+    /// The new window starts at the current time/epoch.
+    /// Once an admin delay is set, loosening changes go through `queue_admin_action`;
+    /// tighter allowances apply immediately and keep what was minted in the current window
+    pub fn update_minter_allowance(
+        ctx: Context<UpdateMinterAllowance>,
+        period: AllowancePeriod,
+        period_length: u64,
+        period_limit: u64,
+    ) -> Result<()> {
+        require!(
+            period_limit == 0 || period_length > 0,
+            StablecoinError::InvalidAmount
+        );
+
+        let minter_info = &mut ctx.accounts.minter_info;
+        let clock = Clock::get()?;
+
+        let mut period_minted = 0;
+        if ctx.accounts.config.is_timelocked() {
+            require!(
+                !minter_info.loosens_allowance(period, period_length, period_limit),
+                StablecoinError::TimelockRequired
+            );
+            // Restarting the window must not hand the minter a fresh allowance
+            minter_info.roll_period(&clock);
+            if minter_info.has_period_allowance() {
+                period_minted = minter_info.period_minted;
+            }
+        }
+        minter_info.set_allowance(period, period_length, period_limit, period_minted, &clock);
+
+        msg!(
+            "Updated minter allowance to {} per {} {:?}",
+            period_limit,
+            period_length,
+            period
+        );
+
+        emit_cpi!(MinterAllowanceUpdated {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            minter: ctx.accounts.minter.key(),
+            period,
+            period_length,
+            period_limit,
+            timestamp: clock.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Remove a minter
+    pub fn remove_minter(ctx: Context<RemoveMinter>) -> Result<()> {
+        let minter_info = &mut ctx.accounts.minter_info;
+        minter_info.quota = 0;
+
+        msg!("Removed minter {}", ctx.accounts.minter.key());
+
+        emit_cpi!(MinterRemoved {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            minter: ctx.accounts.minter.key(),
+            minted: ctx.accounts.minter_info.minted,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Register a burner with an optional burn limit
+    /// A previously removed burner is reactivated; its burned total carries over
+    /// Once an admin delay is set this goes through `queue_admin_action` instead
+    pub fn add_burner(ctx: Context<AddBurner>, burn_limit: Option<u64>) -> Result<()> {
+        require!(!ctx.accounts.config.is_timelocked(), StablecoinError::TimelockRequired);
+
+        let burner_info = &mut ctx.accounts.burner_info;
+
+        if burner_info.authority == Pubkey::default() {
+            burner_info.authority = ctx.accounts.burner.key();
+            burner_info.burned = 0;
+        } else {
+            require!(
+                burner_info.version == ACCOUNT_VERSION,
+                StablecoinError::OutdatedAccountVersion
+            );
+            require!(!burner_info.active, StablecoinError::BurnerAlreadyActive);
+        }
+        burner_info.burn_limit = burn_limit;
+        burner_info.active = true;
+        burner_info.bump = ctx.bumps.burner_info;
+        burner_info.version = ACCOUNT_VERSION;
+
+        msg!("Added burner {} with limit {:?}", ctx.accounts.burner.key(), burn_limit);
+
+        emit_cpi!(BurnerAdded {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            burner: ctx.accounts.burner.key(),
+            burn_limit,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Remove a burner
+    /// The BurnerInfo account is kept so burned amounts can still be reconciled
+    pub fn remove_burner(ctx: Context<RemoveBurner>) -> Result<()> {
+        let burner_info = &mut ctx.accounts.burner_info;
+        burner_info.active = false;
+
+        msg!("Removed burner {} (burned {})", ctx.accounts.burner.key(), burner_info.burned);
+
+        emit_cpi!(BurnerRemoved {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            burner: ctx.accounts.burner.key(),
+            burned: ctx.accounts.burner_info.burned,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Grant a role to a holder
+    /// Once an admin delay is set this goes through `queue_admin_action` instead
+    pub fn grant_role(ctx: Context<GrantRole>, role: RoleKind) -> Result<()> {
+        require!(!ctx.accounts.config.is_timelocked(), StablecoinError::TimelockRequired);
+
+        let role_assignment = &mut ctx.accounts.role_assignment;
+
+        role_assignment.config = ctx.accounts.config.key();
+        role_assignment.role = role;
+        role_assignment.holder = ctx.accounts.holder.key();
+        role_assignment.granted_at = Clock::get()?.unix_timestamp;
+        role_assignment.bump = ctx.bumps.role_assignment;
+        role_assignment.version = ACCOUNT_VERSION;
+
+        msg!("Granted {:?} role to {}", role, ctx.accounts.holder.key());
+
+        emit_cpi!(RoleGranted {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            role,
+            holder: ctx.accounts.holder.key(),
+            timestamp: ctx.accounts.role_assignment.granted_at,
+        });
+        Ok(())
+    }
+
+    /// Revoke a role from a holder
+    /// The `close = master_authority` constraint on the account handles closing automatically
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: RoleKind) -> Result<()> {
+        msg!("Revoked {:?} role from {}", role, ctx.accounts.holder.key());
+
+        emit_cpi!(RoleRevoked {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            role,
+            holder: ctx.accounts.holder.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Update roles (blacklister, pauser, seizer)
+    /// Compatibility path: sets the single legacy holder of each role in config.
+    /// Prefer `grant_role`/`revoke_role` for multiple holders per role.
+    /// Once an admin delay is set this goes through `queue_admin_action` instead.
+    pub fn update_roles(
+        ctx: Context<UpdateRoles>,
+        new_blacklister: Pubkey,
+        new_pauser: Pubkey,
+        new_seizer: Pubkey,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(!config.is_timelocked(), StablecoinError::TimelockRequired);
+
+        config.blacklister = new_blacklister;
+        config.pauser = new_pauser;
+        config.seizer = new_seizer;
+
+        msg!("Updated roles");
+
+        emit_cpi!(RolesUpdated {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            blacklister: new_blacklister,
+            pauser: new_pauser,
+            seizer: new_seizer,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Add an address to the blacklist (SSS-2)
+    /// `expires_at` makes the entry a temporary hold; it must be in the future.
+    /// A lapsed entry has to be purged before the address can be blacklisted again.
+    /// The user's token accounts passed in `remaining_accounts` are frozen in the same
+    /// instruction with the freeze authority PDA; each must be owned by the user and
+    /// hold the config mint. Accounts that are already frozen are left as they are.
+    pub fn add_to_blacklist<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AddToBlacklist<'info>>,
+        reason_code: BlacklistReason,
+        reason: String,
+        case_id: String,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
+
+        require!(config.enable_transfer_hook, StablecoinError::ComplianceNotEnabled);
+        require!(!config.is_paused(PAUSE_BLACKLIST), StablecoinError::TokenPaused);
+        require!(reason.len() <= 100, StablecoinError::InvalidAccount);
+        require!(case_id.len() <= 64, StablecoinError::InvalidAccount);
+
+        let blacklist_entry = &mut ctx.accounts.blacklist_entry;
+        let clock = Clock::get()?;
+
+        if let Some(expires_at) = expires_at {
+            require!(expires_at > clock.unix_timestamp, StablecoinError::InvalidAccount);
+        }
+
+        blacklist_entry.user = ctx.accounts.user.key();
+        blacklist_entry.reason_code = reason_code;
+        blacklist_entry.reason = reason;
+        blacklist_entry.case_id = case_id;
+        blacklist_entry.added_by = ctx.accounts.blacklister.key();
+        blacklist_entry.timestamp = clock.unix_timestamp;
+        blacklist_entry.expires_at = expires_at;
+        blacklist_entry.bump = ctx.bumps.blacklist_entry;
+        blacklist_entry.version = ACCOUNT_VERSION;
+
+        msg!(
+            "Added {} to blacklist: {:?} case {} ({})",
+            ctx.accounts.user.key(),
+            reason_code,
+            blacklist_entry.case_id,
+            blacklist_entry.reason
+        );
+
+        emit_cpi!(Blacklisted {
+            mint: ctx.accounts.mint.key(),
+            blacklister: ctx.accounts.blacklister.key(),
+            user: ctx.accounts.user.key(),
+            reason_code,
+            reason: ctx.accounts.blacklist_entry.reason.clone(),
+            case_id: ctx.accounts.blacklist_entry.case_id.clone(),
+            expires_at,
+            timestamp: clock.unix_timestamp,
+        });
+
+        if ctx.remaining_accounts.is_empty() {
+            return Ok(());
+        }
+
+        // Freeze the user's token accounts so funds cannot move through paths that bypass the hook
+        require!(!ctx.accounts.config.is_paused(PAUSE_FREEZE), StablecoinError::TokenPaused);
+
+        let freeze_authority_bump = ctx.bumps.freeze_authority;
+        let mint_key = ctx.accounts.mint.key();
+
+        // Sign with the freeze authority PDA
+        let freeze_authority_seeds = &[
+            b"freeze_authority".as_ref(),
+            mint_key.as_ref(),
+            &[freeze_authority_bump],
+        ];
+        let freeze_authority_signer = &[&freeze_authority_seeds[..]];
+
+        for account_info in ctx.remaining_accounts.iter() {
+            let token_account = InterfaceAccount::<TokenAccount>::try_from(account_info)?;
+            require_keys_eq!(token_account.owner, ctx.accounts.user.key(), StablecoinError::InvalidAccount);
+            require_keys_eq!(token_account.mint, ctx.accounts.config.mint, StablecoinError::InvalidAccount);
+
+            if token_account.is_frozen() {
+                continue;
+            }
+
+            let cpi_accounts = FreezeAccountCpi {
+                account: account_info.clone(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.freeze_authority.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, freeze_authority_signer);
+            token_2022::freeze_account(cpi_ctx)?;
+
+            msg!("Frozen account {} of blacklisted user", account_info.key());
+
+            emit_cpi!(AccountFrozen {
+                mint: mint_key,
+                authority: ctx.accounts.blacklister.key(),
+                token_account: account_info.key(),
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        Ok(())
+    }
+
+    /// Remove an address from the blacklist (SSS-2)
+    /// The `close = blacklister` constraint on the account handles closing automatically
+    pub fn remove_from_blacklist(ctx: Context<RemoveFromBlacklist>) -> Result<()> {
+        let config = &ctx.accounts.config;
+
+        require!(config.enable_transfer_hook, StablecoinError::ComplianceNotEnabled);
+        require!(!config.is_paused(PAUSE_BLACKLIST), StablecoinError::TokenPaused);
+
+        msg!("Removed {} from blacklist", ctx.accounts.user.key());
+
+        emit_cpi!(BlacklistRemoved {
+            mint: ctx.accounts.mint.key(),
+            blacklister: ctx.accounts.blacklister.key(),
+            user: ctx.accounts.user.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Add many addresses to the blacklist in one instruction (SSS-2)
+    /// `remaining_accounts` holds the BlacklistEntry PDA of each user, in the same order as `users`.
+    /// Users that already have an entry are skipped, so a sanctions list update can be replayed.
+    /// Token accounts are not frozen here; use `add_to_blacklist` for atomic blacklist-and-freeze.
+    pub fn batch_add_to_blacklist<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchAddToBlacklist<'info>>,
+        users: Vec<Pubkey>,
+        reason_code: BlacklistReason,
+        reason: String,
+        case_id: String,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
+
+        require!(config.enable_transfer_hook, StablecoinError::ComplianceNotEnabled);
+        require!(!config.is_paused(PAUSE_BLACKLIST), StablecoinError::TokenPaused);
+        require!(
+            !users.is_empty() && users.len() <= MAX_BLACKLIST_BATCH,
+            StablecoinError::InvalidAmount
+        );
+        require!(
+            users.len() == ctx.remaining_accounts.len(),
+            StablecoinError::InvalidAccount
+        );
+        require!(reason.len() <= 100, StablecoinError::InvalidAccount);
+        require!(case_id.len() <= 64, StablecoinError::InvalidAccount);
+
+        let clock = Clock::get()?;
+        if let Some(expires_at) = expires_at {
+            require!(expires_at > clock.unix_timestamp, StablecoinError::InvalidAccount);
+        }
+
+        let config_key = config.key();
+        let blacklister_key = ctx.accounts.blacklister.key();
+        let mut applied: u32 = 0;
+
+        for (user, entry_info) in users.iter().zip(ctx.remaining_accounts.iter()) {
+            let (expected_entry, bump) = Pubkey::find_program_address(
+                &[b"blacklist", config_key.as_ref(), user.as_ref()],
+                ctx.program_id,
+            );
+            require_keys_eq!(entry_info.key(), expected_entry, StablecoinError::InvalidAccount);
+
+            // Already blacklisted: skip instead of aborting the batch
+            if !entry_info.data_is_empty() {
+                continue;
+            }
+
+            // Sign with the blacklist entry PDA to create it
+            let entry_seeds = &[
+                b"blacklist".as_ref(),
+                config_key.as_ref(),
+                user.as_ref(),
+                &[bump],
+            ];
+            create_pda_account(
+                &ctx.accounts.blacklister.to_account_info(),
+                entry_info,
+                &ctx.accounts.system_program.to_account_info(),
+                BlacklistEntry::LEN,
+                &[&entry_seeds[..]],
+            )?;
+
+            let blacklist_entry = BlacklistEntry {
+                user: *user,
+                reason_code,
+                reason: reason.clone(),
+                case_id: case_id.clone(),
+                added_by: blacklister_key,
+                timestamp: clock.unix_timestamp,
+                expires_at,
+                bump,
+                version: ACCOUNT_VERSION,
+                reserved: [0; 32],
+            };
+            blacklist_entry.try_serialize(&mut &mut entry_info.try_borrow_mut_data()?[..])?;
+            applied += 1;
+
+            emit_cpi!(Blacklisted {
+                mint: ctx.accounts.mint.key(),
+                blacklister: blacklister_key,
+                user: *user,
+                reason_code,
+                reason: reason.clone(),
+                case_id: case_id.clone(),
+                expires_at,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+
+        msg!("Batch blacklisted {} of {} users: {:?} case {}", applied, users.len(), reason_code, case_id);
+
+        emit_cpi!(BlacklistBatchUpdated {
+            mint: ctx.accounts.mint.key(),
+            blacklister: blacklister_key,
+            added: true,
+            requested: users.len() as u32,
+            applied,
+            timestamp: clock.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Remove many addresses from the blacklist in one instruction (SSS-2)
+    /// `remaining_accounts` holds the BlacklistEntry PDA of each user, in the same order as `users`.
+    /// Users without an entry are skipped; closed entries refund rent to the blacklister.
+    pub fn batch_remove_from_blacklist<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchRemoveFromBlacklist<'info>>,
+        users: Vec<Pubkey>,
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
+
+        require!(config.enable_transfer_hook, StablecoinError::ComplianceNotEnabled);
+        require!(!config.is_paused(PAUSE_BLACKLIST), StablecoinError::TokenPaused);
+        require!(
+            !users.is_empty() && users.len() <= MAX_BLACKLIST_BATCH,
+            StablecoinError::InvalidAmount
+        );
+        require!(
+            users.len() == ctx.remaining_accounts.len(),
+            StablecoinError::InvalidAccount
+        );
+
+        let config_key = config.key();
+        let blacklister_key = ctx.accounts.blacklister.key();
+        let clock = Clock::get()?;
+        let mut applied: u32 = 0;
+
+        for (user, entry_info) in users.iter().zip(ctx.remaining_accounts.iter()) {
+            let (expected_entry, _bump) = Pubkey::find_program_address(
+                &[b"blacklist", config_key.as_ref(), user.as_ref()],
+                ctx.program_id,
+            );
+            require_keys_eq!(entry_info.key(), expected_entry, StablecoinError::InvalidAccount);
+
+            // Not blacklisted: skip instead of aborting the batch
+            if entry_info.data_is_empty() {
+                continue;
+            }
+
+            let blacklist_entry = Account::<BlacklistEntry>::try_from(entry_info)?;
+            blacklist_entry.close(ctx.accounts.blacklister.to_account_info())?;
+            applied += 1;
+
+            emit_cpi!(BlacklistRemoved {
+                mint: ctx.accounts.mint.key(),
+                blacklister: blacklister_key,
+                user: *user,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+
+        msg!("Batch removed {} of {} users from blacklist", applied, users.len());
+
+        emit_cpi!(BlacklistBatchUpdated {
+            mint: ctx.accounts.mint.key(),
+            blacklister: blacklister_key,
+            added: false,
+            requested: users.len() as u32,
+            applied,
+            timestamp: clock.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Close a lapsed blacklist entry (SSS-2)
+    /// Permissionless: anyone may call once `expires_at` has passed.
+    /// Rent is refunded to the blacklister who created the entry.
+    pub fn purge_expired_blacklist_entry(ctx: Context<PurgeExpiredBlacklistEntry>) -> Result<()> {
+        let blacklist_entry = &ctx.accounts.blacklist_entry;
+        let clock = Clock::get()?;
+
+        require!(!ctx.accounts.config.is_paused(PAUSE_BLACKLIST), StablecoinError::TokenPaused);
+        require!(
+            blacklist_entry.is_expired(clock.unix_timestamp),
+            StablecoinError::BlacklistEntryNotExpired
+        );
+        let expired_at = blacklist_entry.expires_at.unwrap_or_default();
+
+        msg!("Purged expired blacklist entry for {}", ctx.accounts.user.key());
+
+        emit_cpi!(BlacklistEntryPurged {
+            mint: ctx.accounts.mint.key(),
+            caller: ctx.accounts.caller.key(),
+            user: ctx.accounts.user.key(),
+            expired_at,
+            timestamp: clock.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Create the escrow token account that holds seized funds (SSS-2)
+    /// The escrow is owned by the config PDA; funds leave it only via `release_seized_funds`
+    pub fn initialize_escrow(ctx: Context<InitializeEscrow>) -> Result<()> {
+        // DefaultAccountState::Frozen applies to the escrow too; thaw it so seizures can land
+        if ctx.accounts.config.default_account_frozen {
+            let mint_key = ctx.accounts.mint.key();
+
+            // Sign with the freeze authority PDA
+            let freeze_authority_seeds = &[
+                b"freeze_authority".as_ref(),
+                mint_key.as_ref(),
+                &[ctx.bumps.freeze_authority],
+            ];
+            let freeze_authority_signer = &[&freeze_authority_seeds[..]];
+
+            let cpi_accounts = ThawAccountCpi {
+                account: ctx.accounts.escrow.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.freeze_authority.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, freeze_authority_signer);
+            token_2022::thaw_account(cpi_ctx)?;
+        }
+
+        msg!("Initialized seizure escrow {}", ctx.accounts.escrow.key());
+
+        emit_cpi!(EscrowInitialized {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            escrow: ctx.accounts.escrow.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Seize tokens from a blacklisted or frozen account into escrow (SSS-2)
+    /// Uses the permanent delegate PDA to transfer from frozen accounts
+    /// Funds go to the escrow and are recorded on a new SeizureCase PDA for `case_id`
+    /// The source owner must have an unexpired BlacklistEntry (passed as `blacklist_entry`),
+    /// or the source account must already be frozen by the freeze authority PDA.
+    /// `amount = None` seizes the entire balance of the source account.
+    /// Note: This thaws a frozen source using the freeze authority PDA, transfers using
+    /// the permanent delegate PDA, then freezes the source again.
+    /// On transfer-hook mints, `remaining_accounts` carries the hook program, its
+    /// ExtraAccountMetaList and the extra accounts it lists.
+    pub fn seize<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Seize<'info>>,
+        amount: Option<u64>,
+        case_id: String,
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let clock = Clock::get()?;
+
+        let amount = amount.unwrap_or(ctx.accounts.source_token.amount);
+
+        require!(
+            config.enable_permanent_delegate,
+            StablecoinError::PermanentDelegateNotEnabled
+        );
+        require!(!config.is_paused(PAUSE_SEIZE), StablecoinError::TokenPaused);
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        require!(
+            !case_id.is_empty() && case_id.len() <= MAX_CASE_ID_LEN,
+            StablecoinError::InvalidAccount
+        );
+
+        let source_frozen = ctx.accounts.source_token.is_frozen();
+        require!(
+            source_frozen || is_actively_blacklisted(&ctx.accounts.blacklist_entry, clock.unix_timestamp),
+            StablecoinError::SeizureNotPermitted
+        );
+
+        let permanent_delegate_bump = ctx.bumps.permanent_delegate;
+        let freeze_authority_bump = ctx.bumps.freeze_authority;
+        let mint_key = ctx.accounts.mint.key();
+
+        // Step 1: Thaw the source account using the freeze authority PDA
+        let freeze_authority_seeds = &[
+            b"freeze_authority".as_ref(),
+            mint_key.as_ref(),
+            &[freeze_authority_bump],
+        ];
+        let freeze_authority_signer = &[&freeze_authority_seeds[..]];
+
+        if source_frozen {
+            let thaw_accounts = ThawAccountCpi {
+                account: ctx.accounts.source_token.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.freeze_authority.to_account_info(),
+            };
+            let thaw_program = ctx.accounts.token_program.to_account_info();
+            let thaw_ctx = CpiContext::new_with_signer(thaw_program, thaw_accounts, freeze_authority_signer);
+            token_2022::thaw_account(thaw_ctx)?;
+
+            msg!("Thawed account {} for seizure", ctx.accounts.source_token.key());
+        }
+
+        // Step 2: Transfer using the permanent delegate PDA
+        let delegate_seeds = &[
+            b"permanent_delegate".as_ref(),
+            mint_key.as_ref(),
+            &[permanent_delegate_bump],
+        ];
+        let delegate_signer = &[&delegate_seeds[..]];
+
+        invoke_transfer_checked(
+            ctx.accounts.token_program.key,
+            ctx.accounts.source_token.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.escrow.to_account_info(),
+            ctx.accounts.permanent_delegate.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            config.decimals,
+            delegate_signer,
+        )?;
+
+        // Step 3: Freeze the source again so the remaining balance stays locked
+        let freeze_accounts = FreezeAccountCpi {
+            account: ctx.accounts.source_token.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.freeze_authority.to_account_info(),
+        };
+        let freeze_program = ctx.accounts.token_program.to_account_info();
+        let freeze_ctx = CpiContext::new_with_signer(freeze_program, freeze_accounts, freeze_authority_signer);
+        token_2022::freeze_account(freeze_ctx)?;
+
+        // Step 4: Record the case
+        let seizure_case = &mut ctx.accounts.seizure_case;
+        seizure_case.config = ctx.accounts.config.key();
+        seizure_case.case_id = case_id.clone();
+        seizure_case.source_owner = ctx.accounts.source_token.owner;
+        seizure_case.source_token = ctx.accounts.source_token.key();
+        seizure_case.seizer = ctx.accounts.seizer.key();
+        seizure_case.amount = amount;
+        seizure_case.released = 0;
+        seizure_case.seized_at = clock.unix_timestamp;
+        seizure_case.bump = ctx.bumps.seizure_case;
+        seizure_case.version = ACCOUNT_VERSION;
+
+        msg!(
+            "Seized {} tokens from {} into escrow for case {}",
+            amount,
+            ctx.accounts.source_token.key(),
+            case_id
+        );
+
+        emit_cpi!(Seized {
+            mint: mint_key,
+            seizer: ctx.accounts.seizer.key(),
+            source_token: ctx.accounts.source_token.key(),
+            dest_token: ctx.accounts.escrow.key(),
+            amount,
+            timestamp: clock.unix_timestamp,
+            case_id,
+        });
+        Ok(())
+    }
+
+    /// Sweep the entire balance of several token accounts of one owner into escrow (SSS-2)
+    /// The first `source_count` entries of `remaining_accounts` are the source accounts; each
+    /// must hold the config mint and be owned by `owner`. Each must be frozen unless `owner`
+    /// has an unexpired BlacklistEntry. Any further entries are the transfer-hook accounts,
+    /// as for `seize`.
+    /// Empty accounts are skipped. All amounts are recorded on one SeizureCase for `case_id`,
+    /// and a Seized event is emitted per account.
+    pub fn seize_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SeizeMany<'info>>,
+        case_id: String,
+        source_count: u8,
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let clock = Clock::get()?;
+
+        require!(
+            config.enable_permanent_delegate,
+            StablecoinError::PermanentDelegateNotEnabled
+        );
+        require!(!config.is_paused(PAUSE_SEIZE), StablecoinError::TokenPaused);
+        require!(
+            !case_id.is_empty() && case_id.len() <= MAX_CASE_ID_LEN,
+            StablecoinError::InvalidAccount
+        );
+        let source_count = source_count as usize;
+        require!(
+            (1..=MAX_SEIZE_ACCOUNTS).contains(&source_count)
+                && source_count <= ctx.remaining_accounts.len(),
+            StablecoinError::InvalidAmount
+        );
+        let (sources, hook_accounts) = ctx.remaining_accounts.split_at(source_count);
+
+        let owner_blacklisted = is_actively_blacklisted(&ctx.accounts.blacklist_entry, clock.unix_timestamp);
+
+        let mint_key = ctx.accounts.mint.key();
+        let decimals = config.decimals;
+
+        // Sign with the freeze authority PDA
+        let freeze_authority_seeds = &[
+            b"freeze_authority".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.bumps.freeze_authority],
+        ];
+        let freeze_authority_signer = &[&freeze_authority_seeds[..]];
+
+        // Sign with the permanent delegate PDA
+        let delegate_seeds = &[
+            b"permanent_delegate".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.bumps.permanent_delegate],
+        ];
+        let delegate_signer = &[&delegate_seeds[..]];
+
+        let mut total: u64 = 0;
+
+        for source_info in sources.iter() {
+            let source_token = InterfaceAccount::<TokenAccount>::try_from(source_info)?;
+            require_keys_eq!(source_token.owner, ctx.accounts.owner.key(), StablecoinError::InvalidAccount);
+            require_keys_eq!(source_token.mint, ctx.accounts.config.mint, StablecoinError::InvalidAccount);
+
+            let source_frozen = source_token.is_frozen();
+            require!(source_frozen || owner_blacklisted, StablecoinError::SeizureNotPermitted);
+
+            let amount = source_token.amount;
+            if amount == 0 {
+                continue;
+            }
+
+            if source_frozen {
+                let thaw_accounts = ThawAccountCpi {
+                    account: source_info.clone(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    authority: ctx.accounts.freeze_authority.to_account_info(),
+                };
+                let thaw_program = ctx.accounts.token_program.to_account_info();
+                let thaw_ctx = CpiContext::new_with_signer(thaw_program, thaw_accounts, freeze_authority_signer);
+                token_2022::thaw_account(thaw_ctx)?;
+            }
+
+            invoke_transfer_checked(
+                ctx.accounts.token_program.key,
+                source_info.clone(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.escrow.to_account_info(),
+                ctx.accounts.permanent_delegate.to_account_info(),
+                hook_accounts,
+                amount,
+                decimals,
+                delegate_signer,
+            )?;
+
+            let freeze_accounts = FreezeAccountCpi {
+                account: source_info.clone(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.freeze_authority.to_account_info(),
+            };
+            let freeze_program = ctx.accounts.token_program.to_account_info();
+            let freeze_ctx = CpiContext::new_with_signer(freeze_program, freeze_accounts, freeze_authority_signer);
+            token_2022::freeze_account(freeze_ctx)?;
+
+            total = total.checked_add(amount).ok_or(StablecoinError::InvalidAmount)?;
+
+            msg!("Seized {} tokens from {} into escrow", amount, source_info.key());
+
+            emit_cpi!(Seized {
+                mint: mint_key,
+                seizer: ctx.accounts.seizer.key(),
+                source_token: source_info.key(),
+                dest_token: ctx.accounts.escrow.key(),
+                amount,
+                timestamp: clock.unix_timestamp,
+                case_id: case_id.clone(),
+            });
+        }
+
+        require!(total > 0, StablecoinError::InvalidAmount);
+
+        // Several source accounts: source_token is left unset on the case
+        let seizure_case = &mut ctx.accounts.seizure_case;
+        seizure_case.config = ctx.accounts.config.key();
+        seizure_case.case_id = case_id.clone();
+        seizure_case.source_owner = ctx.accounts.owner.key();
+        seizure_case.source_token = Pubkey::default();
+        seizure_case.seizer = ctx.accounts.seizer.key();
+        seizure_case.amount = total;
+        seizure_case.released = 0;
+        seizure_case.seized_at = clock.unix_timestamp;
+        seizure_case.bump = ctx.bumps.seizure_case;
+        seizure_case.version = ACCOUNT_VERSION;
+
+        msg!(
+            "Seized {} tokens from {} into escrow for case {}",
+            total,
+            ctx.accounts.owner.key(),
+            case_id
+        );
+        Ok(())
+    }
+
+    /// Pay escrowed funds of a seizure case to the treasury or a claimant (SSS-2)
+    /// Restricted to the master authority or holders of the Legal role, for either target.
+    /// Partial releases are allowed; the case keeps track of what is left.
+    /// On transfer-hook mints, `remaining_accounts` carries the hook accounts, as for `seize`.
+    pub fn release_seized_funds<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReleaseSeizedFunds<'info>>,
+        case_id: String,
+        amount: u64,
+        target: ReleaseTarget,
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let seizure_case = &ctx.accounts.seizure_case;
+
+        require!(!config.is_paused(PAUSE_SEIZE), StablecoinError::TokenPaused);
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        require!(amount <= seizure_case.remaining()?, StablecoinError::ReleaseExceedsEscrow);
+        if target == ReleaseTarget::Treasury {
+            require_keys_eq!(
+                ctx.accounts.destination.key(),
+                config.treasury,
+                StablecoinError::InvalidTreasury
+            );
+        }
+
+        let mint_key = ctx.accounts.mint.key();
+
+        // Sign with the config PDA (the escrow owner)
+        let config_seeds = &[
+            b"config".as_ref(),
+            mint_key.as_ref(),
+            &[config.bump],
+        ];
+        let config_signer = &[&config_seeds[..]];
+
+        invoke_transfer_checked(
+            ctx.accounts.token_program.key,
+            ctx.accounts.escrow.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.destination.to_account_info(),
+            config.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            config.decimals,
+            config_signer,
+        )?;
+
+        let seizure_case = &mut ctx.accounts.seizure_case;
+        seizure_case.released = seizure_case
+            .released
+            .checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+
+        msg!(
+            "Released {} tokens for case {} to {} ({:?})",
+            amount,
+            case_id,
+            ctx.accounts.destination.key(),
+            target
+        );
+
+        emit_cpi!(SeizedFundsReleased {
+            mint: mint_key,
+            authority: ctx.accounts.authority.key(),
+            case_id,
+            destination: ctx.accounts.destination.key(),
+            target,
+            amount,
+            remaining: ctx.accounts.seizure_case.remaining()?,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Set the treasury token account that receives released seized funds
+    /// Only the master authority can change the treasury; the seizer cannot
+    /// Once an admin delay is set this goes through `queue_admin_action` instead
+    pub fn set_treasury(ctx: Context<SetTreasury>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(!config.is_timelocked(), StablecoinError::TimelockRequired);
+        let old_treasury = config.treasury;
+        config.treasury = ctx.accounts.treasury.key();
+
+        msg!("Updated treasury to {}", config.treasury);
+
+        emit_cpi!(TreasuryUpdated {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            old_treasury,
+            new_treasury: ctx.accounts.treasury.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Update the token name, symbol and/or URI
+    /// Writes the Token-2022 TokenMetadata (update authority = config PDA)
+    /// and keeps the copies on StablecoinConfig in sync. `None` leaves a field unchanged.
+    pub fn update_metadata(
+        ctx: Context<UpdateMetadata>,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+    ) -> Result<()> {
+        if let Some(name) = &name {
+            require!(name.len() <= 100, StablecoinError::InvalidAccount);
+        }
+        if let Some(symbol) = &symbol {
+            require!(symbol.len() <= 10, StablecoinError::InvalidAccount);
+        }
+        if let Some(uri) = &uri {
+            require!(uri.len() <= 200, StablecoinError::InvalidAccount);
+        }
+
+        let mint_key = ctx.accounts.mint.key();
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let token_program = ctx.accounts.token_program.to_account_info();
+
+        let old_metadata = read_token_metadata(&mint_info)?;
+        let mut new_metadata = old_metadata.clone();
+        let mut updates = vec![];
+        if let Some(name) = name {
+            new_metadata.name = name.clone();
+            updates.push((Field::Name, name));
+        }
+        if let Some(symbol) = symbol {
+            new_metadata.symbol = symbol.clone();
+            updates.push((Field::Symbol, symbol));
+        }
+        if let Some(uri) = uri {
+            new_metadata.uri = uri.clone();
+            updates.push((Field::Uri, uri));
+        }
+
+        fund_metadata_resize(
+            &ctx.accounts.authority,
+            &mint_info,
+            &ctx.accounts.system_program,
+            &old_metadata,
+            &new_metadata,
+        )?;
+
+        // Sign with the config PDA (the metadata update authority)
+        let config_seeds = &[
+            b"config".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.accounts.config.bump],
+        ];
+        let config_signer = &[&config_seeds[..]];
+
+        for (field, value) in updates {
+            let cpi_accounts = TokenMetadataUpdateField {
+                program_id: token_program.clone(),
+                metadata: mint_info.clone(),
+                update_authority: ctx.accounts.config.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, config_signer);
+            token_interface::token_metadata_update_field(cpi_ctx, field, value)?;
+        }
+
+        let config = &mut ctx.accounts.config;
+        config.name = new_metadata.name;
+        config.symbol = new_metadata.symbol;
+        config.uri = new_metadata.uri;
+
+        msg!("Updated metadata: {} ({})", config.name, config.symbol);
+
+        emit_cpi!(MetadataUpdated {
+            mint: mint_key,
+            authority: ctx.accounts.authority.key(),
+            name: ctx.accounts.config.name.clone(),
+            symbol: ctx.accounts.config.symbol.clone(),
+            uri: ctx.accounts.config.uri.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Set a custom key/value field in the mint's TokenMetadata
+    /// Used for issuer disclosures (legal entity, jurisdiction, attestation URL, ...).
+    /// name/symbol/uri are reserved; use `update_metadata` for those.
+    pub fn set_metadata_field(ctx: Context<SetMetadataField>, key: String, value: String) -> Result<()> {
+        require!(
+            !key.is_empty() && key.len() <= MAX_METADATA_KEY_LEN,
+            StablecoinError::InvalidAccount
+        );
+        require!(
+            !matches!(key.as_str(), "name" | "symbol" | "uri"),
+            StablecoinError::InvalidAccount
+        );
+        require!(value.len() <= MAX_METADATA_VALUE_LEN, StablecoinError::InvalidAccount);
+
+        let mint_key = ctx.accounts.mint.key();
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let token_program = ctx.accounts.token_program.to_account_info();
+
+        let old_metadata = read_token_metadata(&mint_info)?;
+        let mut new_metadata = old_metadata.clone();
+        new_metadata.update(Field::Key(key.clone()), value.clone());
+        require!(
+            new_metadata.additional_metadata.len() <= MAX_METADATA_FIELDS,
+            StablecoinError::TooManyMetadataFields
+        );
+
+        fund_metadata_resize(
+            &ctx.accounts.master_authority,
+            &mint_info,
+            &ctx.accounts.system_program,
+            &old_metadata,
+            &new_metadata,
+        )?;
+
+        // Sign with the config PDA (the metadata update authority)
+        let config_seeds = &[
+            b"config".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.accounts.config.bump],
+        ];
+        let config_signer = &[&config_seeds[..]];
+
+        let cpi_accounts = TokenMetadataUpdateField {
+            program_id: token_program.clone(),
+            metadata: mint_info,
+            update_authority: ctx.accounts.config.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, config_signer);
+        token_interface::token_metadata_update_field(cpi_ctx, Field::Key(key.clone()), value.clone())?;
+
+        msg!("Set metadata field {} = {}", key, value);
+
+        emit_cpi!(MetadataFieldSet {
+            mint: mint_key,
+            authority: ctx.accounts.master_authority.key(),
+            key,
+            value,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Remove a custom key/value field from the mint's TokenMetadata
+    /// Token-2022 shrinks the mint; the freed rent stays on the mint account
+    pub fn remove_metadata_field(ctx: Context<RemoveMetadataField>, key: String) -> Result<()> {
+        let mint_key = ctx.accounts.mint.key();
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let config_info = ctx.accounts.config.to_account_info();
+
+        let metadata = read_token_metadata(&mint_info)?;
+        require!(
+            metadata.additional_metadata.iter().any(|(k, _)| *k == key),
+            StablecoinError::MetadataFieldNotFound
+        );
+
+        // Sign with the config PDA (the metadata update authority)
+        let config_seeds = &[
+            b"config".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.accounts.config.bump],
+        ];
+        let config_signer = &[&config_seeds[..]];
+
+        // anchor-spl has no remove_key wrapper, so build the instruction directly
+        let ix = anchor_spl::token_interface::spl_token_metadata_interface::instruction::remove_key(
+            &ctx.accounts.token_program.key(),
+            &mint_key,
+            &config_info.key(),
+            key.clone(),
+            false,
+        );
+        anchor_lang::solana_program::program::invoke_signed(
+            &ix,
+            &[mint_info, config_info],
+            config_signer,
+        )?;
+
+        msg!("Removed metadata field {}", key);
+
+        emit_cpi!(MetadataFieldRemoved {
+            mint: mint_key,
+            authority: ctx.accounts.master_authority.key(),
+            key,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Update the global supply cap (0 = uncapped)
+    /// A cap below the current supply is allowed and blocks further minting
+    /// Once an admin delay is set, raising or removing the cap goes through
+    /// `queue_admin_action`; lowering it still applies immediately
+    pub fn update_max_supply(ctx: Context<UpdateMaxSupply>, new_max_supply: u64) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        if config.raises_max_supply(new_max_supply) {
+            require!(!config.is_timelocked(), StablecoinError::TimelockRequired);
+        }
+        let old_max_supply = config.max_supply;
+        config.max_supply = new_max_supply;
+
+        msg!("Updated max supply to {}", new_max_supply);
+
+        emit_cpi!(MaxSupplyUpdated {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            old_max_supply,
+            new_max_supply,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Transfer master authority
+    /// Kept for compatibility: this only proposes the new authority,
+    /// which must then call `accept_master_authority`
+    pub fn transfer_authority(
+        ctx: Context<TransferAuthority>,
+        new_master_authority: Pubkey,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(!config.is_timelocked(), StablecoinError::TimelockRequired);
+        config.pending_master_authority = Some(new_master_authority);
+
+        msg!("Proposed master authority {}", new_master_authority);
+
+        emit_cpi!(AuthorityTransferProposed {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            pending_authority: new_master_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Propose a new master authority (step 1 of 2)
+    /// Replaces any previously pending proposal
+    /// Once an admin delay is set this goes through `queue_admin_action` instead
+    pub fn propose_master_authority(
+        ctx: Context<ProposeMasterAuthority>,
+        new_master_authority: Pubkey,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(!config.is_timelocked(), StablecoinError::TimelockRequired);
+        config.pending_master_authority = Some(new_master_authority);
+
+        msg!("Proposed master authority {}", new_master_authority);
+
+        emit_cpi!(AuthorityTransferProposed {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            pending_authority: new_master_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Accept a pending master authority transfer (step 2 of 2)
+    /// Must be signed by the proposed authority
+    pub fn accept_master_authority(ctx: Context<AcceptMasterAuthority>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let new_master_authority = ctx.accounts.new_master_authority.key();
+
+        let pending = config
+            .pending_master_authority
+            .ok_or(StablecoinError::NoPendingAuthority)?;
+        require_keys_eq!(pending, new_master_authority, StablecoinError::Unauthorized);
+
+        let old_master_authority = config.master_authority;
+        config.master_authority = new_master_authority;
+        config.pending_master_authority = None;
+
+        msg!("Transferred master authority to {}", new_master_authority);
+
+        emit_cpi!(AuthorityTransferred {
+            mint: ctx.accounts.mint.key(),
+            old_authority: old_master_authority,
+            new_authority: new_master_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Cancel a pending master authority transfer
+    pub fn cancel_master_authority_transfer(
+        ctx: Context<CancelMasterAuthorityTransfer>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let pending = config
+            .pending_master_authority
+            .take()
+            .ok_or(StablecoinError::NoPendingAuthority)?;
+
+        msg!("Cancelled master authority transfer to {}", pending);
+
+        emit_cpi!(AuthorityTransferCancelled {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            cancelled_authority: pending,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Upgrade the config and its accounts to ACCOUNT_VERSION (master authority only)
+    /// remaining_accounts may carry MinterInfo, BurnerInfo, RoleAssignment, BlacklistEntry,
+    /// SeizureCase, PendingAdminAction, ReserveAttestation, MintRequest, AdminCouncil and
+    /// CouncilProposal accounts of this config. Accounts written before versioning are decoded
+    /// from their version 0 layout and grown to the current size; new fields take their defaults
+    /// and the version is bumped. Accounts that are already current are left as-is
+    pub fn migrate_config<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateConfig<'info>>,
+    ) -> Result<()> {
+        let config_info = ctx.accounts.config.to_account_info();
+        let config_key = config_info.key();
+        let payer = ctx.accounts.master_authority.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+
+        let config: StablecoinConfig = load_for_migration(&config_info, &payer, &system_program)?;
+        require_keys_eq!(config.master_authority, payer.key(), StablecoinError::Unauthorized);
+        require_keys_eq!(config.mint, ctx.accounts.mint.key(), StablecoinError::InvalidAccount);
+
+        let from_version = config.version;
+        let mut accounts_migrated: u32 = 0;
+        if store_migrated(&config_info, config)? {
+            accounts_migrated += 1;
+        }
+
+        for account in ctx.remaining_accounts.iter() {
+            let discriminator: [u8; 8] = account
+                .try_borrow_data()?
+                .get(..8)
+                .and_then(|d| d.try_into().ok())
+                .ok_or(StablecoinError::InvalidAccount)?;
+
+            let migrated = if discriminator == MinterInfo::DISCRIMINATOR {
+                let minter_info: MinterInfo = load_for_migration(account, &payer, &system_program)?;
+                require_pda(account, &[b"minter", config_key.as_ref(), minter_info.authority.as_ref()], minter_info.bump)?;
+                store_migrated(account, minter_info)?
+            } else if discriminator == BurnerInfo::DISCRIMINATOR {
+                let burner_info: BurnerInfo = load_for_migration(account, &payer, &system_program)?;
+                require_pda(account, &[b"burner", config_key.as_ref(), burner_info.authority.as_ref()], burner_info.bump)?;
+                store_migrated(account, burner_info)?
+            } else if discriminator == RoleAssignment::DISCRIMINATOR {
+                let role_assignment: RoleAssignment = load_for_migration(account, &payer, &system_program)?;
+                require_keys_eq!(role_assignment.config, config_key, StablecoinError::InvalidAccount);
+                store_migrated(account, role_assignment)?
+            } else if discriminator == BlacklistEntry::DISCRIMINATOR {
+                let blacklist_entry: BlacklistEntry = load_for_migration(account, &payer, &system_program)?;
+                require_pda(account, &[b"blacklist", config_key.as_ref(), blacklist_entry.user.as_ref()], blacklist_entry.bump)?;
+                store_migrated(account, blacklist_entry)?
+            } else if discriminator == SeizureCase::DISCRIMINATOR {
+                let seizure_case: SeizureCase = load_for_migration(account, &payer, &system_program)?;
+                require_keys_eq!(seizure_case.config, config_key, StablecoinError::InvalidAccount);
+                store_migrated(account, seizure_case)?
+            } else if discriminator == PendingAdminAction::DISCRIMINATOR {
+                let pending_action: PendingAdminAction = load_for_migration(account, &payer, &system_program)?;
+                require_keys_eq!(pending_action.config, config_key, StablecoinError::InvalidAccount);
+                store_migrated(account, pending_action)?
+            } else if discriminator == ReserveAttestation::DISCRIMINATOR {
+                let attestation: ReserveAttestation = load_for_migration(account, &payer, &system_program)?;
+                require_keys_eq!(attestation.config, config_key, StablecoinError::InvalidAccount);
+                store_migrated(account, attestation)?
+            } else if discriminator == MintRequest::DISCRIMINATOR {
+                let mint_request: MintRequest = load_for_migration(account, &payer, &system_program)?;
+                require_keys_eq!(mint_request.config, config_key, StablecoinError::InvalidAccount);
+                store_migrated(account, mint_request)?
+            } else if discriminator == UsedFiatReference::DISCRIMINATOR {
+                let used_reference: UsedFiatReference = load_for_migration(account, &payer, &system_program)?;
+                require_keys_eq!(used_reference.config, config_key, StablecoinError::InvalidAccount);
+                store_migrated(account, used_reference)?
+            } else if discriminator == AdminCouncil::DISCRIMINATOR {
+                let council: AdminCouncil = load_for_migration(account, &payer, &system_program)?;
+                require_keys_eq!(council.config, config_key, StablecoinError::InvalidAccount);
+                store_migrated(account, council)?
+            } else if discriminator == CouncilProposal::DISCRIMINATOR {
+                let proposal: CouncilProposal = load_for_migration(account, &payer, &system_program)?;
+                let (council_key, _) = Pubkey::find_program_address(&[b"council", config_key.as_ref()], ctx.program_id);
+                require_keys_eq!(proposal.council, council_key, StablecoinError::InvalidAccount);
+                store_migrated(account, proposal)?
+            } else {
+                return err!(StablecoinError::InvalidAccount);
+            };
+
+            if migrated {
+                accounts_migrated += 1;
+            }
+        }
+
+        msg!(
+            "Migrated {} accounts from version {} to {}",
+            accounts_migrated,
+            from_version,
+            ACCOUNT_VERSION
+        );
+
+        emit_cpi!(ConfigMigrated {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            from_version,
+            to_version: ACCOUNT_VERSION,
+            accounts_migrated,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Queue a sensitive admin change (master authority only)
+    /// It can be executed once `config.admin_delay` seconds have passed. With no delay set
+    /// it can be executed right away, which is how the first delay and guardian are configured.
+    /// Emergency actions (`pause`, freezes, blacklisting) are never queued.
+    pub fn queue_admin_action(ctx: Context<QueueAdminAction>, action: AdminAction) -> Result<()> {
+        match action {
+            AdminAction::SetAdminDelay { delay } => {
+                require!((0..=MAX_ADMIN_DELAY).contains(&delay), StablecoinError::InvalidAmount);
+            }
+            AdminAction::UpdateMinterAllowance { period_length, period_limit, .. } => {
+                require!(
+                    period_limit == 0 || period_length > 0,
+                    StablecoinError::InvalidAmount
+                );
+            }
+            AdminAction::SetReservePolicy { max_attestation_age, collateral_ratio_bps, .. } => {
+                StablecoinConfig::validate_reserve_policy(max_attestation_age, collateral_ratio_bps)?;
+            }
+            AdminAction::SetMintApprovalPolicy { approvals_required, .. } => {
+                StablecoinConfig::validate_mint_approval_policy(approvals_required)?;
+            }
+            _ => {}
+        }
+
+        let clock = Clock::get()?;
+        let config = &mut ctx.accounts.config;
+        let action_id = config.next_admin_action_id;
+        let eta = clock.unix_timestamp + config.admin_delay;
+        config.next_admin_action_id += 1;
+
+        let pending_action = &mut ctx.accounts.pending_action;
+        pending_action.config = config.key();
+        pending_action.id = action_id;
+        pending_action.action = action;
+        pending_action.proposer = ctx.accounts.master_authority.key();
+        pending_action.queued_at = clock.unix_timestamp;
+        pending_action.eta = eta;
+        pending_action.bump = ctx.bumps.pending_action;
+        pending_action.version = ACCOUNT_VERSION;
+
+        msg!("Queued admin action {}: {:?}, executable at {}", action_id, action, eta);
+
+        emit_cpi!(AdminActionQueued {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            action_id,
+            action,
+            eta,
+            timestamp: clock.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Apply a queued admin action once its delay has elapsed (master authority only)
+    /// AddMinter, UpdateMinterQuota and UpdateMinterAllowance need the minter's MinterInfo PDA
+    /// in `minter_info`, AddBurner the BurnerInfo PDA in `burner_info`, GrantRole the
+    /// RoleAssignment PDA in `role_assignment`, SetTreasury the token account in `treasury`.
+    /// The action account is closed and its rent returned to the proposer.
+    pub fn execute_admin_action(ctx: Context<ExecuteAdminAction>) -> Result<()> {
+        let clock = Clock::get()?;
+        let pending_action = &ctx.accounts.pending_action;
+        require!(clock.unix_timestamp >= pending_action.eta, StablecoinError::TimelockNotElapsed);
+
+        let action_id = pending_action.id;
+        let action = pending_action.action;
+        let mint_key = ctx.accounts.mint.key();
+        let authority = ctx.accounts.master_authority.key();
+        let config_key = ctx.accounts.config.key();
+
+        match action {
+            AdminAction::AddMinter { minter, quota } => {
+                let minter_info = ctx
+                    .accounts
+                    .minter_info
+                    .as_ref()
+                    .ok_or(StablecoinError::InvalidAccount)?;
+                let (expected_minter_info, bump) = Pubkey::find_program_address(
+                    &[b"minter", config_key.as_ref(), minter.as_ref()],
+                    ctx.program_id,
+                );
+                require_keys_eq!(minter_info.key(), expected_minter_info, StablecoinError::InvalidAccount);
+                require!(minter_info.data_is_empty(), StablecoinError::InvalidAccount);
+
+                let minter_seeds = &[b"minter".as_ref(), config_key.as_ref(), minter.as_ref(), &[bump]];
+                create_pda_account(
+                    &ctx.accounts.master_authority.to_account_info(),
+                    &minter_info.to_account_info(),
+                    &ctx.accounts.system_program.to_account_info(),
+                    MinterInfo::LEN,
+                    &[&minter_seeds[..]],
+                )?;
+
+                let new_minter_info = MinterInfo {
+                    authority: minter,
+                    quota,
+                    minted: 0,
+                    bump,
+                    period: AllowancePeriod::Seconds,
+                    period_length: 0,
+                    period_limit: 0,
+                    period_minted: 0,
+                    period_start: 0,
+                    version: ACCOUNT_VERSION,
+                    reserved: [0; 32],
+                };
+                new_minter_info.try_serialize(&mut &mut minter_info.try_borrow_mut_data()?[..])?;
+
+                emit_cpi!(MinterAdded {
+                    mint: mint_key,
+                    authority,
+                    minter,
+                    quota,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+            AdminAction::UpdateMinterQuota { minter, new_quota } => {
+                let minter_info_account = ctx
+                    .accounts
+                    .minter_info
+                    .as_ref()
+                    .ok_or(StablecoinError::InvalidAccount)?;
+                require_keys_eq!(*minter_info_account.owner, crate::ID, StablecoinError::InvalidAccount);
+                let mut minter_info = MinterInfo::try_deserialize(&mut &minter_info_account.try_borrow_data()?[..])?;
+                require_pda(
+                    minter_info_account,
+                    &[b"minter", config_key.as_ref(), minter.as_ref()],
+                    minter_info.bump,
+                )?;
+                require!(minter_info.version == ACCOUNT_VERSION, StablecoinError::OutdatedAccountVersion);
+
+                let old_quota = minter_info.quota;
+                minter_info.quota = new_quota;
+                minter_info.try_serialize(&mut &mut minter_info_account.try_borrow_mut_data()?[..])?;
+
+                emit_cpi!(MinterQuotaUpdated {
+                    mint: mint_key,
+                    authority,
+                    minter,
+                    old_quota,
+                    new_quota,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+            AdminAction::UpdateMinterAllowance { minter, period, period_length, period_limit } => {
+                let minter_info_account = ctx
+                    .accounts
+                    .minter_info
+                    .as_ref()
+                    .ok_or(StablecoinError::InvalidAccount)?;
+                require_keys_eq!(*minter_info_account.owner, crate::ID, StablecoinError::InvalidAccount);
+                let mut minter_info = MinterInfo::try_deserialize(&mut &minter_info_account.try_borrow_data()?[..])?;
+                require_pda(
+                    minter_info_account,
+                    &[b"minter", config_key.as_ref(), minter.as_ref()],
+                    minter_info.bump,
+                )?;
+                require!(minter_info.version == ACCOUNT_VERSION, StablecoinError::OutdatedAccountVersion);
+
+                minter_info.set_allowance(period, period_length, period_limit, 0, &clock);
+                minter_info.try_serialize(&mut &mut minter_info_account.try_borrow_mut_data()?[..])?;
+
+                emit_cpi!(MinterAllowanceUpdated {
+                    mint: mint_key,
+                    authority,
+                    minter,
+                    period,
+                    period_length,
+                    period_limit,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+            AdminAction::AddBurner { burner, burn_limit } => {
+                let burner_info_account = ctx
+                    .accounts
+                    .burner_info
+                    .as_ref()
+                    .ok_or(StablecoinError::InvalidAccount)?;
+                let (expected_burner_info, bump) = Pubkey::find_program_address(
+                    &[b"burner", config_key.as_ref(), burner.as_ref()],
+                    ctx.program_id,
+                );
+                require_keys_eq!(burner_info_account.key(), expected_burner_info, StablecoinError::InvalidAccount);
+
+                // A previously removed burner is reactivated; its burned total carries over
+                let burned = if burner_info_account.data_is_empty() {
+                    let burner_seeds = &[b"burner".as_ref(), config_key.as_ref(), burner.as_ref(), &[bump]];
+                    create_pda_account(
+                        &ctx.accounts.master_authority.to_account_info(),
+                        &burner_info_account.to_account_info(),
+                        &ctx.accounts.system_program.to_account_info(),
+                        BurnerInfo::LEN,
+                        &[&burner_seeds[..]],
+                    )?;
+                    0
+                } else {
+                    require_keys_eq!(*burner_info_account.owner, crate::ID, StablecoinError::InvalidAccount);
+                    let burner_info = BurnerInfo::try_deserialize(&mut &burner_info_account.try_borrow_data()?[..])?;
+                    require!(burner_info.version == ACCOUNT_VERSION, StablecoinError::OutdatedAccountVersion);
+                    require!(!burner_info.active, StablecoinError::BurnerAlreadyActive);
+                    burner_info.burned
+                };
+
+                let new_burner_info = BurnerInfo {
+                    authority: burner,
+                    burn_limit,
+                    burned,
+                    active: true,
+                    bump,
+                    version: ACCOUNT_VERSION,
+                    reserved: [0; 32],
+                };
+                new_burner_info.try_serialize(&mut &mut burner_info_account.try_borrow_mut_data()?[..])?;
+
+                emit_cpi!(BurnerAdded {
+                    mint: mint_key,
+                    authority,
+                    burner,
+                    burn_limit,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+            AdminAction::UpdateRoles { blacklister, pauser, seizer } => {
+                let config = &mut ctx.accounts.config;
+                config.blacklister = blacklister;
+                config.pauser = pauser;
+                config.seizer = seizer;
+
+                emit_cpi!(RolesUpdated {
+                    mint: mint_key,
+                    authority,
+                    blacklister,
+                    pauser,
+                    seizer,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+            AdminAction::TransferAuthority { new_master_authority } => {
+                ctx.accounts.config.pending_master_authority = Some(new_master_authority);
+
+                emit_cpi!(AuthorityTransferProposed {
+                    mint: mint_key,
+                    authority,
+                    pending_authority: new_master_authority,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+            AdminAction::SetAdminDelay { delay } => {
+                ctx.accounts.config.admin_delay = delay;
+            }
+            AdminAction::SetGuardian { guardian } => {
+                ctx.accounts.config.guardian = guardian;
+            }
+            AdminAction::SetReservePolicy { reserve_gating, max_attestation_age, collateral_ratio_bps } => {
+                let config = &mut ctx.accounts.config;
+                config.reserve_gating = reserve_gating;
+                config.max_attestation_age = max_attestation_age;
+                config.collateral_ratio_bps = collateral_ratio_bps;
+
+                emit_cpi!(ReservePolicyUpdated {
+                    mint: mint_key,
+                    authority,
+                    reserve_gating,
+                    max_attestation_age,
+                    collateral_ratio_bps,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+            AdminAction::SetMintApprovalPolicy { approvals_required, requests_only } => {
+                let config = &mut ctx.accounts.config;
+                config.mint_approvals_required = approvals_required;
+                config.mint_requests_only = requests_only;
+
+                emit_cpi!(MintApprovalPolicyUpdated {
+                    mint: mint_key,
+                    authority,
+                    approvals_required,
+                    requests_only,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+            AdminAction::GrantRole { role, holder } => {
+                let role_assignment = ctx
+                    .accounts
+                    .role_assignment
+                    .as_ref()
+                    .ok_or(StablecoinError::InvalidAccount)?;
+                let (expected_role_assignment, bump) = Pubkey::find_program_address(
+                    &[b"role", config_key.as_ref(), role.seed(), holder.as_ref()],
+                    ctx.program_id,
+                );
+                require_keys_eq!(role_assignment.key(), expected_role_assignment, StablecoinError::InvalidAccount);
+                require!(role_assignment.data_is_empty(), StablecoinError::InvalidAccount);
+
+                let role_seeds = &[b"role".as_ref(), config_key.as_ref(), role.seed(), holder.as_ref(), &[bump]];
+                create_pda_account(
+                    &ctx.accounts.master_authority.to_account_info(),
+                    &role_assignment.to_account_info(),
+                    &ctx.accounts.system_program.to_account_info(),
+                    RoleAssignment::LEN,
+                    &[&role_seeds[..]],
+                )?;
+
+                let new_role_assignment = RoleAssignment {
+                    config: config_key,
+                    role,
+                    holder,
+                    granted_at: clock.unix_timestamp,
+                    bump,
+                    version: ACCOUNT_VERSION,
+                    reserved: [0; 32],
+                };
+                new_role_assignment.try_serialize(&mut &mut role_assignment.try_borrow_mut_data()?[..])?;
+
+                emit_cpi!(RoleGranted {
+                    mint: mint_key,
+                    authority,
+                    role,
+                    holder,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+            AdminAction::UpdateMaxSupply { new_max_supply } => {
+                let config = &mut ctx.accounts.config;
+                let old_max_supply = config.max_supply;
+                config.max_supply = new_max_supply;
+
+                emit_cpi!(MaxSupplyUpdated {
+                    mint: mint_key,
+                    authority,
+                    old_max_supply,
+                    new_max_supply,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+            AdminAction::SetTreasury { treasury } => {
+                let treasury_account = ctx
+                    .accounts
+                    .treasury
+                    .as_ref()
+                    .ok_or(StablecoinError::InvalidAccount)?;
+                require_keys_eq!(treasury_account.key(), treasury, StablecoinError::InvalidAccount);
+                require_keys_eq!(treasury_account.mint, mint_key, StablecoinError::InvalidAccount);
+
+                let config = &mut ctx.accounts.config;
+                let old_treasury = config.treasury;
+                config.treasury = treasury;
+
+                emit_cpi!(TreasuryUpdated {
+                    mint: mint_key,
+                    authority,
+                    old_treasury,
+                    new_treasury: treasury,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+        }
+
+        msg!("Executed admin action {}: {:?}", action_id, action);
+
+        emit_cpi!(AdminActionExecuted {
+            mint: mint_key,
+            authority,
+            action_id,
+            action,
+            timestamp: clock.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Drop a queued admin action before it is executed
+    /// Can be signed by the master authority or the configured guardian
+    pub fn cancel_admin_action(ctx: Context<CancelAdminAction>) -> Result<()> {
+        let pending_action = &ctx.accounts.pending_action;
+
+        msg!("Cancelled admin action {}: {:?}", pending_action.id, pending_action.action);
+
+        emit_cpi!(AdminActionCancelled {
+            mint: ctx.accounts.mint.key(),
+            cancelled_by: ctx.accounts.canceller.key(),
+            action_id: pending_action.id,
+            action: pending_action.action,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Create the M-of-N admin council (master authority only)
+    /// This does not hand over control: propose the council authority PDA as master
+    /// authority, then accept through a council proposal
+    pub fn create_council(ctx: Context<CreateCouncil>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        AdminCouncil::validate_members(&members, threshold)?;
+
+        let council = &mut ctx.accounts.council;
+        council.config = ctx.accounts.config.key();
+        council.members = members.clone();
+        council.threshold = threshold;
+        council.proposal_count = 0;
+        council.bump = ctx.bumps.council;
+        council.authority_bump = ctx.bumps.council_authority;
+        council.version = ACCOUNT_VERSION;
+
+        msg!("Created {}-of-{} admin council", threshold, members.len());
+
+        emit_cpi!(CouncilCreated {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            council_authority: ctx.accounts.council_authority.key(),
+            members,
+            threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Replace the council members and threshold
+    /// Must be signed by the council authority PDA, i.e. run through a council proposal
+    pub fn update_council(ctx: Context<UpdateCouncil>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        AdminCouncil::validate_members(&members, threshold)?;
+
+        let council = &mut ctx.accounts.council;
+        council.members = members.clone();
+        council.threshold = threshold;
+
+        msg!("Updated admin council to {}-of-{}", threshold, members.len());
+
+        emit_cpi!(CouncilUpdated {
+            mint: ctx.accounts.mint.key(),
+            members,
+            threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Propose an sss-token instruction for the council to sign (council members only)
+    /// The instruction must be one of `CouncilProposal::EXECUTABLE`. The proposal expires
+    /// at `expires_at`, at most MAX_COUNCIL_PROPOSAL_TTL from now. The proposer's approval
+    /// is recorded immediately
+    pub fn create_council_proposal(
+        ctx: Context<CreateCouncilProposal>,
+        accounts: Vec<ProposalAccount>,
+        data: Vec<u8>,
+        expires_at: i64,
+    ) -> Result<()> {
+        require!(accounts.len() <= MAX_PROPOSAL_ACCOUNTS, StablecoinError::InvalidAccount);
+        require!(
+            data.len() >= 8 && data.len() <= MAX_PROPOSAL_DATA_LEN,
+            StablecoinError::InvalidAccount
+        );
+        require!(
+            CouncilProposal::is_executable(&data),
+            StablecoinError::ProposalInstructionNotAllowed
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            expires_at > now && expires_at <= now + MAX_COUNCIL_PROPOSAL_TTL,
+            StablecoinError::InvalidAmount
+        );
+
+        let council = &mut ctx.accounts.council;
+        let proposal_id = council.proposal_count;
+        council.proposal_count += 1;
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.council = council.key();
+        proposal.id = proposal_id;
+        proposal.proposer = ctx.accounts.member.key();
+        proposal.accounts = accounts;
+        proposal.data = data.clone();
+        proposal.approvals = vec![ctx.accounts.member.key()];
+        proposal.created_at = now;
+        proposal.expires_at = expires_at;
+        proposal.bump = ctx.bumps.proposal;
+        proposal.version = ACCOUNT_VERSION;
+
+        msg!("Created council proposal {}", proposal_id);
+
+        emit_cpi!(CouncilProposalCreated {
+            mint: ctx.accounts.mint.key(),
+            proposal_id,
+            proposer: ctx.accounts.member.key(),
+            data,
+            expires_at,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    /// Approve an unexpired council proposal (council members only)
+    /// Approvals from keys removed from the council are dropped
+    pub fn approve_council_proposal(ctx: Context<ApproveCouncilProposal>) -> Result<()> {
+        let council = &ctx.accounts.council;
+        let proposal = &mut ctx.accounts.proposal;
+        let member = ctx.accounts.member.key();
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(!proposal.is_expired(now), StablecoinError::CouncilProposalExpired);
+        require!(!proposal.approvals.contains(&member), StablecoinError::AlreadyApproved);
+        proposal.approvals.retain(|approver| council.is_member(approver));
+        proposal.approvals.push(member);
+
+        let approvals = proposal.approvals.len() as u8;
+        msg!(
+            "Council proposal {} approved by {} ({}/{})",
+            proposal.id,
+            member,
+            approvals,
+            council.threshold
+        );
+
+        emit_cpi!(CouncilProposalApproved {
+            mint: ctx.accounts.mint.key(),
+            proposal_id: proposal.id,
+            member,
+            approvals,
+            threshold: council.threshold,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    /// Execute an unexpired council proposal once it has `threshold` approvals (council
+    /// members only)
+    /// The proposal's accounts must be passed in `remaining_accounts`; the instruction is
+    /// invoked with the council authority PDA as signer. The proposal is then closed, so it
+    /// cannot be executed twice.
+    pub fn execute_council_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteCouncilProposal<'info>>,
+    ) -> Result<()> {
+        let council = &ctx.accounts.council;
+        let proposal = &ctx.accounts.proposal;
+        require!(
+            !proposal.is_expired(Clock::get()?.unix_timestamp),
+            StablecoinError::CouncilProposalExpired
+        );
+        // Re-checked in case the proposal predates the whitelist
+        require!(
+            CouncilProposal::is_executable(&proposal.data),
+            StablecoinError::ProposalInstructionNotAllowed
+        );
+        require!(
+            proposal.approval_count(council) >= council.threshold as usize,
+            StablecoinError::ThresholdNotMet
+        );
+
+        // Sign with the council authority PDA
+        let config_key = ctx.accounts.config.key();
+        let council_authority_seeds = &[
+            b"council_authority".as_ref(),
+            config_key.as_ref(),
+            &[council.authority_bump],
+        ];
+        let council_authority_signer = &[&council_authority_seeds[..]];
+
+        let mut account_infos = ctx.remaining_accounts.to_vec();
+        account_infos.push(ctx.accounts.program.to_account_info());
+        anchor_lang::solana_program::program::invoke_signed(
+            &proposal.instruction(),
+            &account_infos,
+            council_authority_signer,
+        )?;
+
+        msg!("Executed council proposal {}", proposal.id);
+
+        emit_cpi!(CouncilProposalExecuted {
+            mint: ctx.accounts.mint.key(),
+            proposal_id: proposal.id,
+            executor: ctx.accounts.member.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Withdraw a council proposal (proposer only)
+    pub fn cancel_council_proposal(ctx: Context<CancelCouncilProposal>) -> Result<()> {
+        let proposal_id = ctx.accounts.proposal.id;
+
+        msg!("Cancelled council proposal {}", proposal_id);
+
+        emit_cpi!(CouncilProposalCancelled {
+            mint: ctx.accounts.mint.key(),
+            proposal_id,
+            proposer: ctx.accounts.proposer.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Post the latest reserve attestation (Attestor role only)
+    /// `as_of` must be in the past and newer than the current attestation
+    pub fn post_reserve_attestation(
+        ctx: Context<PostReserveAttestation>,
+        reserves: u64,
+        as_of: i64,
+        report_uri_hash: [u8; 32],
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let attestation = &mut ctx.accounts.reserve_attestation;
+        attestation.validate_update(as_of, now)?;
+
+        attestation.config = ctx.accounts.config.key();
+        attestation.reserves = reserves;
+        attestation.as_of = as_of;
+        attestation.report_uri_hash = report_uri_hash;
+        attestation.attestor = ctx.accounts.attestor.key();
+        attestation.posted_at = now;
+        attestation.bump = ctx.bumps.reserve_attestation;
+        attestation.version = ACCOUNT_VERSION;
+
+        msg!("Attested reserves of {} as of {}", reserves, as_of);
+
+        emit_cpi!(ReserveAttested {
+            mint: ctx.accounts.mint.key(),
+            attestor: ctx.accounts.attestor.key(),
+            reserves,
+            as_of,
+            report_uri_hash,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    /// Configure proof-of-reserves gating for minting (master authority only)
+    /// `collateral_ratio_bps` is the reserves required per unit of supply, e.g. 10_200 = 102%.
+    /// Once an admin delay is set this goes through `queue_admin_action` instead.
+    pub fn set_reserve_policy(
+        ctx: Context<SetReservePolicy>,
+        reserve_gating: bool,
+        max_attestation_age: i64,
+        collateral_ratio_bps: u16,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(!config.is_timelocked(), StablecoinError::TimelockRequired);
+        StablecoinConfig::validate_reserve_policy(max_attestation_age, collateral_ratio_bps)?;
+
+        config.reserve_gating = reserve_gating;
+        config.max_attestation_age = max_attestation_age;
+        config.collateral_ratio_bps = collateral_ratio_bps;
+
+        msg!(
+            "Reserve gating {}: max age {}s, collateral ratio {} bps",
+            reserve_gating,
+            max_attestation_age,
+            collateral_ratio_bps
+        );
+
+        emit_cpi!(ReservePolicyUpdated {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            reserve_gating,
+            max_attestation_age,
+            collateral_ratio_bps,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Configure the mint request workflow (master authority only)
+    /// `approvals_required` MintApprover sign-offs are needed per request; `requests_only`
+    /// disables `mint_tokens` so every mint goes through a request.
+    /// Once an admin delay is set this goes through `queue_admin_action` instead.
+    pub fn set_mint_approval_policy(
+        ctx: Context<SetMintApprovalPolicy>,
+        approvals_required: u8,
+        requests_only: bool,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(!config.is_timelocked(), StablecoinError::TimelockRequired);
+        StablecoinConfig::validate_mint_approval_policy(approvals_required)?;
+
+        config.mint_approvals_required = approvals_required;
+        config.mint_requests_only = requests_only;
+
+        msg!(
+            "Mint requests need {} approvals, requests only: {}",
+            approvals_required,
+            requests_only
+        );
+
+        emit_cpi!(MintApprovalPolicyUpdated {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.master_authority.key(),
+            approvals_required,
+            requests_only,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Request a mint backed by an incoming fiat payment (registered minters only)
+    /// The request expires at `expires_at`, at most MAX_MINT_REQUEST_TTL from now
+    pub fn create_mint_request(
+        ctx: Context<CreateMintRequest>,
+        amount: u64,
+        fiat_reference: String,
+        expires_at: i64,
+    ) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        require!(
+            !fiat_reference.is_empty() && fiat_reference.len() <= MAX_FIAT_REFERENCE_LEN,
+            StablecoinError::InvalidAccount
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            expires_at > now && expires_at <= now + MAX_MINT_REQUEST_TTL,
+            StablecoinError::InvalidAmount
+        );
+
+        let mint_request = &mut ctx.accounts.mint_request;
+        mint_request.config = ctx.accounts.config.key();
+        mint_request.minter = ctx.accounts.minter.key();
+        mint_request.recipient = ctx.accounts.recipient.key();
+        mint_request.amount = amount;
+        mint_request.fiat_reference = fiat_reference.clone();
+        mint_request.approvals = Vec::new();
+        mint_request.created_at = now;
+        mint_request.expires_at = expires_at;
+        mint_request.bump = ctx.bumps.mint_request;
+        mint_request.version = ACCOUNT_VERSION;
+
+        msg!(
+            "Mint request {} for {} tokens to {}",
+            fiat_reference,
+            amount,
+            ctx.accounts.recipient.key()
+        );
+
+        emit_cpi!(MintRequestCreated {
+            mint: ctx.accounts.mint.key(),
+            request: ctx.accounts.mint_request.key(),
+            minter: ctx.accounts.minter.key(),
+            recipient: ctx.accounts.recipient.key(),
+            amount,
+            fiat_reference,
+            expires_at,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    /// Approve a pending mint request (MintApprover role only)
+    /// The requesting minter cannot approve their own request.
+    /// `remaining_accounts` may carry the closed MintApprover RoleAssignment PDAs of earlier
+    /// approvers whose role was revoked; their approvals are dropped to make room.
+    pub fn approve_mint_request(ctx: Context<ApproveMintRequest>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let approver = ctx.accounts.approver.key();
+        let config_key = ctx.accounts.config.key();
+        let mint_request = &mut ctx.accounts.mint_request;
+
+        require!(!mint_request.is_expired(now), StablecoinError::MintRequestExpired);
+        require_keys_neq!(approver, mint_request.minter, StablecoinError::Unauthorized);
+        require!(!mint_request.approvals.contains(&approver), StablecoinError::AlreadyApproved);
+
+        let revoked = revoked_mint_approvers(&config_key, &mint_request.approvals, ctx.remaining_accounts);
+        mint_request.approvals.retain(|recorded| !revoked.contains(recorded));
+        require!(
+            mint_request.approvals.len() < MAX_MINT_APPROVALS,
+            StablecoinError::InvalidAmount
+        );
+        mint_request.approvals.push(approver);
+
+        let approvals = mint_request.approvals.len() as u8;
+        let approvals_required = ctx.accounts.config.mint_approval_threshold() as u8;
+        msg!(
+            "Mint request {} approved by {} ({}/{})",
+            mint_request.fiat_reference,
+            approver,
+            approvals,
+            approvals_required
+        );
+
+        emit_cpi!(MintRequestApproved {
+            mint: ctx.accounts.mint.key(),
+            request: ctx.accounts.mint_request.key(),
+            approver,
+            approvals,
+            approvals_required,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    /// Mint an approved request and close it (requesting minter only)
+    /// Subject to the same limits as `mint_tokens`; rent is refunded to the minter.
+    /// `remaining_accounts` carries the MintApprover RoleAssignment PDAs of the approvers;
+    /// approvals only count while the approver still holds the role.
+    /// The fiat reference is recorded in a UsedFiatReference PDA paid for by the minter.
+    pub fn execute_mint_request(ctx: Context<ExecuteMintRequest>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let config = &ctx.accounts.config;
+        let mint_request = &ctx.accounts.mint_request;
+
+        require!(!mint_request.is_expired(now), StablecoinError::MintRequestExpired);
+        let current_approvers = current_mint_approvers(&config.key(), ctx.remaining_accounts)?;
+        let approvers = mint_request.live_approvals(&current_approvers);
+        require!(
+            approvers.len() >= config.mint_approval_threshold(),
+            StablecoinError::ThresholdNotMet
+        );
+
+        let amount = mint_request.amount;
+        apply_mint_limits(
+            config,
+            &mut ctx.accounts.minter_info,
+            ctx.accounts.reserve_attestation.as_deref(),
+            ctx.accounts.mint.supply,
+            amount,
+        )?;
+
+        let mint_key = ctx.accounts.mint.key();
+        mint_with_authority_pda(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.recipient.to_account_info(),
+            &ctx.accounts.mint_authority.to_account_info(),
+            ctx.bumps.mint_authority,
+            amount,
+        )?;
+
+        let used_reference = &mut ctx.accounts.used_fiat_reference;
+        used_reference.config = config.key();
+        used_reference.minter = ctx.accounts.minter.key();
+        used_reference.recipient = ctx.accounts.recipient.key();
+        used_reference.amount = amount;
+        used_reference.executed_at = now;
+        used_reference.bump = ctx.bumps.used_fiat_reference;
+        used_reference.version = ACCOUNT_VERSION;
+
+        let mint_request = &ctx.accounts.mint_request;
+        msg!(
+            "Executed mint request {}: {} tokens to {}",
+            mint_request.fiat_reference,
+            amount,
+            ctx.accounts.recipient.key()
+        );
+
+        emit_cpi!(TokensMinted {
+            mint: mint_key,
+            minter: ctx.accounts.minter.key(),
+            token_account: ctx.accounts.recipient.key(),
+            amount,
+            minter_total_minted: ctx.accounts.minter_info.minted,
+            timestamp: now,
+        });
+        emit_cpi!(MintRequestExecuted {
+            mint: mint_key,
+            request: ctx.accounts.mint_request.key(),
+            minter: ctx.accounts.minter.key(),
+            recipient: ctx.accounts.recipient.key(),
+            amount,
+            fiat_reference: ctx.accounts.mint_request.fiat_reference.clone(),
+            approvers,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    /// Reject a pending mint request (master authority or MintApprover role)
+    /// Rent is refunded to the requesting minter
+    pub fn reject_mint_request(ctx: Context<RejectMintRequest>) -> Result<()> {
+        let mint_request = &ctx.accounts.mint_request;
+
+        msg!("Rejected mint request {}", mint_request.fiat_reference);
+
+        emit_cpi!(MintRequestRejected {
+            mint: ctx.accounts.mint.key(),
+            request: mint_request.key(),
+            rejected_by: ctx.accounts.authority.key(),
+            fiat_reference: mint_request.fiat_reference.clone(),
+            expired: false,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Close an expired mint request that was never executed
+    /// Permissionless: anyone may call once `expires_at` has passed.
+    /// Rent is refunded to the requesting minter.
+    pub fn close_expired_mint_request(ctx: Context<CloseExpiredMintRequest>) -> Result<()> {
+        let mint_request = &ctx.accounts.mint_request;
+        let now = Clock::get()?.unix_timestamp;
+        require!(mint_request.is_expired(now), StablecoinError::MintRequestNotExpired);
+
+        msg!("Closed expired mint request {}", mint_request.fiat_reference);
+
+        emit_cpi!(MintRequestRejected {
+            mint: ctx.accounts.mint.key(),
+            request: mint_request.key(),
+            rejected_by: ctx.accounts.caller.key(),
+            fiat_reference: mint_request.fiat_reference.clone(),
+            expired: true,
+            timestamp: now,
+        });
+        Ok(())
+    }
+}
+
+// ============================================
+// CONTEXT STRUCTS
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(
+    name: String,
+    symbol: String,
+    uri: String,
+    decimals: u8,
+    enable_permanent_delegate: bool,
+    enable_transfer_hook: bool,
+    default_account_frozen: bool,
+    max_supply: u64,
+)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = StablecoinConfig::LEN,
+        seeds = [b"config", mint.key().as_ref()],
+        bump
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// The new mint - created and initialized by this instruction
+    #[account(mut)]
+    pub mint: Signer<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint authority PDA - seeds: ["mint_authority", mint.key()]
+    #[account(
+        seeds = [b"mint_authority", mint.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the mint authority PDA set on the mint
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// The freeze authority PDA - seeds: ["freeze_authority", mint.key()]
+    #[account(
+        seeds = [b"freeze_authority", mint.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the freeze authority PDA set on the mint
+    pub freeze_authority: UncheckedAccount<'info>,
+
+    /// The permanent delegate PDA - seeds: ["permanent_delegate", mint.key()]
+    #[account(
+        seeds = [b"permanent_delegate", mint.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the permanent delegate PDA set on the mint when enabled
+    pub permanent_delegate: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MintTokens<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The mint authority PDA - seeds: ["mint_authority", mint.key()]
+    #[account(
+        seeds = [b"mint_authority", mint.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the mint authority PDA that signs via seeds
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"minter", config.key().as_ref(), minter.key().as_ref()],
+        bump = minter_info.bump,
+        constraint = minter_info.authority == minter.key() @ StablecoinError::Unauthorized,
+        constraint = minter_info.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+
+    /// The minter requesting the mint - must be the MinterInfo authority
+    pub minter: Signer<'info>,
+
+    #[account(mut)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    /// Reserve attestation - seeds: ["reserves", config.key()]
+    /// Required when config.reserve_gating is on
+    #[account(
+        seeds = [b"reserves", config.key().as_ref()],
+        bump = reserve_attestation.bump,
+        constraint = reserve_attestation.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub reserve_attestation: Option<Account<'info, ReserveAttestation>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BurnTokens<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"burner", config.key().as_ref(), burner.key().as_ref()],
+        bump = burner_info.bump,
+        constraint = burner_info.authority == burner.key() @ StablecoinError::Unauthorized,
+        constraint = burner_info.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub burner_info: Account<'info, BurnerInfo>,
+
+    /// The registered burner - must own or be delegated the token account
+    pub burner: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BurnFrom<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The holder's token account to burn from
+    #[account(mut)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"burner", config.key().as_ref(), burner.key().as_ref()],
+        bump = burner_info.bump,
+        constraint = burner_info.authority == burner.key() @ StablecoinError::Unauthorized,
+        constraint = burner_info.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub burner_info: Account<'info, BurnerInfo>,
+
+    pub burner: Signer<'info>,
+
+    /// The permanent delegate PDA - seeds: ["permanent_delegate", mint.key()]
+    #[account(
+        seeds = [b"permanent_delegate", mint.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the permanent delegate PDA that signs via seeds
+    pub permanent_delegate: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FreezeTokenAccountPda<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The freezer signer - must be the authorized seizer role
+    /// Either the legacy `seizer` in config or a holder of a Seizer RoleAssignment
+    #[account(
+        constraint = config.seizer == seizer.key() || role_assignment.is_some() @ StablecoinError::Unauthorized
+    )]
+    pub seizer: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", config.key().as_ref(), RoleKind::Seizer.seed(), seizer.key().as_ref()],
+        bump = role_assignment.bump,
+        constraint = role_assignment.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    /// The freeze authority PDA - seeds: ["freeze_authority", mint.key()]
+    #[account(
+        seeds = [b"freeze_authority", mint.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the freeze authority PDA that signs via seeds
+    pub freeze_authority: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ThawTokenAccountPda<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The thawer signer - must be the authorized seizer role
+    /// Either the legacy `seizer` in config or a holder of a Seizer RoleAssignment
+    #[account(
+        constraint = config.seizer == seizer.key() || role_assignment.is_some() @ StablecoinError::Unauthorized
+    )]
+    pub seizer: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", config.key().as_ref(), RoleKind::Seizer.seed(), seizer.key().as_ref()],
+        bump = role_assignment.bump,
+        constraint = role_assignment.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    /// The freeze authority PDA - seeds: ["freeze_authority", mint.key()]
+    #[account(
+        seeds = [b"freeze_authority", mint.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the freeze authority PDA that signs via seeds
+    pub freeze_authority: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        constraint = config.pauser == pauser.key() || role_assignment.is_some() @ StablecoinError::Unauthorized
+    )]
+    pub pauser: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", config.key().as_ref(), RoleKind::Pauser.seed(), pauser.key().as_ref()],
+        bump = role_assignment.bump,
+        constraint = role_assignment.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        constraint = config.pauser == pauser.key() || role_assignment.is_some() @ StablecoinError::Unauthorized
+    )]
+    pub pauser: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", config.key().as_ref(), RoleKind::Pauser.seed(), pauser.key().as_ref()],
+        bump = role_assignment.bump,
+        constraint = role_assignment.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddMinter<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: The minter's public key
+    pub minter: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = master_authority,
+        space = MinterInfo::LEN,
+        seeds = [b"minter", config.key().as_ref(), minter.key().as_ref()],
+        bump
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMinterQuota<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"minter", config.key().as_ref(), minter.key().as_ref()],
+        bump = minter_info.bump,
+        constraint = minter_info.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+
+    /// CHECK: The minter's public key
+    pub minter: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMinterAllowance<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"minter", config.key().as_ref(), minter.key().as_ref()],
+        bump = minter_info.bump,
+        constraint = minter_info.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+
+    /// CHECK: The minter's public key
+    pub minter: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveMinter<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"minter", config.key().as_ref(), minter.key().as_ref()],
+        bump = minter_info.bump,
+        constraint = minter_info.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+
+    /// CHECK: The minter's public key
+    pub minter: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddBurner<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: The burner's public key
+    pub burner: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = master_authority,
+        space = BurnerInfo::LEN,
+        seeds = [b"burner", config.key().as_ref(), burner.key().as_ref()],
+        bump
+    )]
+    pub burner_info: Account<'info, BurnerInfo>,
+
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveBurner<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"burner", config.key().as_ref(), burner.key().as_ref()],
+        bump = burner_info.bump,
+        constraint = burner_info.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub burner_info: Account<'info, BurnerInfo>,
+
+    /// CHECK: The burner's public key
+    pub burner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(role: RoleKind)]
+pub struct GrantRole<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: The holder receiving the role
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = master_authority,
+        space = RoleAssignment::LEN,
+        seeds = [b"role", config.key().as_ref(), role.seed(), holder.key().as_ref()],
+        bump
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(role: RoleKind)]
+pub struct RevokeRole<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: The holder losing the role
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = master_authority,
+        seeds = [b"role", config.key().as_ref(), role.seed(), holder.key().as_ref()],
+        bump = role_assignment.bump,
+        constraint = role_assignment.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateRoles<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddToBlacklist<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = config.blacklister == blacklister.key() || role_assignment.is_some() @ StablecoinError::Unauthorized
+    )]
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", config.key().as_ref(), RoleKind::Blacklister.seed(), blacklister.key().as_ref()],
+        bump = role_assignment.bump,
+        constraint = role_assignment.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    /// CHECK: The user to blacklist
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = blacklister,
+        space = BlacklistEntry::LEN,
+        seeds = [b"blacklist", config.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+
+    /// The freeze authority PDA - seeds: ["freeze_authority", mint.key()]
+    /// Freezes the token accounts passed in remaining_accounts
+    #[account(
+        seeds = [b"freeze_authority", mint.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the freeze authority PDA set on the mint
+    pub freeze_authority: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveFromBlacklist<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = config.blacklister == blacklister.key() || role_assignment.is_some() @ StablecoinError::Unauthorized
+    )]
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", config.key().as_ref(), RoleKind::Blacklister.seed(), blacklister.key().as_ref()],
+        bump = role_assignment.bump,
+        constraint = role_assignment.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    /// CHECK: The user to remove from blacklist
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = blacklister,
+        seeds = [b"blacklist", config.key().as_ref(), user.key().as_ref()],
+        bump = blacklist_entry.bump,
+        constraint = blacklist_entry.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BatchAddToBlacklist<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = config.blacklister == blacklister.key() || role_assignment.is_some() @ StablecoinError::Unauthorized
+    )]
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", config.key().as_ref(), RoleKind::Blacklister.seed(), blacklister.key().as_ref()],
+        bump = role_assignment.bump,
+        constraint = role_assignment.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BatchRemoveFromBlacklist<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = config.blacklister == blacklister.key() || role_assignment.is_some() @ StablecoinError::Unauthorized
+    )]
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", config.key().as_ref(), RoleKind::Blacklister.seed(), blacklister.key().as_ref()],
+        bump = role_assignment.bump,
+        constraint = role_assignment.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PurgeExpiredBlacklistEntry<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: The blacklisted user
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = added_by,
+        seeds = [b"blacklist", config.key().as_ref(), user.key().as_ref()],
+        bump = blacklist_entry.bump,
+        has_one = added_by @ StablecoinError::InvalidAccount,
+        constraint = blacklist_entry.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+
+    /// CHECK: Rent recipient - must be the blacklister recorded on the entry
+    #[account(mut)]
+    pub added_by: UncheckedAccount<'info>,
+
+    /// Anyone may purge an expired entry
+    pub caller: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeEscrow<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Seized-funds escrow - seeds: ["escrow", config.key()], owned by the config PDA
+    #[account(
+        init,
+        payer = master_authority,
+        token::mint = mint,
+        token::authority = config,
+        token::token_program = token_program,
+        seeds = [b"escrow", config.key().as_ref()],
+        bump
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    /// The freeze authority PDA - seeds: ["freeze_authority", mint.key()]
+    /// Thaws the escrow when the mint defaults new accounts to frozen
+    #[account(
+        seeds = [b"freeze_authority", mint.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the freeze authority PDA set on the mint
+    pub freeze_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(amount: Option<u64>, case_id: String)]
+pub struct Seize<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = source_token.mint == config.mint @ StablecoinError::InvalidAccount
+    )]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// Seized-funds escrow - seeds: ["escrow", config.key()]
+    #[account(
+        mut,
+        seeds = [b"escrow", config.key().as_ref()],
+        bump
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = seizer,
+        space = SeizureCase::LEN,
+        seeds = [b"seizure_case", config.key().as_ref(), case_id.as_bytes()],
+        bump
+    )]
+    pub seizure_case: Account<'info, SeizureCase>,
+
+    #[account(
+        mut,
+        constraint = config.seizer == seizer.key() || role_assignment.is_some() @ StablecoinError::Unauthorized
+    )]
+    pub seizer: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", config.key().as_ref(), RoleKind::Seizer.seed(), seizer.key().as_ref()],
+        bump = role_assignment.bump,
+        constraint = role_assignment.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    /// Blacklist entry of the source owner - seeds: ["blacklist", config.key(), source_token.owner]
+    /// Must not have expired; may be omitted when the source account is frozen
+    #[account(
+        seeds = [b"blacklist", config.key().as_ref(), source_token.owner.as_ref()],
+        bump = blacklist_entry.bump,
+        constraint = blacklist_entry.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub blacklist_entry: Option<Account<'info, BlacklistEntry>>,
+
+    /// The freeze authority PDA - seeds: ["freeze_authority", mint.key()]
+    /// This PDA acts as the freeze authority for the mint
+    /// Must be set as the freeze authority when creating the mint
+    /// Used to thaw the frozen account before transfer and re-freeze it afterwards
+    #[account(
+        seeds = [b"freeze_authority", mint.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the freeze authority PDA that signs via seeds
+    pub freeze_authority: UncheckedAccount<'info>,
+
+    /// The permanent delegate PDA - seeds: ["permanent_delegate", mint.key()]
+    /// This PDA acts as the permanent delegate for the mint
+    /// Must be set as the permanent delegate when creating the mint
+    #[account(
+        seeds = [b"permanent_delegate", mint.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the permanent delegate PDA that signs via seeds
+    pub permanent_delegate: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct SeizeMany<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Owner of every source token account in remaining_accounts
+    pub owner: UncheckedAccount<'info>,
+
+    /// Seized-funds escrow - seeds: ["escrow", config.key()]
+    #[account(
+        mut,
+        seeds = [b"escrow", config.key().as_ref()],
+        bump
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = seizer,
+        space = SeizureCase::LEN,
+        seeds = [b"seizure_case", config.key().as_ref(), case_id.as_bytes()],
+        bump
+    )]
+    pub seizure_case: Account<'info, SeizureCase>,
+
+    #[account(
+        mut,
+        constraint = config.seizer == seizer.key() || role_assignment.is_some() @ StablecoinError::Unauthorized
+    )]
+    pub seizer: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", config.key().as_ref(), RoleKind::Seizer.seed(), seizer.key().as_ref()],
+        bump = role_assignment.bump,
+        constraint = role_assignment.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    /// Blacklist entry of the owner - seeds: ["blacklist", config.key(), owner.key()]
+    /// Must not have expired; may be omitted when every source account is frozen
+    #[account(
+        seeds = [b"blacklist", config.key().as_ref(), owner.key().as_ref()],
+        bump = blacklist_entry.bump,
+        constraint = blacklist_entry.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub blacklist_entry: Option<Account<'info, BlacklistEntry>>,
+
+    /// The freeze authority PDA - seeds: ["freeze_authority", mint.key()]
+    /// Thaws each frozen source before transfer and re-freezes it afterwards
+    #[account(
+        seeds = [b"freeze_authority", mint.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the freeze authority PDA that signs via seeds
+    pub freeze_authority: UncheckedAccount<'info>,
+
+    /// The permanent delegate PDA - seeds: ["permanent_delegate", mint.key()]
+    #[account(
+        seeds = [b"permanent_delegate", mint.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the permanent delegate PDA that signs via seeds
+    pub permanent_delegate: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct ReleaseSeizedFunds<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Seized-funds escrow - seeds: ["escrow", config.key()]
+    #[account(
+        mut,
+        seeds = [b"escrow", config.key().as_ref()],
+        bump
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"seizure_case", config.key().as_ref(), case_id.as_bytes()],
+        bump = seizure_case.bump,
+        constraint = seizure_case.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub seizure_case: Account<'info, SeizureCase>,
+
+    #[account(
+        mut,
+        constraint = destination.mint == config.mint @ StablecoinError::InvalidAccount,
+        constraint = destination.key() != escrow.key() @ StablecoinError::InvalidAccount
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = config.master_authority == authority.key() || role_assignment.is_some() @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", config.key().as_ref(), RoleKind::Legal.seed(), authority.key().as_ref()],
+        bump = role_assignment.bump,
+        constraint = role_assignment.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        constraint = treasury.mint == config.mint @ StablecoinError::InvalidAccount
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    pub master_authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = config.master_authority == authority.key() || role_assignment.is_some() @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", config.key().as_ref(), RoleKind::Metadata.seed(), authority.key().as_ref()],
+        bump = role_assignment.bump,
+        constraint = role_assignment.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetMetadataField<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveMetadataField<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub master_authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMaxSupply<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TransferAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeMasterAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptMasterAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The proposed master authority - must match pending_master_authority
+    pub new_master_authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelMasterAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: May still be in a pre-versioning layout; resized and deserialized by the handler
+    #[account(
+        mut,
+        seeds = [b"config", mint.key().as_ref()],
+        bump,
+        owner = crate::ID @ StablecoinError::InvalidAccount
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Must be the config's master authority; pays for the extra space
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct QueueAdminAction<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = master_authority,
+        space = PendingAdminAction::LEN,
+        seeds = [b"admin_action", config.key().as_ref(), &config.next_admin_action_id.to_le_bytes()],
+        bump
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteAdminAction<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"admin_action", config.key().as_ref(), &pending_action.id.to_le_bytes()],
+        bump = pending_action.bump,
+        has_one = proposer @ StablecoinError::InvalidAccount,
+        constraint = pending_action.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    /// CHECK: Rent recipient - must be the proposer recorded on the action
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// MinterInfo PDA for AddMinter / UpdateMinterQuota / UpdateMinterAllowance
+    /// - seeds: ["minter", config.key(), minter]
+    /// CHECK: Checked against the queued action by the handler
+    #[account(mut)]
+    pub minter_info: Option<UncheckedAccount<'info>>,
+
+    /// BurnerInfo PDA for AddBurner - seeds: ["burner", config.key(), burner]
+    /// CHECK: Checked against the queued action by the handler
+    #[account(mut)]
+    pub burner_info: Option<UncheckedAccount<'info>>,
 
+    /// RoleAssignment PDA for GrantRole - seeds: ["role", config.key(), role, holder]
+    /// CHECK: Checked against the queued action by the handler
     #[account(mut)]
-    pub mint: InterfaceAccount<'info, Mint>,
+    pub role_assignment: Option<UncheckedAccount<'info>>,
+
+    /// Token account for SetTreasury; must match the queued action
+    pub treasury: Option<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub master_authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token2022>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct MintTokens<'info> {
+pub struct CancelAdminAction<'info> {
     #[account(
         seeds = [b"config", mint.key().as_ref()],
-        bump = config.bump
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
     pub config: Account<'info, StablecoinConfig>,
 
-    #[account(mut)]
     pub mint: InterfaceAccount<'info, Mint>,
 
-    /// CHECK: The mint authority (must match mint's mint_authority)
-    pub mint_authority: Signer<'info>,
-
     #[account(
         mut,
-        seeds = [b"minter", config.key().as_ref(), minter.key().as_ref()],
-        bump = minter_info.bump
+        close = proposer,
+        seeds = [b"admin_action", config.key().as_ref(), &pending_action.id.to_le_bytes()],
+        bump = pending_action.bump,
+        has_one = proposer @ StablecoinError::InvalidAccount,
+        constraint = pending_action.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
-    pub minter_info: Account<'info, MinterInfo>,
-
-    /// CHECK: The minter requesting the mint
-    pub minter: UncheckedAccount<'info>,
+    pub pending_action: Account<'info, PendingAdminAction>,
 
+    /// CHECK: Rent recipient - must be the proposer recorded on the action
     #[account(mut)]
-    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    pub proposer: UncheckedAccount<'info>,
 
-    pub token_program: Program<'info, Token2022>,
+    /// Master authority or guardian
+    #[account(
+        constraint = config.master_authority == canceller.key()
+            || (config.guardian != Pubkey::default() && config.guardian == canceller.key())
+            @ StablecoinError::Unauthorized
+    )]
+    pub canceller: Signer<'info>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct BurnTokens<'info> {
+pub struct CreateCouncil<'info> {
     #[account(
         seeds = [b"config", mint.key().as_ref()],
-        bump = config.bump
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
     pub config: Account<'info, StablecoinConfig>,
 
-    #[account(mut)]
     pub mint: InterfaceAccount<'info, Mint>,
 
-    #[account(mut)]
-    pub token_account: InterfaceAccount<'info, TokenAccount>,
-
-    pub burner: Signer<'info>,
-
-    pub token_program: Program<'info, Token2022>,
-}
-
-#[derive(Accounts)]
-pub struct FreezeTokenAccount<'info> {
     #[account(
-        seeds = [b"config", mint.key().as_ref()],
-        bump = config.bump
+        init,
+        payer = master_authority,
+        space = AdminCouncil::LEN,
+        seeds = [b"council", config.key().as_ref()],
+        bump
     )]
-    pub config: Account<'info, StablecoinConfig>,
+    pub council: Account<'info, AdminCouncil>,
 
-    #[account(mut)]
-    pub mint: InterfaceAccount<'info, Mint>,
+    /// The council authority PDA - seeds: ["council_authority", config.key()]
+    #[account(
+        seeds = [b"council_authority", config.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Data-less PDA that signs council-executed instructions
+    pub council_authority: UncheckedAccount<'info>,
 
     #[account(mut)]
-    pub token_account: InterfaceAccount<'info, TokenAccount>,
-
-    /// The freeze authority - must be the mint's freeze authority
-    pub freeze_authority: Signer<'info>,
+    pub master_authority: Signer<'info>,
 
-    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct ThawTokenAccount<'info> {
+pub struct UpdateCouncil<'info> {
     #[account(
         seeds = [b"config", mint.key().as_ref()],
-        bump = config.bump
+        bump = config.bump,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
     pub config: Account<'info, StablecoinConfig>,
 
-    #[account(mut)]
     pub mint: InterfaceAccount<'info, Mint>,
 
-    #[account(mut)]
-    pub token_account: InterfaceAccount<'info, TokenAccount>,
-
-    /// The freeze authority - must be the mint's freeze authority
-    pub freeze_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"council", config.key().as_ref()],
+        bump = council.bump,
+        constraint = council.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub council: Account<'info, AdminCouncil>,
 
-    pub token_program: Program<'info, Token2022>,
+    /// The council authority PDA, signing through `execute_council_proposal`
+    #[account(
+        seeds = [b"council_authority", config.key().as_ref()],
+        bump = council.authority_bump
+    )]
+    pub council_authority: Signer<'info>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct FreezeTokenAccountPda<'info> {
+pub struct CreateCouncilProposal<'info> {
     #[account(
         seeds = [b"config", mint.key().as_ref()],
         bump = config.bump,
-        has_one = seizer @ StablecoinError::Unauthorized
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
     pub config: Account<'info, StablecoinConfig>,
 
-    #[account(mut)]
     pub mint: InterfaceAccount<'info, Mint>,
 
-    #[account(mut)]
-    pub token_account: InterfaceAccount<'info, TokenAccount>,
-
-    /// The freezer signer - must be the authorized seizer role
-    /// This is mapped to the `seizer` field in config via has_one constraint
-    pub seizer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"council", config.key().as_ref()],
+        bump = council.bump,
+        constraint = council.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub council: Account<'info, AdminCouncil>,
 
-    /// The freeze authority PDA - seeds: ["freeze_authority", mint.key()]
     #[account(
-        seeds = [b"freeze_authority", mint.key().as_ref()],
+        init,
+        payer = member,
+        space = CouncilProposal::LEN,
+        seeds = [b"council_proposal", council.key().as_ref(), &council.proposal_count.to_le_bytes()],
         bump
     )]
-    /// CHECK: This is the freeze authority PDA that signs via seeds
-    pub freeze_authority: UncheckedAccount<'info>,
+    pub proposal: Account<'info, CouncilProposal>,
 
-    pub token_program: Program<'info, Token2022>,
+    #[account(
+        mut,
+        constraint = council.is_member(&member.key()) @ StablecoinError::Unauthorized
+    )]
+    pub member: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct ThawTokenAccountPda<'info> {
+pub struct ApproveCouncilProposal<'info> {
     #[account(
         seeds = [b"config", mint.key().as_ref()],
         bump = config.bump,
-        has_one = seizer @ StablecoinError::Unauthorized
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
     pub config: Account<'info, StablecoinConfig>,
 
-    #[account(mut)]
     pub mint: InterfaceAccount<'info, Mint>,
 
-    #[account(mut)]
-    pub token_account: InterfaceAccount<'info, TokenAccount>,
-
-    /// The thawer signer - must be the authorized seizer role
-    /// This is mapped to the `seizer` field in config via has_one constraint
-    pub seizer: Signer<'info>,
+    #[account(
+        seeds = [b"council", config.key().as_ref()],
+        bump = council.bump,
+        constraint = council.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub council: Account<'info, AdminCouncil>,
 
-    /// The freeze authority PDA - seeds: ["freeze_authority", mint.key()]
     #[account(
-        seeds = [b"freeze_authority", mint.key().as_ref()],
-        bump
+        mut,
+        seeds = [b"council_proposal", council.key().as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump,
+        constraint = proposal.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
-    /// CHECK: This is the freeze authority PDA that signs via seeds
-    pub freeze_authority: UncheckedAccount<'info>,
+    pub proposal: Account<'info, CouncilProposal>,
 
-    pub token_program: Program<'info, Token2022>,
+    #[account(
+        constraint = council.is_member(&member.key()) @ StablecoinError::Unauthorized
+    )]
+    pub member: Signer<'info>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct Pause<'info> {
+pub struct ExecuteCouncilProposal<'info> {
+    /// Not writable here: the executed instruction may modify it
     #[account(
-        mut,
         seeds = [b"config", mint.key().as_ref()],
         bump = config.bump,
-        has_one = pauser @ StablecoinError::Unauthorized
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
     pub config: Account<'info, StablecoinConfig>,
 
     pub mint: InterfaceAccount<'info, Mint>,
 
-    pub pauser: Signer<'info>,
+    /// Not writable here: the executed instruction may be `update_council`
+    #[account(
+        seeds = [b"council", config.key().as_ref()],
+        bump = council.bump,
+        constraint = council.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub council: Account<'info, AdminCouncil>,
 
-    pub token_program: Program<'info, Token2022>,
+    /// The council authority PDA - seeds: ["council_authority", config.key()]
+    #[account(
+        mut,
+        seeds = [b"council_authority", config.key().as_ref()],
+        bump = council.authority_bump
+    )]
+    /// CHECK: Data-less PDA that signs the proposal's instruction
+    pub council_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"council_proposal", council.key().as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = proposer @ StablecoinError::InvalidAccount,
+        constraint = proposal.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+
+    /// CHECK: Rent recipient - must be the proposer recorded on the proposal
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = council.is_member(&member.key()) @ StablecoinError::Unauthorized
+    )]
+    pub member: Signer<'info>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct Unpause<'info> {
+pub struct CancelCouncilProposal<'info> {
     #[account(
-        mut,
         seeds = [b"config", mint.key().as_ref()],
         bump = config.bump,
-        has_one = pauser @ StablecoinError::Unauthorized
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
     pub config: Account<'info, StablecoinConfig>,
 
     pub mint: InterfaceAccount<'info, Mint>,
 
-    pub pauser: Signer<'info>,
+    #[account(
+        seeds = [b"council", config.key().as_ref()],
+        bump = council.bump,
+        constraint = council.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub council: Account<'info, AdminCouncil>,
 
-    pub token_program: Program<'info, Token2022>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"council_proposal", council.key().as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = proposer @ StablecoinError::Unauthorized,
+        constraint = proposal.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct AddMinter<'info> {
+pub struct PostReserveAttestation<'info> {
     #[account(
         seeds = [b"config", mint.key().as_ref()],
         bump = config.bump,
-        has_one = master_authority @ StablecoinError::Unauthorized
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
     pub config: Account<'info, StablecoinConfig>,
 
     pub mint: InterfaceAccount<'info, Mint>,
 
-    /// CHECK: The minter's public key
-    pub minter: UncheckedAccount<'info>,
-
     #[account(
-        init,
-        payer = master_authority,
-        space = MinterInfo::LEN,
-        seeds = [b"minter", config.key().as_ref(), minter.key().as_ref()],
+        init_if_needed,
+        payer = attestor,
+        space = ReserveAttestation::LEN,
+        seeds = [b"reserves", config.key().as_ref()],
         bump
     )]
-    pub minter_info: Account<'info, MinterInfo>,
+    pub reserve_attestation: Account<'info, ReserveAttestation>,
 
     #[account(mut)]
-    pub master_authority: Signer<'info>,
+    pub attestor: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", config.key().as_ref(), RoleKind::Attestor.seed(), attestor.key().as_ref()],
+        bump = role_assignment.bump,
+        constraint = role_assignment.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
 
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct UpdateMinterQuota<'info> {
+pub struct SetReservePolicy<'info> {
     #[account(
+        mut,
         seeds = [b"config", mint.key().as_ref()],
         bump = config.bump,
-        has_one = master_authority @ StablecoinError::Unauthorized
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
     pub config: Account<'info, StablecoinConfig>,
 
     pub mint: InterfaceAccount<'info, Mint>,
 
+    #[account(mut)]
+    pub master_authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetMintApprovalPolicy<'info> {
     #[account(
         mut,
-        seeds = [b"minter", config.key().as_ref(), minter.key().as_ref()],
-        bump = minter_info.bump
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        has_one = master_authority @ StablecoinError::Unauthorized,
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
-    pub minter_info: Account<'info, MinterInfo>,
+    pub config: Account<'info, StablecoinConfig>,
 
-    /// CHECK: The minter's public key
-    pub minter: UncheckedAccount<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(mut)]
     pub master_authority: Signer<'info>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct RemoveMinter<'info> {
+#[instruction(amount: u64, fiat_reference: String)]
+pub struct CreateMintRequest<'info> {
     #[account(
         seeds = [b"config", mint.key().as_ref()],
         bump = config.bump,
-        has_one = master_authority @ StablecoinError::Unauthorized
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
     pub config: Account<'info, StablecoinConfig>,
 
     pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
-        mut,
         seeds = [b"minter", config.key().as_ref(), minter.key().as_ref()],
-        bump = minter_info.bump
+        bump = minter_info.bump,
+        constraint = minter_info.authority == minter.key() @ StablecoinError::Unauthorized,
+        constraint = minter_info.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
     pub minter_info: Account<'info, MinterInfo>,
 
-    /// CHECK: The minter's public key
-    pub minter: UncheckedAccount<'info>,
-
-    #[account(mut)]
-    pub master_authority: Signer<'info>,
-}
+    #[account(
+        constraint = recipient.mint == mint.key() @ StablecoinError::InvalidAccount
+    )]
+    pub recipient: InterfaceAccount<'info, TokenAccount>,
 
-#[derive(Accounts)]
-pub struct UpdateRoles<'info> {
     #[account(
-        mut,
-        seeds = [b"config", mint.key().as_ref()],
-        bump = config.bump,
-        has_one = master_authority @ StablecoinError::Unauthorized
+        init,
+        payer = minter,
+        space = MintRequest::LEN,
+        seeds = [b"mint_request", config.key().as_ref(), fiat_reference.as_bytes()],
+        bump
     )]
-    pub config: Account<'info, StablecoinConfig>,
+    pub mint_request: Account<'info, MintRequest>,
 
-    pub mint: InterfaceAccount<'info, Mint>,
+    /// UsedFiatReference PDA - seeds: ["fiat_reference", config.key(), fiat_reference]
+    /// Must not exist: the reference has not backed an executed request yet
+    /// CHECK: Only checked for being empty
+    #[account(
+        seeds = [b"fiat_reference", config.key().as_ref(), fiat_reference.as_bytes()],
+        bump,
+        constraint = used_fiat_reference.data_is_empty() @ StablecoinError::FiatReferenceUsed
+    )]
+    pub used_fiat_reference: UncheckedAccount<'info>,
 
+    /// The registered minter making the request
     #[account(mut)]
-    pub master_authority: Signer<'info>,
+    pub minter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(reason: String)]
-pub struct AddToBlacklist<'info> {
+pub struct ApproveMintRequest<'info> {
     #[account(
         seeds = [b"config", mint.key().as_ref()],
         bump = config.bump,
-        has_one = blacklister @ StablecoinError::Unauthorized
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
     pub config: Account<'info, StablecoinConfig>,
 
     pub mint: InterfaceAccount<'info, Mint>,
 
-    #[account(mut)]
-    pub blacklister: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"mint_request", config.key().as_ref(), mint_request.fiat_reference.as_bytes()],
+        bump = mint_request.bump,
+        constraint = mint_request.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub mint_request: Account<'info, MintRequest>,
 
-    /// CHECK: The user to blacklist
-    pub user: UncheckedAccount<'info>,
+    pub approver: Signer<'info>,
 
     #[account(
-        init,
-        payer = blacklister,
-        space = BlacklistEntry::LEN,
-        seeds = [b"blacklist", config.key().as_ref(), user.key().as_ref()],
-        bump
+        seeds = [b"role", config.key().as_ref(), RoleKind::MintApprover.seed(), approver.key().as_ref()],
+        bump = role_assignment.bump,
+        constraint = role_assignment.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
-    pub blacklist_entry: Account<'info, BlacklistEntry>,
-
-    pub system_program: Program<'info, System>,
+    pub role_assignment: Account<'info, RoleAssignment>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct RemoveFromBlacklist<'info> {
+pub struct ExecuteMintRequest<'info> {
     #[account(
         seeds = [b"config", mint.key().as_ref()],
         bump = config.bump,
-        has_one = blacklister @ StablecoinError::Unauthorized
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
     pub config: Account<'info, StablecoinConfig>,
 
+    #[account(mut)]
     pub mint: InterfaceAccount<'info, Mint>,
 
-    #[account(mut)]
-    pub blacklister: Signer<'info>,
+    /// The mint authority PDA - seeds: ["mint_authority", mint.key()]
+    #[account(
+        seeds = [b"mint_authority", mint.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the mint authority PDA that signs via seeds
+    pub mint_authority: UncheckedAccount<'info>,
 
-    /// CHECK: The user to remove from blacklist
-    pub user: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"minter", config.key().as_ref(), minter.key().as_ref()],
+        bump = minter_info.bump,
+        constraint = minter_info.authority == minter.key() @ StablecoinError::Unauthorized,
+        constraint = minter_info.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
 
     #[account(
         mut,
-        close = blacklister,
-        seeds = [b"blacklist", config.key().as_ref(), user.key().as_ref()],
-        bump = blacklist_entry.bump
+        close = minter,
+        seeds = [b"mint_request", config.key().as_ref(), mint_request.fiat_reference.as_bytes()],
+        bump = mint_request.bump,
+        has_one = minter @ StablecoinError::Unauthorized,
+        has_one = recipient @ StablecoinError::InvalidAccount,
+        constraint = mint_request.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
-    pub blacklist_entry: Account<'info, BlacklistEntry>,
+    pub mint_request: Account<'info, MintRequest>,
 
+    #[account(
+        init,
+        payer = minter,
+        space = UsedFiatReference::LEN,
+        seeds = [b"fiat_reference", config.key().as_ref(), mint_request.fiat_reference.as_bytes()],
+        bump
+    )]
+    pub used_fiat_reference: Account<'info, UsedFiatReference>,
+
+    /// The minter that created the request; receives its rent and pays for the
+    /// UsedFiatReference record
+    #[account(mut)]
+    pub minter: Signer<'info>,
+
+    #[account(mut)]
+    pub recipient: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
+
+    /// Reserve attestation - seeds: ["reserves", config.key()]
+    /// Required when config.reserve_gating is on
+    #[account(
+        seeds = [b"reserves", config.key().as_ref()],
+        bump = reserve_attestation.bump,
+        constraint = reserve_attestation.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub reserve_attestation: Option<Account<'info, ReserveAttestation>>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct Seize<'info> {
+pub struct RejectMintRequest<'info> {
     #[account(
         seeds = [b"config", mint.key().as_ref()],
         bump = config.bump,
-        has_one = seizer @ StablecoinError::Unauthorized
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
     pub config: Account<'info, StablecoinConfig>,
 
     pub mint: InterfaceAccount<'info, Mint>,
 
-    #[account(mut)]
-    pub source_token: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        close = minter,
+        seeds = [b"mint_request", config.key().as_ref(), mint_request.fiat_reference.as_bytes()],
+        bump = mint_request.bump,
+        has_one = minter @ StablecoinError::InvalidAccount,
+        constraint = mint_request.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub mint_request: Account<'info, MintRequest>,
 
+    /// CHECK: Rent recipient - must be the minter recorded on the request
     #[account(mut)]
-    pub dest_token: InterfaceAccount<'info, TokenAccount>,
-
-    pub seizer: Signer<'info>,
+    pub minter: UncheckedAccount<'info>,
 
-    /// The freeze authority PDA - seeds: ["freeze_authority", mint.key()]
-    /// This PDA acts as the freeze authority for the mint
-    /// Must be set as the freeze authority when creating the mint
-    /// Used to thaw the frozen account before transfer
     #[account(
-        seeds = [b"freeze_authority", mint.key().as_ref()],
-        bump
+        constraint = config.master_authority == authority.key() || role_assignment.is_some() @ StablecoinError::Unauthorized
     )]
-    /// CHECK: This is the freeze authority PDA that signs via seeds
-    pub freeze_authority: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
 
-    /// The permanent delegate PDA - seeds: ["permanent_delegate", mint.key()]
-    /// This PDA acts as the permanent delegate for the mint
-    /// Must be set as the permanent delegate when creating the mint
     #[account(
-        seeds = [b"permanent_delegate", mint.key().as_ref()],
-        bump
+        seeds = [b"role", config.key().as_ref(), RoleKind::MintApprover.seed(), authority.key().as_ref()],
+        bump = role_assignment.bump,
+        constraint = role_assignment.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
-    /// CHECK: This is the permanent delegate PDA that signs via seeds
-    pub permanent_delegate: UncheckedAccount<'info>,
-
-    pub token_program: Program<'info, Token2022>,
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct TransferAuthority<'info> {
+pub struct CloseExpiredMintRequest<'info> {
     #[account(
-        mut,
         seeds = [b"config", mint.key().as_ref()],
         bump = config.bump,
-        has_one = master_authority @ StablecoinError::Unauthorized
+        constraint = config.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
     )]
     pub config: Account<'info, StablecoinConfig>,
 
     pub mint: InterfaceAccount<'info, Mint>,
 
+    #[account(
+        mut,
+        close = minter,
+        seeds = [b"mint_request", config.key().as_ref(), mint_request.fiat_reference.as_bytes()],
+        bump = mint_request.bump,
+        has_one = minter @ StablecoinError::InvalidAccount,
+        constraint = mint_request.version == ACCOUNT_VERSION @ StablecoinError::OutdatedAccountVersion
+    )]
+    pub mint_request: Account<'info, MintRequest>,
+
+    /// CHECK: Rent recipient - must be the minter recorded on the request
     #[account(mut)]
-    pub master_authority: Signer<'info>,
+    pub minter: UncheckedAccount<'info>,
+
+    /// Anyone may close an expired request
+    pub caller: Signer<'info>,
 }
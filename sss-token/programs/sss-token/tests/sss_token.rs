@@ -1,1156 +1,900 @@
-use anchor_client::solana_sdk::signature::{Keypair, Signer};
-use anchor_client::solana_sdk::system_program;
-use anchor_client::{Client, Program};
-use std::rc::Rc;
+use anchor_lang::prelude::{borsh, AnchorDeserialize, AnchorSerialize, Clock, Pubkey};
+use anchor_lang::{Discriminator, Event, InstructionData, ToAccountMetas};
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::{Field, TokenMetadata};
+use sss_token::{
+    AccountFrozen, AdminAction, AdminCouncil, AllowancePeriod, AuthorityTransferred, BlacklistEntry, BlacklistReason,
+    Blacklisted, CouncilProposal, MintRequest, MinterInfo, PendingAdminAction, ProposalAccount,
+    ReserveAttestation, RoleKind, SeizureCase, UsedFiatReference, StablecoinConfig, StablecoinConfigV0, StablecoinError,
+    BlacklistEntryV0, MinterInfoV0, Versioned,
+    Seized, TokensBurned, TokensMinted, MAX_CASE_ID_LEN, MAX_METADATA_FIELDS,
+    ACCOUNT_VERSION, BPS_DENOMINATOR, MAX_ADMIN_DELAY, MAX_COUNCIL_MEMBERS, MAX_PROPOSAL_ACCOUNTS,
+    MAX_COUNCIL_PROPOSAL_TTL, MAX_FIAT_REFERENCE_LEN, MAX_MINT_APPROVALS, MAX_PROPOSAL_DATA_LEN, MAX_METADATA_KEY_LEN,
+    MAX_METADATA_VALUE_LEN, PAUSE_ALL, PAUSE_BLACKLIST, PAUSE_BURN, PAUSE_FLAGS, PAUSE_FREEZE,
+    PAUSE_MINT, PAUSE_SEIZE, PAUSE_TRANSFER,
+};
 
 // Program ID
 const SSS_TOKEN_PROGRAM_ID: &str = "Hf1s4EvjS79S6kcHdKhaZHVQsnsjqMbJgBEFZfaGDPmw";
 
-fn get_pda(program_id: &str, seeds: &[&[u8]]) -> (solana_sdk::pubkey::Pubkey, u8) {
-    solana_sdk::pubkey::Pubkey::find_program_address(seeds, &SSS_TOKEN_PROGRAM_ID.parse().unwrap())
-}
-
-#[test]
-fn test_initialize_sss1_minimal_stablecoin() {
-    // Create a local validator
-    let payer = Keypair::new();
-    let payer_pubkey = payer.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", payer_pubkey.as_ref()],
-    );
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    // Note: In a real Anchor test environment, you would:
-    // 1. Use `anchor_lang::test` macro
-    // 2. Or use `ProgramTest` from solana-program-test
-    // For now, this is a placeholder structure
-    
-    // The test would verify:
-    // - Initialize is called with correct parameters
-    // - Config account is created with correct values
-    // - Master authority is set to payer
-    // - Module flags are set correctly
-    
-    println!("Test: Initialize SSS-1 minimal stablecoin");
-    println!("Config PDA: {}", config_pda);
-    println!("Mint: {}", mint_pubkey);
-    println!("Authority: {}", payer_pubkey);
-}
-
-#[test]
-fn test_add_minter() {
-    let payer = Keypair::new();
-    let payer_pubkey = payer.pubkey();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let minter_keypair = Keypair::new();
-    let minter_pubkey = minter_keypair.pubkey();
-    
-    let (minter_info_pda, minter_info_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"minter", config_pda.as_ref(), minter_pubkey.as_ref()],
-    );
-    
-    println!("Test: Add minter");
-    println!("Config PDA: {}", config_pda);
-    println!("Minter: {}", minter_pubkey);
-    println!("Minter Info PDA: {}", minter_info_pda);
-    
-    // The test would verify:
-    // - Minter is added with correct quota
-    // - MinterInfo account is created
-    // - Quota is set to 1,000,000
-    // - Minted amount starts at 0
-}
-
-#[test]
-fn test_remove_minter() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let minter_keypair = Keypair::new();
-    let minter_pubkey = minter_keypair.pubkey();
-    
-    let (minter_info_pda, _minter_info_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"minter", config_pda.as_ref(), minter_pubkey.as_ref()],
-    );
-    
-    println!("Test: Remove minter");
-    println!("Minter: {}", minter_pubkey);
-    println!("Minter Info PDA: {}", minter_info_pda);
-    
-    // The test would verify:
-    // - Minter's quota is set to 0
-    // - Minter can no longer mint tokens
+fn minter_with_allowance(period: AllowancePeriod, period_length: u64, period_limit: u64) -> MinterInfo {
+    MinterInfo {
+        authority: Pubkey::new_unique(),
+        quota: 10_000_000,
+        minted: 0,
+        bump: 255,
+        period,
+        period_length,
+        period_limit,
+        period_minted: 0,
+        period_start: 0,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 32],
+    }
+}
+
+fn clock_at(unix_timestamp: i64, epoch: u64) -> Clock {
+    Clock {
+        unix_timestamp,
+        epoch,
+        ..Clock::default()
+    }
+}
+
+fn blacklist_entry_expiring(expires_at: Option<i64>) -> BlacklistEntry {
+    BlacklistEntry {
+        user: Pubkey::new_unique(),
+        reason_code: BlacklistReason::CourtOrder,
+        reason: "Temporary hold".to_string(),
+        case_id: "CASE-2024-001".to_string(),
+        added_by: Pubkey::new_unique(),
+        timestamp: 1_700_000_000,
+        expires_at,
+        bump: 255,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 32],
+    }
+}
+
+fn council_with(members: Vec<Pubkey>, threshold: u8) -> AdminCouncil {
+    AdminCouncil {
+        config: Pubkey::new_unique(),
+        members,
+        threshold,
+        proposal_count: 0,
+        bump: 255,
+        authority_bump: 254,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 32],
+    }
+}
+
+fn config_with_reserve_policy(max_attestation_age: i64, collateral_ratio_bps: u16) -> StablecoinConfig {
+    StablecoinConfig {
+        master_authority: Pubkey::new_unique(),
+        pending_master_authority: None,
+        mint: Pubkey::new_unique(),
+        name: "Test Stablecoin".to_string(),
+        symbol: "TUSD".to_string(),
+        uri: String::new(),
+        decimals: 6,
+        pause_mask: 0,
+        bump: 255,
+        enable_permanent_delegate: false,
+        enable_transfer_hook: false,
+        default_account_frozen: false,
+        blacklister: Pubkey::new_unique(),
+        pauser: Pubkey::new_unique(),
+        seizer: Pubkey::new_unique(),
+        max_supply: 0,
+        treasury: Pubkey::default(),
+        version: ACCOUNT_VERSION,
+        admin_delay: 0,
+        guardian: Pubkey::default(),
+        next_admin_action_id: 0,
+        reserve_gating: true,
+        max_attestation_age,
+        collateral_ratio_bps,
+        mint_approvals_required: 0,
+        mint_requests_only: false,
+        reserved: [0; 3],
+    }
+}
+
+fn attestation_of(reserves: u64, as_of: i64) -> ReserveAttestation {
+    ReserveAttestation {
+        config: Pubkey::new_unique(),
+        reserves,
+        as_of,
+        report_uri_hash: [7; 32],
+        attestor: Pubkey::new_unique(),
+        posted_at: as_of,
+        bump: 255,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 32],
+    }
 }
 
-#[test]
-fn test_pause_and_unpause() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    println!("Test: Pause and unpause");
-    println!("Config PDA: {}", config_pda);
-    
-    // The test would verify:
-    // - Pause instruction sets paused flag to true
-    // - Mint operations are blocked when paused
-    // - Unpause instruction sets paused flag to false
-    // - Operations resume after unpause
-}
-
-#[test]
-fn test_transfer_authority() {
-    let payer = Keypair::new();
-    let payer_pubkey = payer.pubkey();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let new_authority = Keypair::new();
-    
-    println!("Test: Transfer authority");
-    println!("Old authority: {}", payer_pubkey);
-    println!("New authority: {}", new_authority.pubkey());
-    
-    // The test would verify:
-    // - Authority can only be transferred by current master authority
-    // - New authority is set correctly
-    // - Old authority no longer has master authority privileges
-}
-
-#[test]
-fn test_update_roles() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let new_blacklister = Keypair::new();
-    let new_pauser = Keypair::new();
-    let new_seizer = Keypair::new();
-    
-    println!("Test: Update roles");
-    println!("New blacklister: {}", new_blacklister.pubkey());
-    println!("New pauser: {}", new_pauser.pubkey());
-    println!("New seizer: {}", new_seizer.pubkey());
-    
-    // The test would verify:
-    // - Roles can only be updated by master authority
-    // - New roles are set correctly
-    // - Old role accounts no longer have their privileges
-}
-
-#[test]
-fn test_mint_tokens() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let minter = Keypair::new();
-    let (minter_info_pda, _minter_info_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"minter", config_pda.as_ref(), minter.pubkey().as_ref()],
-    );
-    
-    let token_account = Keypair::new();
-    
-    println!("Test: Mint tokens");
-    println!("Minter: {}", minter.pubkey());
-    println!("Minter Info PDA: {}", minter_info_pda);
-    println!("Token Account: {}", token_account.pubkey());
-    
-    // The test would verify:
-    // - Minter can mint tokens up to their quota
-    // - Minted amount is incremented
-    // - Token account balance increases
-    // - Quota enforcement prevents over-minting
-}
-
-#[test]
-fn test_burn_tokens() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let burner = Keypair::new();
-    let token_account = Keypair::new();
-    
-    println!("Test: Burn tokens");
-    println!("Burner: {}", burner.pubkey());
-    println!("Token Account: {}", token_account.pubkey());
-    
-    // The test would verify:
-    // - Token owner can burn their tokens
-    // - Token account balance decreases
-    // - Burn amount must be <= account balance
-    // - Token supply is reduced
+fn get_pda(program_id: &str, seeds: &[&[u8]]) -> (solana_sdk::pubkey::Pubkey, u8) {
+    solana_sdk::pubkey::Pubkey::find_program_address(seeds, &program_id.parse().unwrap())
 }
 
 #[test]
-fn test_freeze_and_thaw_token_account() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let freeze_authority = Keypair::new();
-    let token_account = Keypair::new();
-    
-    println!("Test: Freeze and thaw token account");
-    println!("Freeze Authority: {}", freeze_authority.pubkey());
-    println!("Token Account: {}", token_account.pubkey());
-    
-    // The test would verify:
-    // - Freeze authority can freeze token accounts
-    // - Frozen accounts cannot send tokens
-    // - Freeze authority can thaw (unfreeze) accounts
-    // - Thawed accounts can send tokens again
-}
+fn test_pause_flags_are_distinct_bits() {
+    let flags = [PAUSE_MINT, PAUSE_BURN, PAUSE_TRANSFER, PAUSE_SEIZE, PAUSE_BLACKLIST, PAUSE_FREEZE];
 
-#[test]
-fn test_update_minter_quota() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let minter = Keypair::new();
-    let (minter_info_pda, _minter_info_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"minter", config_pda.as_ref(), minter.pubkey().as_ref()],
-    );
-    
-    println!("Test: Update minter quota");
-    println!("Minter: {}", minter.pubkey());
-    println!("Minter Info PDA: {}", minter_info_pda);
-    
-    // The test would verify:
-    // - Master authority can update minter quotas
-    // - New quota is set correctly
-    // - Existing minted amount is preserved
-    // - Minter can continue minting with new quota
+    let mut combined = 0u8;
+    for flag in flags {
+        assert_eq!(flag.count_ones(), 1);
+        assert_eq!(combined & flag, 0);
+        combined |= flag;
+    }
+    assert_eq!(combined, PAUSE_FLAGS);
 }
 
 #[test]
-fn test_add_to_blacklist() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let blacklister = Keypair::new();
-    let user_to_blacklist = Keypair::new();
-    
-    let (blacklist_entry_pda, _blacklist_entry_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"blacklist", config_pda.as_ref(), user_to_blacklist.pubkey().as_ref()],
-    );
-    
-    println!("Test: Add to blacklist");
-    println!("Blacklister: {}", blacklister.pubkey());
-    println!("User to blacklist: {}", user_to_blacklist.pubkey());
-    println!("Blacklist Entry PDA: {}", blacklist_entry_pda);
-    
-    // The test would verify:
-    // - Blacklister can add addresses to blacklist
-    // - Blacklist entry is created with reason and timestamp
-    // - Compliance module must be enabled
-    // - User cannot be added twice (AlreadyBlacklisted error)
+fn test_pause_all_leaves_freeze_available() {
+    assert_eq!(PAUSE_ALL & PAUSE_FREEZE, 0);
+    assert_eq!(PAUSE_ALL | PAUSE_FREEZE, PAUSE_FLAGS);
 }
 
 #[test]
-fn test_remove_from_blacklist() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let blacklister = Keypair::new();
-    let user = Keypair::new();
-    
-    let (blacklist_entry_pda, _blacklist_entry_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"blacklist", config_pda.as_ref(), user.pubkey().as_ref()],
-    );
-    
-    println!("Test: Remove from blacklist");
-    println!("Blacklister: {}", blacklister.pubkey());
-    println!("User: {}", user.pubkey());
-    println!("Blacklist Entry PDA: {}", blacklist_entry_pda);
-    
-    // The test would verify:
-    // - Blacklister can remove addresses from blacklist
-    // - Blacklist entry account is closed
-    // - SOL from closing is returned to blacklister
-    // - User can receive/transact tokens again
-}
+fn test_role_kind_seeds_are_distinct() {
+    let config = Pubkey::new_unique();
+    let holder = Pubkey::new_unique();
+    let roles = [
+        RoleKind::Blacklister,
+        RoleKind::Pauser,
+        RoleKind::Seizer,
+        RoleKind::Metadata,
+        RoleKind::Legal,
+        RoleKind::Attestor,
+        RoleKind::MintApprover,
+    ];
 
-#[test]
-fn test_seize_tokens() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let seizer = Keypair::new();
-    let source_token = Keypair::new();
-    let dest_token = Keypair::new();
-    
-    println!("Test: Seize tokens");
-    println!("Seizer: {}", seizer.pubkey());
-    println!("Source Token Account: {}", source_token.pubkey());
-    println!("Destination Token Account: {}", dest_token.pubkey());
-    
-    // The test would verify:
-    // - Seizer can transfer tokens from any account
-    // - Permanent delegate must be enabled
-    // - Tokens are transferred to destination account
-    // - Source account balance decreases
-    // - Works even on frozen accounts (SSS-2 requirement)
-}
+    let pdas: Vec<Pubkey> = roles
+        .iter()
+        .map(|role| get_pda(SSS_TOKEN_PROGRAM_ID, &[b"role", config.as_ref(), role.seed(), holder.as_ref()]).0)
+        .collect();
 
-#[test]
-fn test_full_workflow() {
-    println!("Test: Full stablecoin workflow");
-    println!("This test simulates a complete token lifecycle:");
-    println!("1. Initialize stablecoin with SSS-2 features");
-    println!("2. Add minter with quota");
-    println!("3. Mint tokens to user");
-    println!("4. User attempts to transfer (validated by transfer hook)");
-    println!("5. Admin adds malicious user to blacklist");
-    println!("6. Malicious user tries to transfer (blocked)");
-    println!("7. Admin seizes tokens from malicious user");
-    println!("8. Admin removes user from blacklist");
-    println!("9. Minter burns excess tokens");
-    println!("10. Admin pauses/unpauses for emergency");
-    
-    // This would be a comprehensive end-to-end test
-    // demonstrating all features working together
+    for i in 0..pdas.len() {
+        for j in (i + 1)..pdas.len() {
+            assert_ne!(pdas[i], pdas[j]);
+        }
+    }
 }
 
-// ============================================
-// NEGATIVE TEST CASES
-// ============================================
-
 #[test]
-fn test_initialize_name_too_long() {
-    let payer = Keypair::new();
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    // Create a name that exceeds 100 characters
-    let long_name = "x".repeat(101);
-    
-    println!("Test: Initialize with name too long (negative)");
-    println!("Name length: {} (max 100)", long_name.len());
-    println!("Expected error: InvalidAccount");
-    
-    // The test would verify:
-    // - Transaction fails with InvalidAccount error
-    // - Config account is not created
-    // - Error message indicates name length violation
-}
+fn test_minter_daily_allowance_resets() {
+    let mut minter_info = minter_with_allowance(AllowancePeriod::Seconds, 86_400, 1_000_000);
+    minter_info.period_start = 1_700_000_000;
+    minter_info.period_minted = 1_000_000;
 
-#[test]
-fn test_initialize_symbol_too_long() {
-    let payer = Keypair::new();
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    // Create a symbol that exceeds 10 characters
-    let long_symbol = "x".repeat(11);
-    
-    println!("Test: Initialize with symbol too long (negative)");
-    println!("Symbol length: {} (max 10)", long_symbol.len());
-    println!("Expected error: InvalidAccount");
-    
-    // The test would verify:
-    // - Transaction fails with InvalidAccount error
-    // - Config account is not created
-}
+    // Still inside the current day: nothing resets
+    minter_info.roll_period(&clock_at(1_700_000_000 + 86_399, 0));
+    assert_eq!(minter_info.period_minted, 1_000_000);
+    assert_eq!(minter_info.period_start, 1_700_000_000);
 
-#[test]
-fn test_initialize_uri_too_long() {
-    let payer = Keypair::new();
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    // Create a URI that exceeds 200 characters
-    let long_uri = "x".repeat(201);
-    
-    println!("Test: Initialize with URI too long (negative)");
-    println!("URI length: {} (max 200)", long_uri.len());
-    println!("Expected error: InvalidAccount");
-    
-    // The test would verify:
-    // - Transaction fails with InvalidAccount error
-    // - Config account is not created
-}
-
-#[test]
-fn test_mint_tokens_when_paused() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let minter = Keypair::new();
-    let (minter_info_pda, _minter_info_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"minter", config_pda.as_ref(), minter.pubkey().as_ref()],
-    );
-    
-    println!("Test: Mint tokens when paused (negative)");
-    println!("Expected error: TokenPaused");
-    
-    // The test would verify:
-    // - Pre-condition: Token is paused
-    // - Mint transaction fails with TokenPaused error
-    // - Token account balance remains unchanged
-    // - Minter's minted amount is not incremented
+    // Three and a half days later: window restarts on a day boundary
+    minter_info.roll_period(&clock_at(1_700_000_000 + 3 * 86_400 + 43_200, 0));
+    assert_eq!(minter_info.period_minted, 0);
+    assert_eq!(minter_info.period_start, 1_700_000_000 + 3 * 86_400);
 }
 
 #[test]
-fn test_mint_tokens_over_quota() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let minter = Keypair::new();
-    let (minter_info_pda, _minter_info_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"minter", config_pda.as_ref(), minter.pubkey().as_ref()],
-    );
-    
-    println!("Test: Mint tokens over quota (negative)");
-    println!("Expected error: QuotaExceeded");
-    
-    // The test would verify:
-    // - Pre-condition: Minter has quota of 1,000,000
-    // - Attempt to mint 2,000,000 tokens
-    // - Transaction fails with QuotaExceeded error
-    // - Token account balance remains unchanged
-}
+fn test_minter_epoch_allowance_resets() {
+    let mut minter_info = minter_with_allowance(AllowancePeriod::Epochs, 2, 500);
+    minter_info.period_start = 100;
+    minter_info.period_minted = 400;
 
-#[test]
-fn test_burn_tokens_when_paused() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let burner = Keypair::new();
-    let token_account = Keypair::new();
-    
-    println!("Test: Burn tokens when paused (negative)");
-    println!("Expected error: TokenPaused");
-    
-    // The test would verify:
-    // - Pre-condition: Token is paused
-    // - Burn transaction fails with TokenPaused error
-    // - Token account balance remains unchanged
-}
+    minter_info.roll_period(&clock_at(0, 101));
+    assert_eq!(minter_info.period_minted, 400);
 
-#[test]
-fn test_burn_tokens_more_than_balance() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let burner = Keypair::new();
-    let token_account = Keypair::new();
-    
-    println!("Test: Burn more tokens than balance (negative)");
-    println!("Expected error: SPL Token error (insufficient balance)");
-    
-    // The test would verify:
-    // - Pre-condition: Account has 100 tokens
-    // - Attempt to burn 200 tokens
-    // - Transaction fails with SPL Token insufficient balance error
+    minter_info.roll_period(&clock_at(0, 102));
+    assert_eq!(minter_info.period_minted, 0);
+    assert_eq!(minter_info.period_start, 102);
 }
 
 #[test]
-fn test_pause_by_unauthorized() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let unauthorized_user = Keypair::new();
-    
-    println!("Test: Pause by unauthorized user (negative)");
-    println!("Unauthorized: {}", unauthorized_user.pubkey());
-    println!("Expected error: Unauthorized");
-    
-    // The test would verify:
-    // - Unauthorized user attempts to pause
-    // - Transaction fails with Unauthorized error
-    // - Token remains unpaused
-}
+fn test_minter_without_allowance_never_resets() {
+    let mut minter_info = minter_with_allowance(AllowancePeriod::Seconds, 0, 0);
+    minter_info.period_minted = 42;
 
-#[test]
-fn test_unpause_by_unauthorized() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let unauthorized_user = Keypair::new();
-    
-    println!("Test: Unpause by unauthorized user (negative)");
-    println!("Unauthorized: {}", unauthorized_user.pubkey());
-    println!("Expected error: Unauthorized");
-    
-    // The test would verify:
-    // - Pre-condition: Token is paused
-    // - Unauthorized user attempts to unpause
-    // - Transaction fails with Unauthorized error
-    // - Token remains paused
+    assert!(!minter_info.has_period_allowance());
+    minter_info.roll_period(&clock_at(i64::MAX, u64::MAX));
+    assert_eq!(minter_info.period_minted, 42);
 }
 
 #[test]
-fn test_add_minter_by_unauthorized() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let unauthorized_user = Keypair::new();
-    let new_minter = Keypair::new();
-    
-    let (minter_info_pda, _minter_info_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"minter", config_pda.as_ref(), new_minter.pubkey().as_ref()],
-    );
-    
-    println!("Test: Add minter by unauthorized user (negative)");
-    println!("Unauthorized: {}", unauthorized_user.pubkey());
-    println!("Expected error: Unauthorized");
-    
-    // The test would verify:
-    // - Non-master authority attempts to add minter
-    // - Transaction fails with Unauthorized error
-    // - MinterInfo account is not created
-}
+fn test_blacklist_entry_expiry() {
+    let permanent = blacklist_entry_expiring(None);
+    assert!(!permanent.is_expired(i64::MAX));
 
-#[test]
-fn test_remove_minter_by_unauthorized() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let unauthorized_user = Keypair::new();
-    let minter = Keypair::new();
-    
-    let (minter_info_pda, _minter_info_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"minter", config_pda.as_ref(), minter.pubkey().as_ref()],
-    );
-    
-    println!("Test: Remove minter by unauthorized user (negative)");
-    println!("Unauthorized: {}", unauthorized_user.pubkey());
-    println!("Expected error: Unauthorized");
-    
-    // The test would verify:
-    // - Non-master authority attempts to remove minter
-    // - Transaction fails with Unauthorized error
-    // - Minter's quota remains unchanged
+    let temporary = blacklist_entry_expiring(Some(1_700_086_400));
+    assert!(!temporary.is_expired(1_700_086_399));
+    assert!(temporary.is_expired(1_700_086_400));
+    assert!(temporary.is_expired(1_800_000_000));
 }
 
 #[test]
-fn test_update_minter_quota_by_unauthorized() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let unauthorized_user = Keypair::new();
-    let minter = Keypair::new();
-    
-    let (minter_info_pda, _minter_info_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"minter", config_pda.as_ref(), minter.pubkey().as_ref()],
-    );
-    
-    println!("Test: Update minter quota by unauthorized user (negative)");
-    println!("Unauthorized: {}", unauthorized_user.pubkey());
-    println!("Expected error: Unauthorized");
-    
-    // The test would verify:
-    // - Non-master authority attempts to update quota
-    // - Transaction fails with Unauthorized error
-    // - Minter's quota remains unchanged
-}
+fn test_blacklist_entry_len_fits_largest_entry() {
+    let mut entry = blacklist_entry_expiring(Some(i64::MAX));
+    entry.reason = "r".repeat(100);
+    entry.case_id = "c".repeat(64);
 
-#[test]
-fn test_transfer_authority_by_unauthorized() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let unauthorized_user = Keypair::new();
-    let new_authority = Keypair::new();
-    
-    println!("Test: Transfer authority by unauthorized user (negative)");
-    println!("Unauthorized: {}", unauthorized_user.pubkey());
-    println!("Expected error: Unauthorized");
-    
-    // The test would verify:
-    // - Non-master authority attempts to transfer authority
-    // - Transaction fails with Unauthorized error
-    // - Master authority remains unchanged
+    let serialized = entry.try_to_vec().unwrap();
+    assert_eq!(8 + serialized.len(), BlacklistEntry::LEN);
 }
 
 #[test]
-fn test_update_roles_by_unauthorized() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let unauthorized_user = Keypair::new();
-    let new_blacklister = Keypair::new();
-    
-    println!("Test: Update roles by unauthorized user (negative)");
-    println!("Unauthorized: {}", unauthorized_user.pubkey());
-    println!("Expected error: Unauthorized");
-    
-    // The test would verify:
-    // - Non-master authority attempts to update roles
-    // - Transaction fails with Unauthorized error
-    // - Roles remain unchanged
-}
+fn test_seizure_case_remaining() {
+    let mut seizure_case = SeizureCase {
+        config: Pubkey::new_unique(),
+        case_id: "c".repeat(MAX_CASE_ID_LEN),
+        source_owner: Pubkey::new_unique(),
+        source_token: Pubkey::new_unique(),
+        seizer: Pubkey::new_unique(),
+        amount: 1_000_000,
+        released: 0,
+        seized_at: 1_700_000_000,
+        bump: 255,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 32],
+    };
+    assert_eq!(seizure_case.remaining().unwrap(), 1_000_000);
+
+    seizure_case.released = 400_000;
+    assert_eq!(seizure_case.remaining().unwrap(), 600_000);
 
-#[test]
-fn test_freeze_by_unauthorized() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let unauthorized_user = Keypair::new();
-    let token_account = Keypair::new();
-    
-    println!("Test: Freeze by unauthorized user (negative)");
-    println!("Unauthorized: {}", unauthorized_user.pubkey());
-    println!("Expected error: SPL Token error (owner mismatch)");
-    
-    // The test would verify:
-    // - User without freeze authority attempts to freeze
-    // - Transaction fails with SPL Token error
-    // - Account remains unfrozen
-}
+    // A corrupted case must not wrap around to a huge releasable amount
+    seizure_case.released = 1_000_001;
+    assert_eq!(seizure_case.remaining().unwrap_err(), StablecoinError::MathOverflow.into());
 
-#[test]
-fn test_thaw_by_unauthorized() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let unauthorized_user = Keypair::new();
-    let token_account = Keypair::new();
-    
-    println!("Test: Thaw by unauthorized user (negative)");
-    println!("Unauthorized: {}", unauthorized_user.pubkey());
-    println!("Expected error: SPL Token error (owner mismatch)");
-    
-    // The test would verify:
-    // - Pre-condition: Account is frozen
-    // - User without freeze authority attempts to thaw
-    // - Transaction fails with SPL Token error
-    // - Account remains frozen
-}
+    let serialized = seizure_case.try_to_vec().unwrap();
+    assert_eq!(8 + serialized.len(), SeizureCase::LEN);
+}
 
 #[test]
-fn test_add_to_blacklist_by_unauthorized() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let unauthorized_user = Keypair::new();
-    let user_to_blacklist = Keypair::new();
-    
-    let (blacklist_entry_pda, _blacklist_entry_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"blacklist", config_pda.as_ref(), user_to_blacklist.pubkey().as_ref()],
-    );
-    
-    println!("Test: Add to blacklist by unauthorized user (negative)");
-    println!("Unauthorized: {}", unauthorized_user.pubkey());
-    println!("Expected error: Unauthorized");
-    
-    // The test would verify:
-    // - Non-blacklister attempts to add to blacklist
-    // - Transaction fails with Unauthorized error
-    // - Blacklist entry is not created
-}
+fn test_metadata_field_limits_fit_realloc() {
+    // Worst case: maximum name/symbol/uri plus every custom field at maximum length
+    let mut metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey(Pubkey::new_unique()),
+        mint: Pubkey::new_unique(),
+        name: "N".repeat(100),
+        symbol: "S".repeat(10),
+        uri: "U".repeat(200),
+        additional_metadata: vec![],
+    };
+    for i in 0..MAX_METADATA_FIELDS {
+        let key = format!("{:0>width$}", i, width = MAX_METADATA_KEY_LEN);
+        metadata.update(Field::Key(key), "V".repeat(MAX_METADATA_VALUE_LEN));
+    }
+
+    assert_eq!(metadata.additional_metadata.len(), MAX_METADATA_FIELDS);
+    // Keep the worst-case mint metadata bounded at 10 KiB
+    assert!(metadata.tlv_size_of().unwrap() <= 10 * 1024);
+}
+
+#[test]
+fn test_event_discriminators_are_distinct() {
+    let discriminators: [&[u8]; 6] = [
+        TokensMinted::DISCRIMINATOR,
+        TokensBurned::DISCRIMINATOR,
+        AccountFrozen::DISCRIMINATOR,
+        Blacklisted::DISCRIMINATOR,
+        Seized::DISCRIMINATOR,
+        AuthorityTransferred::DISCRIMINATOR,
+    ];
+
+    for (i, a) in discriminators.iter().enumerate() {
+        for b in &discriminators[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+}
+
+/// Seized as first published; consumers decoding this prefix must keep working
+#[derive(AnchorDeserialize)]
+struct SeizedV1 {
+    mint: Pubkey,
+    seizer: Pubkey,
+    source_token: Pubkey,
+    dest_token: Pubkey,
+    amount: u64,
+    timestamp: i64,
+}
+
+#[test]
+fn test_seized_event_keeps_original_prefix() {
+    let event = Seized {
+        mint: Pubkey::new_unique(),
+        seizer: Pubkey::new_unique(),
+        source_token: Pubkey::new_unique(),
+        dest_token: Pubkey::new_unique(),
+        amount: 42_000_000,
+        timestamp: 1_700_000_000,
+        case_id: "CASE-2024-001".to_string(),
+    };
+
+    let data = event.data();
+    let mut payload = &data[Seized::DISCRIMINATOR.len()..];
+    let decoded = SeizedV1::deserialize(&mut payload).unwrap();
+    assert_eq!(decoded.mint, event.mint);
+    assert_eq!(decoded.seizer, event.seizer);
+    assert_eq!(decoded.source_token, event.source_token);
+    assert_eq!(decoded.dest_token, event.dest_token);
+    assert_eq!(decoded.amount, event.amount);
+    assert_eq!(decoded.timestamp, event.timestamp);
+
+    // New fields only ever follow the original ones
+    assert_eq!(String::deserialize(&mut payload).unwrap(), event.case_id);
+    assert!(payload.is_empty());
+}
+
+#[test]
+fn test_tokens_minted_event_layout() {
+    let event = TokensMinted {
+        mint: Pubkey::new_unique(),
+        minter: Pubkey::new_unique(),
+        token_account: Pubkey::new_unique(),
+        amount: 1_000_000,
+        minter_total_minted: 3_000_000,
+        timestamp: 1_700_000_000,
+    };
+
+    let data = event.data();
+    let (discriminator, payload) = data.split_at(TokensMinted::DISCRIMINATOR.len());
+    assert_eq!(discriminator, TokensMinted::DISCRIMINATOR);
+
+    let decoded = TokensMinted::try_from_slice(payload).unwrap();
+    assert_eq!(decoded.mint, event.mint);
+    assert_eq!(decoded.minter, event.minter);
+    assert_eq!(decoded.token_account, event.token_account);
+    assert_eq!(decoded.amount, event.amount);
+    assert_eq!(decoded.minter_total_minted, event.minter_total_minted);
+    assert_eq!(decoded.timestamp, event.timestamp);
+}
+
+#[test]
+fn test_config_len_fits_largest_config() {
+    let config = StablecoinConfig {
+        master_authority: Pubkey::new_unique(),
+        pending_master_authority: Some(Pubkey::new_unique()),
+        mint: Pubkey::new_unique(),
+        name: "N".repeat(100),
+        symbol: "S".repeat(10),
+        uri: "U".repeat(200),
+        decimals: 6,
+        pause_mask: PAUSE_ALL,
+        bump: 255,
+        enable_permanent_delegate: true,
+        enable_transfer_hook: true,
+        default_account_frozen: true,
+        blacklister: Pubkey::new_unique(),
+        pauser: Pubkey::new_unique(),
+        seizer: Pubkey::new_unique(),
+        max_supply: u64::MAX,
+        treasury: Pubkey::new_unique(),
+        version: ACCOUNT_VERSION,
+        admin_delay: MAX_ADMIN_DELAY,
+        guardian: Pubkey::new_unique(),
+        next_admin_action_id: u64::MAX,
+        reserve_gating: true,
+        max_attestation_age: i64::MAX,
+        collateral_ratio_bps: u16::MAX,
+        mint_approvals_required: MAX_MINT_APPROVALS as u8,
+        mint_requests_only: true,
+        reserved: [0; 3],
+    };
+
+    let serialized = config.try_to_vec().unwrap();
+    assert_eq!(8 + serialized.len(), StablecoinConfig::LEN);
+}
+
+/// StablecoinConfig exactly as the pre-versioning program wrote it
+#[derive(AnchorSerialize)]
+struct BaselineStablecoinConfig {
+    master_authority: Pubkey,
+    mint: Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+    decimals: u8,
+    paused: bool,
+    bump: u8,
+    enable_permanent_delegate: bool,
+    enable_transfer_hook: bool,
+    default_account_frozen: bool,
+    blacklister: Pubkey,
+    pauser: Pubkey,
+    seizer: Pubkey,
+}
+
+/// BlacklistEntry exactly as the pre-versioning program wrote it
+#[derive(AnchorSerialize)]
+struct BaselineBlacklistEntry {
+    user: Pubkey,
+    reason: String,
+    timestamp: i64,
+    bump: u8,
+}
+
+/// Account data as stored on chain: discriminator, fields, then unused space up to `len`
+fn baseline_account_data(discriminator: &[u8], fields: Vec<u8>, len: usize) -> Vec<u8> {
+    let mut data = discriminator.to_vec();
+    data.extend(fields);
+    assert!(data.len() <= len);
+    data.resize(len, 0);
+    data
+}
+
+#[test]
+fn test_migrate_decodes_baseline_config() {
+    let baseline = BaselineStablecoinConfig {
+        master_authority: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        name: "Legacy USD".to_string(),
+        symbol: "LUSD".to_string(),
+        uri: "https://example.com/lusd.json".to_string(),
+        decimals: 6,
+        paused: true,
+        bump: 254,
+        enable_permanent_delegate: true,
+        enable_transfer_hook: false,
+        default_account_frozen: true,
+        blacklister: Pubkey::new_unique(),
+        pauser: Pubkey::new_unique(),
+        seizer: Pubkey::new_unique(),
+    };
+    let data = baseline_account_data(
+        StablecoinConfig::DISCRIMINATOR,
+        baseline.try_to_vec().unwrap(),
+        StablecoinConfigV0::LEN,
+    );
+
+    // Smaller than the current layout, so migrate_config takes the legacy path
+    assert!(data.len() < StablecoinConfig::LEN);
+
+    let config = StablecoinConfig::decode_legacy(&data[8..]).unwrap();
+    assert_eq!(config.master_authority, baseline.master_authority);
+    assert_eq!(config.mint, baseline.mint);
+    assert_eq!(config.name, baseline.name);
+    assert_eq!(config.symbol, baseline.symbol);
+    assert_eq!(config.uri, baseline.uri);
+    assert_eq!(config.decimals, 6);
+    assert_eq!(config.bump, 254);
+    assert!(config.enable_permanent_delegate);
+    assert!(!config.enable_transfer_hook);
+    assert!(config.default_account_frozen);
+    assert_eq!(config.blacklister, baseline.blacklister);
+    assert_eq!(config.pauser, baseline.pauser);
+    assert_eq!(config.seizer, baseline.seizer);
+
+    // The old pause switch only covered minting and burning
+    assert_eq!(config.pause_mask, PAUSE_MINT | PAUSE_BURN);
+    assert_eq!(config.pending_master_authority, None);
+    assert_eq!(config.max_supply, 0);
+    assert_eq!(config.admin_delay, 0);
+    assert_eq!(config.version, 0);
+
+    let migrated = config.try_to_vec().unwrap();
+    assert!(8 + migrated.len() <= StablecoinConfig::LEN);
+}
+
+#[test]
+fn test_migrate_decodes_baseline_blacklist_entry() {
+    let baseline = BaselineBlacklistEntry {
+        user: Pubkey::new_unique(),
+        reason: "OFAC SDN match".to_string(),
+        timestamp: 1_650_000_000,
+        bump: 253,
+    };
+    let data = baseline_account_data(
+        BlacklistEntry::DISCRIMINATOR,
+        baseline.try_to_vec().unwrap(),
+        BlacklistEntryV0::LEN,
+    );
+    assert!(data.len() < BlacklistEntry::LEN);
+
+    let entry = BlacklistEntry::decode_legacy(&data[8..]).unwrap();
+    assert_eq!(entry.user, baseline.user);
+    assert_eq!(entry.reason, baseline.reason);
+    assert_eq!(entry.timestamp, baseline.timestamp);
+    assert_eq!(entry.bump, 253);
+    assert_eq!(entry.reason_code, BlacklistReason::Other);
+    assert_eq!(entry.case_id, "");
+    assert_eq!(entry.expires_at, None);
+    assert!(!entry.is_expired(i64::MAX));
+    assert_eq!(entry.version, 0);
+}
+
+#[test]
+fn test_migrate_decodes_baseline_minter_info() {
+    let baseline = MinterInfoV0 {
+        authority: Pubkey::new_unique(),
+        quota: 5_000_000,
+        minted: 1_250_000,
+        bump: 252,
+    };
+    let data = baseline_account_data(
+        MinterInfo::DISCRIMINATOR,
+        baseline.try_to_vec().unwrap(),
+        MinterInfoV0::LEN,
+    );
+    assert_eq!(data.len(), MinterInfoV0::LEN);
+
+    let minter_info = MinterInfo::decode_legacy(&data[8..]).unwrap();
+    assert_eq!(minter_info.authority, baseline.authority);
+    assert_eq!(minter_info.quota, 5_000_000);
+    assert_eq!(minter_info.minted, 1_250_000);
+    assert_eq!(minter_info.bump, 252);
+    assert!(!minter_info.has_period_allowance());
+    assert_eq!(minter_info.version, 0);
+}
+
+#[test]
+fn test_migrate_rejects_legacy_decode_for_new_account_types() {
+    let err = SeizureCase::decode_legacy(&[]).err().unwrap();
+    assert_eq!(err, StablecoinError::OutdatedAccountVersion.into());
+}
+
+#[test]
+fn test_raising_max_supply_needs_timelock() {
+    let mut config = config_with_reserve_policy(0, 0);
+
+    // Uncapped: any cap only tightens
+    config.max_supply = 0;
+    assert!(!config.raises_max_supply(1_000_000));
+    assert!(!config.raises_max_supply(0));
+
+    config.max_supply = 1_000_000;
+    assert!(!config.raises_max_supply(1_000_000));
+    assert!(!config.raises_max_supply(500_000));
+    assert!(config.raises_max_supply(1_000_001));
+    // Removing the cap is the largest raise of all
+    assert!(config.raises_max_supply(0));
+}
+
+#[test]
+fn test_loosening_minter_allowance_needs_timelock() {
+    // No allowance yet: adding one only tightens
+    let unlimited = minter_with_allowance(AllowancePeriod::Seconds, 0, 0);
+    assert!(!unlimited.loosens_allowance(AllowancePeriod::Seconds, 86_400, 1_000_000));
+
+    let minter = minter_with_allowance(AllowancePeriod::Seconds, 86_400, 1_000_000);
+    assert!(!minter.loosens_allowance(AllowancePeriod::Seconds, 86_400, 1_000_000));
+    assert!(!minter.loosens_allowance(AllowancePeriod::Seconds, 86_400, 500_000));
+    assert!(!minter.loosens_allowance(AllowancePeriod::Seconds, 172_800, 1_000_000));
+
+    assert!(minter.loosens_allowance(AllowancePeriod::Seconds, 86_400, 1_000_001));
+    assert!(minter.loosens_allowance(AllowancePeriod::Seconds, 3_600, 1_000_000));
+    assert!(minter.loosens_allowance(AllowancePeriod::Epochs, 86_400, 1_000_000));
+    // Lifting the allowance is the largest loosening of all
+    assert!(minter.loosens_allowance(AllowancePeriod::Seconds, 0, 0));
+}
+
+#[test]
+fn test_tighter_allowance_keeps_window_usage() {
+    let mut minter = minter_with_allowance(AllowancePeriod::Seconds, 86_400, 1_000_000);
+    minter.period_minted = 900_000;
+
+    minter.set_allowance(AllowancePeriod::Seconds, 86_400, 950_000, minter.period_minted, &clock_at(3_600, 0));
+    assert_eq!(minter.period_limit, 950_000);
+    assert_eq!(minter.period_minted, 900_000);
+    assert_eq!(minter.period_start, 3_600);
+}
+
+#[test]
+fn test_pending_admin_action_len_fits_largest_action() {
+    let pending_action = PendingAdminAction {
+        config: Pubkey::new_unique(),
+        id: u64::MAX,
+        action: AdminAction::UpdateRoles {
+            blacklister: Pubkey::new_unique(),
+            pauser: Pubkey::new_unique(),
+            seizer: Pubkey::new_unique(),
+        },
+        proposer: Pubkey::new_unique(),
+        queued_at: 1_700_000_000,
+        eta: 1_700_000_000 + MAX_ADMIN_DELAY,
+        bump: 255,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 32],
+    };
+
+    let serialized = pending_action.try_to_vec().unwrap();
+    assert_eq!(8 + serialized.len(), PendingAdminAction::LEN);
+}
+
+#[test]
+fn test_council_member_validation() {
+    let members: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+    assert!(AdminCouncil::validate_members(&members, 2).is_ok());
+    assert!(AdminCouncil::validate_members(&members, 3).is_ok());
+
+    // Threshold must be between 1 and the member count
+    assert!(AdminCouncil::validate_members(&members, 0).is_err());
+    assert!(AdminCouncil::validate_members(&members, 4).is_err());
+
+    // No empty, oversized, duplicate or default member sets
+    assert!(AdminCouncil::validate_members(&[], 1).is_err());
+    let too_many: Vec<Pubkey> = (0..=MAX_COUNCIL_MEMBERS).map(|_| Pubkey::new_unique()).collect();
+    assert!(AdminCouncil::validate_members(&too_many, 1).is_err());
+    assert!(AdminCouncil::validate_members(&[members[0], members[1], members[0]], 2).is_err());
+    assert!(AdminCouncil::validate_members(&[members[0], Pubkey::default()], 1).is_err());
+}
+
+#[test]
+fn test_council_proposal_executes_admin_instruction() {
+    let program_id: Pubkey = SSS_TOKEN_PROGRAM_ID.parse().unwrap();
+    let mint = Pubkey::new_unique();
+    let (config_pda, _) = Pubkey::find_program_address(&[b"config", mint.as_ref()], &program_id);
+    let (council_authority, _) =
+        Pubkey::find_program_address(&[b"council_authority", config_pda.as_ref()], &program_id);
+    let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority"], &program_id);
+
+    // update_max_supply with the council authority PDA standing in as master authority
+    let metas = sss_token::accounts::UpdateMaxSupply {
+        config: config_pda,
+        mint,
+        master_authority: council_authority,
+        event_authority,
+        program: program_id,
+    }
+    .to_account_metas(None);
+    let data = sss_token::instruction::UpdateMaxSupply { new_max_supply: 1_000_000 }.data();
+
+    let members: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+    let mut council = council_with(members.clone(), 2);
+    let proposal = CouncilProposal {
+        council: Pubkey::new_unique(),
+        id: 0,
+        proposer: members[0],
+        accounts: metas
+            .iter()
+            .map(|meta| ProposalAccount {
+                pubkey: meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: data.clone(),
+        approvals: vec![members[0], members[1]],
+        created_at: 1_700_000_000,
+        expires_at: 1_700_000_000 + MAX_COUNCIL_PROPOSAL_TTL,
+        bump: 255,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 32],
+    };
+
+    let instruction = proposal.instruction();
+    assert_eq!(instruction.program_id, program_id);
+    assert_eq!(instruction.data, data);
+    assert_eq!(instruction.accounts, metas);
+    let signers: Vec<Pubkey> = instruction
+        .accounts
+        .iter()
+        .filter(|meta| meta.is_signer)
+        .map(|meta| meta.pubkey)
+        .collect();
+    assert_eq!(signers, vec![council_authority]);
+    assert!(CouncilProposal::is_executable(&proposal.data));
+    assert!(!proposal.is_expired(1_700_000_000));
+    assert!(proposal.is_expired(proposal.expires_at));
+
+    // 2-of-3 is met; removing an approver from the council drops their approval
+    assert_eq!(proposal.approval_count(&council), 2);
+    council.members = vec![members[0], members[2]];
+    assert_eq!(proposal.approval_count(&council), 1);
+}
+
+#[test]
+fn test_council_proposal_whitelist() {
+    assert!(CouncilProposal::is_executable(
+        &sss_token::instruction::UpdateCouncil { members: vec![], threshold: 1 }.data()
+    ));
+    assert!(CouncilProposal::is_executable(
+        &sss_token::instruction::UpdateMaxSupply { new_max_supply: 1 }.data()
+    ));
+    // Role-signed instructions and the proposal flow itself cannot be proposed
+    assert!(!CouncilProposal::is_executable(
+        &sss_token::instruction::MintTokens { amount: 1 }.data()
+    ));
+    assert!(!CouncilProposal::is_executable(
+        &sss_token::instruction::Pause { mask: PAUSE_ALL }.data()
+    ));
+    assert!(!CouncilProposal::is_executable(
+        &sss_token::instruction::ExecuteCouncilProposal {}.data()
+    ));
+    assert!(!CouncilProposal::is_executable(&[0u8; 4]));
+}
+
+#[test]
+fn test_council_lens_fit_largest_accounts() {
+    let members: Vec<Pubkey> = (0..MAX_COUNCIL_MEMBERS).map(|_| Pubkey::new_unique()).collect();
+    let mut council = council_with(members.clone(), MAX_COUNCIL_MEMBERS as u8);
+    council.proposal_count = u64::MAX;
+    assert_eq!(8 + council.try_to_vec().unwrap().len(), AdminCouncil::LEN);
+
+    let proposal = CouncilProposal {
+        council: Pubkey::new_unique(),
+        id: u64::MAX,
+        proposer: members[0],
+        accounts: vec![
+            ProposalAccount {
+                pubkey: Pubkey::new_unique(),
+                is_signer: false,
+                is_writable: true,
+            };
+            MAX_PROPOSAL_ACCOUNTS
+        ],
+        data: vec![0xAB; MAX_PROPOSAL_DATA_LEN],
+        approvals: members,
+        created_at: 1_700_000_000,
+        expires_at: 1_700_000_000 + MAX_COUNCIL_PROPOSAL_TTL,
+        bump: 255,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 32],
+    };
+    assert_eq!(8 + proposal.try_to_vec().unwrap().len(), CouncilProposal::LEN);
+}
+
+#[test]
+fn test_reserve_attestation_backed_supply() {
+    let attestation = attestation_of(1_000_000, 1_700_000_000);
+
+    // 0 and BPS_DENOMINATOR both mean fully backed 1:1
+    assert_eq!(attestation.backed_supply(0), 1_000_000);
+    assert_eq!(attestation.backed_supply(BPS_DENOMINATOR as u16), 1_000_000);
+    // 125% collateral: reserves cover 80% of their face value in supply
+    assert_eq!(attestation.backed_supply(12_500), 800_000);
+
+    // No overflow at the top of the range
+    assert_eq!(attestation_of(u64::MAX, 0).backed_supply(0), u64::MAX);
+}
+
+#[test]
+fn test_check_reserves() {
+    let now = 1_700_000_000;
+    let config = config_with_reserve_policy(3_600, 10_000);
+    let attestation = attestation_of(1_000_000, now - 60);
+
+    assert!(config.check_reserves(Some(&attestation), 400_000, 600_000, now).is_ok());
+    assert_eq!(
+        config.check_reserves(Some(&attestation), 400_000, 600_001, now).unwrap_err(),
+        StablecoinError::ReservesExceeded.into()
+    );
+    assert_eq!(
+        config.check_reserves(None, 0, 1, now).unwrap_err(),
+        StablecoinError::StaleAttestation.into()
+    );
+
+    // Older than max_attestation_age
+    let stale = attestation_of(1_000_000, now - 3_601);
+    assert_eq!(
+        config.check_reserves(Some(&stale), 0, 1, now).unwrap_err(),
+        StablecoinError::StaleAttestation.into()
+    );
+
+    // max_attestation_age = 0 disables the staleness limit
+    let no_limit = config_with_reserve_policy(0, 10_000);
+    assert!(no_limit.check_reserves(Some(&stale), 0, 1, now).is_ok());
+
+    // Gating off ignores reserves entirely
+    let mut ungated = config_with_reserve_policy(3_600, 10_000);
+    ungated.reserve_gating = false;
+    assert!(ungated.check_reserves(None, u64::MAX, 0, now).is_ok());
+
+    // Policy validation
+    assert!(StablecoinConfig::validate_reserve_policy(3_600, 10_200).is_ok());
+    assert!(StablecoinConfig::validate_reserve_policy(3_600, 9_999).is_err());
+    assert!(StablecoinConfig::validate_reserve_policy(-1, 10_000).is_err());
+}
+
+#[test]
+fn test_reserve_attestation_len() {
+    let attestation = attestation_of(u64::MAX, i64::MAX);
+    assert_eq!(8 + attestation.try_to_vec().unwrap().len(), ReserveAttestation::LEN);
+}
+
+#[test]
+fn test_reserve_attestation_update_rules() {
+    let now = 1_700_000_000;
+    let current = attestation_of(5_000_000, now - 3_600);
+
+    // Newer measurements replace the current one, up to and including `now`
+    assert!(current.validate_update(now - 60, now).is_ok());
+    assert!(current.validate_update(now, now).is_ok());
 
-#[test]
-fn test_add_to_blacklist_compliance_disabled() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let blacklister = Keypair::new();
-    let user_to_blacklist = Keypair::new();
-    
-    let (blacklist_entry_pda, _blacklist_entry_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"blacklist", config_pda.as_ref(), user_to_blacklist.pubkey().as_ref()],
-    );
-    
-    println!("Test: Add to blacklist when compliance disabled (negative)");
-    println!("Expected error: ComplianceNotEnabled");
-    
-    // The test would verify:
-    // - Pre-condition: Token initialized with enable_transfer_hook = false
-    // - Blacklister attempts to add to blacklist
-    // - Transaction fails with ComplianceNotEnabled error
-}
+    // Future-dated reports are rejected
+    let err = current.validate_update(now + 1, now).unwrap_err();
+    assert_eq!(err, StablecoinError::InvalidAmount.into());
+
+    // as_of must move strictly forward
+    let err = current.validate_update(now - 3_600, now).unwrap_err();
+    assert_eq!(err, StablecoinError::StaleAttestation.into());
+    let err = current.validate_update(now - 7_200, now).unwrap_err();
+    assert_eq!(err, StablecoinError::StaleAttestation.into());
 
-#[test]
-fn test_add_to_blacklist_already_blacklisted() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let blacklister = Keypair::new();
-    let user = Keypair::new();
-    
-    let (blacklist_entry_pda, _blacklist_entry_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"blacklist", config_pda.as_ref(), user.pubkey().as_ref()],
-    );
-    
-    println!("Test: Add to blacklist when already blacklisted (negative)");
-    println!("Expected error: Account already exists (Anchor)");
-    
-    // The test would verify:
-    // - Pre-condition: User is already blacklisted
-    // - Attempt to add same user again
-    // - Transaction fails because account already exists
+    // A freshly created attestation account accepts any past measurement
+    let empty = attestation_of(0, 0);
+    assert!(empty.validate_update(1, now).is_ok());
 }
 
 #[test]
-fn test_add_to_blacklist_reason_too_long() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let blacklister = Keypair::new();
-    let user_to_blacklist = Keypair::new();
-    
-    // Create a reason that exceeds 100 characters
-    let long_reason = "x".repeat(101);
-    
-    let (blacklist_entry_pda, _blacklist_entry_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"blacklist", config_pda.as_ref(), user_to_blacklist.pubkey().as_ref()],
-    );
-    
-    println!("Test: Add to blacklist with reason too long (negative)");
-    println!("Reason length: {} (max 100)", long_reason.len());
-    println!("Expected error: InvalidAccount");
-    
-    // The test would verify:
-    // - Blacklister attempts to add with reason > 100 chars
-    // - Transaction fails with InvalidAccount error
-}
+fn test_mint_request_len_and_expiry() {
+    let mint_request = MintRequest {
+        config: Pubkey::new_unique(),
+        minter: Pubkey::new_unique(),
+        recipient: Pubkey::new_unique(),
+        amount: u64::MAX,
+        fiat_reference: "W".repeat(MAX_FIAT_REFERENCE_LEN),
+        approvals: (0..MAX_MINT_APPROVALS).map(|_| Pubkey::new_unique()).collect(),
+        created_at: 1_700_000_000,
+        expires_at: 1_700_086_400,
+        bump: 255,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 32],
+    };
+    assert_eq!(8 + mint_request.try_to_vec().unwrap().len(), MintRequest::LEN);
 
-#[test]
-fn test_remove_from_blacklist_by_unauthorized() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let unauthorized_user = Keypair::new();
-    let blacklisted_user = Keypair::new();
-    
-    let (blacklist_entry_pda, _blacklist_entry_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"blacklist", config_pda.as_ref(), blacklisted_user.pubkey().as_ref()],
-    );
-    
-    println!("Test: Remove from blacklist by unauthorized user (negative)");
-    println!("Unauthorized: {}", unauthorized_user.pubkey());
-    println!("Expected error: Unauthorized");
-    
-    // The test would verify:
-    // - Non-blacklister attempts to remove from blacklist
-    // - Transaction fails with Unauthorized error
-    // - Blacklist entry remains
+    assert!(!mint_request.is_expired(1_700_086_399));
+    assert!(mint_request.is_expired(1_700_086_400));
 }
 
 #[test]
-fn test_remove_from_blacklist_not_blacklisted() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let blacklister = Keypair::new();
-    let user = Keypair::new();
-    
-    let (blacklist_entry_pda, _blacklist_entry_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"blacklist", config_pda.as_ref(), user.pubkey().as_ref()],
-    );
-    
-    println!("Test: Remove from blacklist when not blacklisted (negative)");
-    println!("Expected error: Account does not exist (Anchor)");
-    
-    // The test would verify:
-    // - Pre-condition: User is NOT blacklisted
-    // - Attempt to remove non-existent blacklist entry
-    // - Transaction fails because account doesn't exist
+fn test_used_fiat_reference_len() {
+    let used_reference = UsedFiatReference {
+        config: Pubkey::new_unique(),
+        minter: Pubkey::new_unique(),
+        recipient: Pubkey::new_unique(),
+        amount: u64::MAX,
+        executed_at: 1_700_000_600,
+        bump: 255,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 32],
+    };
+    assert_eq!(8 + used_reference.try_to_vec().unwrap().len(), UsedFiatReference::LEN);
 }
 
 #[test]
-fn test_seize_by_unauthorized() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let unauthorized_user = Keypair::new();
-    let source_token = Keypair::new();
-    let dest_token = Keypair::new();
-    
-    println!("Test: Seize by unauthorized user (negative)");
-    println!("Unauthorized: {}", unauthorized_user.pubkey());
-    println!("Expected error: Unauthorized");
-    
-    // The test would verify:
-    // - Non-seizer attempts to seize tokens
-    // - Transaction fails with Unauthorized error
-    // - Token balances remain unchanged
-}
+fn test_mint_request_counts_only_current_approvers() {
+    let approvers: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+    let mint_request = MintRequest {
+        config: Pubkey::new_unique(),
+        minter: Pubkey::new_unique(),
+        recipient: Pubkey::new_unique(),
+        amount: 1_000_000,
+        fiat_reference: "WIRE-2024-000123".to_string(),
+        approvals: approvers.clone(),
+        created_at: 1_700_000_000,
+        expires_at: 1_700_086_400,
+        bump: 255,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 32],
+    };
 
-#[test]
-fn test_seize_permanent_delegate_disabled() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let seizer = Keypair::new();
-    let source_token = Keypair::new();
-    let dest_token = Keypair::new();
-    
-    println!("Test: Seize when permanent delegate disabled (negative)");
-    println!("Expected error: PermanentDelegateNotEnabled");
-    
-    // The test would verify:
-    // - Pre-condition: Token initialized with enable_permanent_delegate = false
-    // - Seizer attempts to seize tokens
-    // - Transaction fails with PermanentDelegateNotEnabled error
-}
+    assert_eq!(mint_request.live_approvals(&approvers), approvers);
 
-#[test]
-fn test_seize_zero_amount() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let seizer = Keypair::new();
-    let source_token = Keypair::new();
-    let dest_token = Keypair::new();
-    
-    println!("Test: Seize with zero amount (negative)");
-    println!("Expected error: InvalidAmount");
-    
-    // The test would verify:
-    // - Seizer attempts to seize 0 tokens
-    // - Transaction fails with InvalidAmount error
-}
+    // approvers[1] had the role revoked after approving
+    let current = [approvers[0], approvers[2], Pubkey::new_unique()];
+    assert_eq!(mint_request.live_approvals(&current), vec![approvers[0], approvers[2]]);
 
-#[test]
-fn test_seize_more_than_balance() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let seizer = Keypair::new();
-    let source_token = Keypair::new();
-    let dest_token = Keypair::new();
-    
-    println!("Test: Seize more tokens than balance (negative)");
-    println!("Expected error: SPL Token error (insufficient balance)");
-    
-    // The test would verify:
-    // - Pre-condition: Source account has 100 tokens
-    // - Attempt to seize 200 tokens
-    // - Transaction fails with SPL Token insufficient balance error
+    // Role holders that never approved do not count
+    assert!(mint_request.live_approvals(&[Pubkey::new_unique()]).is_empty());
 }
 
 #[test]
-fn test_freeze_pda_by_unauthorized() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let unauthorized_user = Keypair::new();
-    let token_account = Keypair::new();
-    
-    println!("Test: Freeze with PDA by unauthorized user (negative)");
-    println!("Unauthorized: {}", unauthorized_user.pubkey());
-    println!("Expected error: Unauthorized");
-    
-    // The test would verify:
-    // - Non-seizer attempts to freeze using PDA method
-    // - Transaction fails with Unauthorized error
-    // - Account remains unfrozen
-}
+fn test_mint_approval_threshold() {
+    let mut config = config_with_reserve_policy(0, 0);
 
-#[test]
-fn test_thaw_pda_by_unauthorized() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        SSS_TOKEN_PROGRAM_ID,
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let unauthorized_user = Keypair::new();
-    let token_account = Keypair::new();
-    
-    println!("Test: Thaw with PDA by unauthorized user (negative)");
-    println!("Unauthorized: {}", unauthorized_user.pubkey());
-    println!("Expected error: Unauthorized");
-    
-    // The test would verify:
-    // - Pre-condition: Account is frozen
-    // - Non-seizer attempts to thaw using PDA method
-    // - Transaction fails with Unauthorized error
-    // - Account remains frozen
+    // Unset (e.g. a config that predates the workflow) still needs one approval
+    assert_eq!(config.mint_approval_threshold(), 1);
+    config.mint_approvals_required = 2;
+    assert_eq!(config.mint_approval_threshold(), 2);
+
+    assert!(StablecoinConfig::validate_mint_approval_policy(1).is_ok());
+    assert!(StablecoinConfig::validate_mint_approval_policy(MAX_MINT_APPROVALS as u8).is_ok());
+    assert!(StablecoinConfig::validate_mint_approval_policy(0).is_err());
+    assert!(StablecoinConfig::validate_mint_approval_policy(MAX_MINT_APPROVALS as u8 + 1).is_err());
 }
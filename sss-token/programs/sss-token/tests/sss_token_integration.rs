@@ -0,0 +1,4921 @@
+//! SSS Token Integration Tests
+//!
+//! Runs the SBF builds of sss_token and transfer_hook inside solana-program-test, against the Token-2022
+//! program bundled with solana-program-test. Anchor CPIs only run on-chain, so these
+//! tests need the compiled programs: `anchor build`, then `cargo test-sbf -p sss-token`
+
+#![cfg(feature = "test-sbf")]
+
+use anchor_lang::{
+    AccountDeserialize, AnchorDeserialize, AnchorSerialize, Discriminator, InstructionData, ToAccountMetas,
+};
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
+use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::clock::Clock;
+use solana_sdk::instruction::{AccountMeta, Instruction, InstructionError};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+use spl_token_2022::extension::default_account_state::DefaultAccountState;
+use spl_token_2022::extension::metadata_pointer::MetadataPointer;
+use spl_token_2022::extension::permanent_delegate::PermanentDelegate;
+use spl_token_2022::extension::transfer_hook::TransferHook;
+use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
+use spl_token_2022::error::TokenError;
+use spl_token_2022::state::{Account as TokenAccount, AccountState, Mint};
+use sss_token::{
+    AccountFrozen, AdminAction, AdminActionCancelled, AdminActionExecuted, AdminActionQueued, AdminCouncil,
+    AllowancePeriod, BlacklistBatchUpdated, BlacklistEntry, BlacklistEntryV0, BlacklistReason, BurnerInfo,
+    ConfigMigrated, CouncilProposal, MintRequest, MintRequestApproved, MintRequestCreated, MintRequestExecuted,
+    MintRequestRejected, MinterAdded, MinterInfo, MinterInfoV0, PendingAdminAction, ProposalAccount, ReleaseTarget,
+    ReserveAttestation, ReserveAttested, RoleAssignment, RoleKind, Seized, SeizedFundsReleased, SeizureCase,
+    StablecoinConfig, StablecoinConfigV0, StablecoinError, TokensMinted, TreasuryUpdated, UsedFiatReference,
+    ACCOUNT_VERSION, MAX_ADMIN_DELAY, MAX_BLACKLIST_BATCH, MAX_CASE_ID_LEN, MAX_COUNCIL_PROPOSAL_TTL,
+    MAX_METADATA_FIELDS, MAX_METADATA_KEY_LEN, MAX_METADATA_VALUE_LEN, MAX_MINT_APPROVALS, PAUSE_ALL, PAUSE_BLACKLIST,
+    PAUSE_BURN, PAUSE_MINT,
+};
+use transfer_hook::TransferHookError;
+
+// ============================================
+// HARNESS
+// ============================================
+
+async fn start() -> ProgramTestContext {
+    let mut program_test = ProgramTest::new("sss_token", sss_token::ID, None);
+    program_test.add_program("transfer_hook", transfer_hook::ID, None);
+    program_test.start_with_context().await
+}
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &sss_token::ID).0
+}
+
+fn event_authority() -> Pubkey {
+    pda(&[b"__event_authority"])
+}
+
+async fn send(
+    context: &mut ProgramTestContext,
+    instruction: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let blockhash = context.banks_client.get_latest_blockhash().await?;
+    let mut all_signers = vec![&context.payer];
+    for signer in signers {
+        if signer.pubkey() != context.payer.pubkey() {
+            all_signers.push(signer);
+        }
+    }
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+    context.banks_client.process_transaction(transaction).await
+}
+
+/// Advance one slot, so a repeated transaction gets a fresh blockhash and is not deduplicated
+async fn refresh_blockhash(context: &mut ProgramTestContext) {
+    let slot = context.banks_client.get_root_slot().await.unwrap();
+    context.warp_to_slot(slot + 2).unwrap();
+}
+
+/// Simulate `instruction` and decode the `T` events it emits
+/// Events are self-CPIs signed by the event authority PDA, not just log lines
+async fn simulate_events<T: Discriminator + AnchorDeserialize>(
+    context: &mut ProgramTestContext,
+    instruction: Instruction,
+    signers: &[&Keypair],
+) -> Vec<T> {
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend(signers.iter().filter(|signer| signer.pubkey() != context.payer.pubkey()));
+    let transaction =
+        Transaction::new_signed_with_payer(&[instruction], Some(&context.payer.pubkey()), &all_signers, blockhash);
+    let keys = transaction.message.account_keys.clone();
+    let simulation = context.banks_client.simulate_transaction(transaction).await.unwrap();
+    assert!(matches!(simulation.result, Some(Ok(()))));
+
+    let inner = simulation.simulation_details.unwrap().inner_instructions.unwrap();
+    inner[0]
+        .iter()
+        .map(|inner| &inner.instruction)
+        .filter(|instruction| keys[instruction.program_id_index as usize] == sss_token::ID)
+        .filter_map(|instruction| {
+            assert_eq!(keys[instruction.accounts[0] as usize], event_authority());
+            let data = instruction.data.strip_prefix(anchor_lang::event::EVENT_IX_TAG_LE).unwrap();
+            let data = data.strip_prefix(T::DISCRIMINATOR)?;
+            Some(T::deserialize(&mut &data[..]).unwrap())
+        })
+        .collect()
+}
+
+fn assert_custom_error(result: Result<(), BanksClientError>, error: StablecoinError) {
+    assert_error_code(result, anchor_lang::error::ERROR_CODE_OFFSET + error as u32);
+}
+
+/// Assert the transaction failed with custom program error `code`
+fn assert_error_code(result: Result<(), BanksClientError>, code: u32) {
+    match result.unwrap_err().unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(actual)) => assert_eq!(actual, code),
+        other => panic!("expected custom error {}, got {:?}", code, other),
+    }
+}
+
+async fn fetch_config(context: &mut ProgramTestContext, config: Pubkey) -> StablecoinConfig {
+    fetch_account(context, config).await
+}
+
+async fn fetch_account<T: AccountDeserialize>(context: &mut ProgramTestContext, address: Pubkey) -> T {
+    let account = context.banks_client.get_account(address).await.unwrap().unwrap();
+    T::try_deserialize(&mut account.data.as_slice()).unwrap()
+}
+
+async fn account_exists(context: &mut ProgramTestContext, address: Pubkey) -> bool {
+    context.banks_client.get_account(address).await.unwrap().is_some()
+}
+
+async fn supply(context: &mut ProgramTestContext, mint: Pubkey) -> u64 {
+    let account = context.banks_client.get_account(mint).await.unwrap().unwrap();
+    StateWithExtensions::<Mint>::unpack(&account.data).unwrap().base.supply
+}
+
+fn initialize_instruction(
+    authority: Pubkey,
+    mint: Pubkey,
+    args: sss_token::instruction::Initialize,
+) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::Initialize {
+            config: pda(&[b"config", mint.as_ref()]),
+            mint,
+            authority,
+            mint_authority: pda(&[b"mint_authority", mint.as_ref()]),
+            freeze_authority: pda(&[b"freeze_authority", mint.as_ref()]),
+            permanent_delegate: pda(&[b"permanent_delegate", mint.as_ref()]),
+            system_program: solana_sdk::system_program::ID,
+            token_program: spl_token_2022::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+fn initialize_args(
+    enable_permanent_delegate: bool,
+    enable_transfer_hook: bool,
+    default_account_frozen: bool,
+    max_supply: u64,
+) -> sss_token::instruction::Initialize {
+    sss_token::instruction::Initialize {
+        name: "Test USD".to_string(),
+        symbol: "TUSD".to_string(),
+        uri: "https://example.com/tusd.json".to_string(),
+        decimals: 6,
+        enable_permanent_delegate,
+        enable_transfer_hook,
+        default_account_frozen,
+        max_supply,
+    }
+}
+
+/// Initialize a stablecoin with the payer as master authority
+async fn initialize(
+    context: &mut ProgramTestContext,
+    enable_permanent_delegate: bool,
+    enable_transfer_hook: bool,
+    default_account_frozen: bool,
+    max_supply: u64,
+) -> (Keypair, Pubkey) {
+    let mint = Keypair::new();
+    let args = initialize_args(
+        enable_permanent_delegate,
+        enable_transfer_hook,
+        default_account_frozen,
+        max_supply,
+    );
+    let instruction = initialize_instruction(context.payer.pubkey(), mint.pubkey(), args);
+    send(context, instruction, &[&mint]).await.unwrap();
+    if enable_transfer_hook {
+        initialize_extra_account_meta_list(context, mint.pubkey()).await;
+    }
+    let config = pda(&[b"config", mint.pubkey().as_ref()]);
+    (mint, config)
+}
+
+/// Write the transfer hook's ExtraAccountMetaList for `mint`
+async fn initialize_extra_account_meta_list(context: &mut ProgramTestContext, mint: Pubkey) {
+    let instruction = Instruction {
+        program_id: transfer_hook::ID,
+        accounts: transfer_hook::accounts::InitializeExtraAccountMetaList {
+            payer: context.payer.pubkey(),
+            extra_account_meta_list: extra_account_meta_list(mint),
+            mint,
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: transfer_hook::instruction::InitializeExtraAccountMetaList {}.data(),
+    };
+    send(context, instruction, &[]).await.unwrap();
+}
+
+fn extra_account_meta_list(mint: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"extra-account-metas", mint.as_ref()], &transfer_hook::ID).0
+}
+
+/// The accounts Token-2022 needs to invoke the transfer hook, in `transfer_hook::extra_account_metas` order
+fn transfer_hook_accounts(mint: Pubkey, source_owner: Pubkey, destination_owner: Pubkey) -> Vec<AccountMeta> {
+    let config = pda(&[b"config", mint.as_ref()]);
+    vec![
+        AccountMeta::new_readonly(sss_token::ID, false),
+        AccountMeta::new_readonly(config, false),
+        AccountMeta::new_readonly(pda(&[b"blacklist", config.as_ref(), source_owner.as_ref()]), false),
+        AccountMeta::new_readonly(pda(&[b"blacklist", config.as_ref(), destination_owner.as_ref()]), false),
+        AccountMeta::new_readonly(transfer_hook::ID, false),
+        AccountMeta::new_readonly(extra_account_meta_list(mint), false),
+    ]
+}
+
+/// Create `owner`'s associated token account for `mint`
+async fn create_token_account(context: &mut ProgramTestContext, mint: Pubkey, owner: Pubkey) -> Pubkey {
+    let instruction = spl_associated_token_account::instruction::create_associated_token_account(
+        &context.payer.pubkey(),
+        &owner,
+        &mint,
+        &spl_token_2022::ID,
+    );
+    send(context, instruction, &[]).await.unwrap();
+    spl_associated_token_account::get_associated_token_address_with_program_id(&owner, &mint, &spl_token_2022::ID)
+}
+
+/// Create a second, non-associated token account for `owner`
+async fn create_auxiliary_token_account(context: &mut ProgramTestContext, mint: Pubkey, owner: Pubkey) -> Pubkey {
+    let account = Keypair::new();
+    let mint_account = context.banks_client.get_account(mint).await.unwrap().unwrap();
+    let mint_state = StateWithExtensions::<Mint>::unpack(&mint_account.data).unwrap();
+    let extensions = ExtensionType::get_required_init_account_extensions(&mint_state.get_extension_types().unwrap());
+    let space = ExtensionType::try_calculate_account_len::<TokenAccount>(&extensions).unwrap();
+    let lamports = context.banks_client.get_rent().await.unwrap().minimum_balance(space);
+
+    let create = solana_sdk::system_instruction::create_account(
+        &context.payer.pubkey(),
+        &account.pubkey(),
+        lamports,
+        space as u64,
+        &spl_token_2022::ID,
+    );
+    send(context, create, &[&account]).await.unwrap();
+    let initialize =
+        spl_token_2022::instruction::initialize_account3(&spl_token_2022::ID, &account.pubkey(), &mint, &owner).unwrap();
+    send(context, initialize, &[]).await.unwrap();
+    account.pubkey()
+}
+
+async fn fetch_token_account(context: &mut ProgramTestContext, address: Pubkey) -> TokenAccount {
+    let account = context.banks_client.get_account(address).await.unwrap().unwrap();
+    StateWithExtensions::<TokenAccount>::unpack(&account.data).unwrap().base
+}
+
+/// Token-2022 transfer signed by the source owner
+/// The transfer hook accounts are appended when the mint has the hook enabled
+async fn transfer(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    source: Pubkey,
+    destination: Pubkey,
+    owner: &Keypair,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let mut instruction = spl_token_2022::instruction::transfer_checked(
+        &spl_token_2022::ID,
+        &source,
+        &mint,
+        &destination,
+        &owner.pubkey(),
+        &[],
+        amount,
+        6,
+    )
+    .unwrap();
+    let mint_account = context.banks_client.get_account(mint).await.unwrap().unwrap();
+    let mint_state = StateWithExtensions::<Mint>::unpack(&mint_account.data).unwrap();
+    if mint_state.get_extension::<TransferHook>().is_ok() {
+        let destination_owner = fetch_token_account(context, destination).await.owner;
+        instruction
+            .accounts
+            .extend(transfer_hook_accounts(mint, owner.pubkey(), destination_owner));
+    }
+    send(context, instruction, &[owner]).await
+}
+
+fn add_minter(config: Pubkey, mint: Pubkey, minter: Pubkey, master_authority: Pubkey, quota: u64) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::AddMinter {
+            config,
+            mint,
+            minter,
+            minter_info: pda(&[b"minter", config.as_ref(), minter.as_ref()]),
+            master_authority,
+            system_program: solana_sdk::system_program::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::AddMinter { quota }.data(),
+    }
+}
+
+fn remove_minter(config: Pubkey, mint: Pubkey, minter: Pubkey, master_authority: Pubkey) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::RemoveMinter {
+            config,
+            mint,
+            minter_info: pda(&[b"minter", config.as_ref(), minter.as_ref()]),
+            minter,
+            master_authority,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::RemoveMinter {}.data(),
+    }
+}
+
+fn update_minter_quota(config: Pubkey, mint: Pubkey, minter: Pubkey, master_authority: Pubkey, new_quota: u64) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::UpdateMinterQuota {
+            config,
+            mint,
+            minter_info: pda(&[b"minter", config.as_ref(), minter.as_ref()]),
+            minter,
+            master_authority,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::UpdateMinterQuota { new_quota }.data(),
+    }
+}
+
+/// Limit `minter` to `period_limit` per window of `period_length` seconds
+fn update_minter_allowance(
+    config: Pubkey,
+    mint: Pubkey,
+    minter: Pubkey,
+    master_authority: Pubkey,
+    period_length: u64,
+    period_limit: u64,
+) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::UpdateMinterAllowance {
+            config,
+            mint,
+            minter_info: pda(&[b"minter", config.as_ref(), minter.as_ref()]),
+            minter,
+            master_authority,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::UpdateMinterAllowance {
+            period: AllowancePeriod::Seconds,
+            period_length,
+            period_limit,
+        }
+        .data(),
+    }
+}
+
+fn update_max_supply(config: Pubkey, mint: Pubkey, master_authority: Pubkey, new_max_supply: u64) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::UpdateMaxSupply {
+            config,
+            mint,
+            master_authority,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::UpdateMaxSupply { new_max_supply }.data(),
+    }
+}
+
+fn mint_tokens(config: Pubkey, mint: Pubkey, minter: Pubkey, token_account: Pubkey, amount: u64) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::MintTokens {
+            config,
+            mint,
+            mint_authority: pda(&[b"mint_authority", mint.as_ref()]),
+            minter_info: pda(&[b"minter", config.as_ref(), minter.as_ref()]),
+            minter,
+            token_account,
+            token_program: spl_token_2022::ID,
+            reserve_attestation: None,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::MintTokens { amount }.data(),
+    }
+}
+
+fn add_burner(config: Pubkey, mint: Pubkey, burner: Pubkey, master_authority: Pubkey, burn_limit: Option<u64>) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::AddBurner {
+            config,
+            mint,
+            burner,
+            burner_info: pda(&[b"burner", config.as_ref(), burner.as_ref()]),
+            master_authority,
+            system_program: solana_sdk::system_program::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::AddBurner { burn_limit }.data(),
+    }
+}
+
+fn remove_burner(config: Pubkey, mint: Pubkey, burner: Pubkey, master_authority: Pubkey) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::RemoveBurner {
+            config,
+            mint,
+            burner_info: pda(&[b"burner", config.as_ref(), burner.as_ref()]),
+            burner,
+            master_authority,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::RemoveBurner {}.data(),
+    }
+}
+
+fn burn_tokens(config: Pubkey, mint: Pubkey, token_account: Pubkey, burner: Pubkey, amount: u64) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::BurnTokens {
+            config,
+            mint,
+            token_account,
+            burner_info: pda(&[b"burner", config.as_ref(), burner.as_ref()]),
+            burner,
+            token_program: spl_token_2022::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::BurnTokens { amount }.data(),
+    }
+}
+
+fn burn_from(config: Pubkey, mint: Pubkey, token_account: Pubkey, burner: Pubkey, amount: u64, redemption_id: &str) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::BurnFrom {
+            config,
+            mint,
+            token_account,
+            burner_info: pda(&[b"burner", config.as_ref(), burner.as_ref()]),
+            burner,
+            permanent_delegate: pda(&[b"permanent_delegate", mint.as_ref()]),
+            token_program: spl_token_2022::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::BurnFrom {
+            amount,
+            redemption_id: redemption_id.to_string(),
+        }
+        .data(),
+    }
+}
+
+/// Create a token account for `owner` holding `amount`, minted by the payer as minter
+async fn funded_token_account(
+    context: &mut ProgramTestContext,
+    config: Pubkey,
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let token_account = create_token_account(context, mint, owner).await;
+    let minter = context.payer.pubkey();
+    let minter_info = pda(&[b"minter", config.as_ref(), minter.as_ref()]);
+    if context.banks_client.get_account(minter_info).await.unwrap().is_none() {
+        send(context, add_minter(config, mint, minter, minter, u64::MAX), &[])
+            .await
+            .unwrap();
+    }
+    send(context, mint_tokens(config, mint, minter, token_account, amount), &[])
+        .await
+        .unwrap();
+    token_account
+}
+
+/// Freeze (`freeze = true`) or thaw `token_account` through the alias instructions
+fn freeze_or_thaw(config: Pubkey, mint: Pubkey, token_account: Pubkey, seizer: Pubkey, freeze: bool) -> Instruction {
+    let accounts = sss_token::accounts::FreezeTokenAccountPda {
+        config,
+        mint,
+        token_account,
+        seizer,
+        role_assignment: None,
+        freeze_authority: pda(&[b"freeze_authority", mint.as_ref()]),
+        token_program: spl_token_2022::ID,
+        event_authority: event_authority(),
+        program: sss_token::ID,
+    }
+    .to_account_metas(None);
+    let data = if freeze {
+        sss_token::instruction::FreezeTokenAccount {}.data()
+    } else {
+        sss_token::instruction::ThawTokenAccount {}.data()
+    };
+    Instruction {
+        program_id: sss_token::ID,
+        accounts,
+        data,
+    }
+}
+
+/// Transfer SOL from the payer so `keypair` can pay rent and fees
+async fn fund(context: &mut ProgramTestContext, keypair: &Keypair) {
+    let transfer = solana_sdk::system_instruction::transfer(
+        &context.payer.pubkey(),
+        &keypair.pubkey(),
+        1_000_000_000,
+    );
+    send(context, transfer, &[]).await.unwrap();
+}
+
+async fn now(context: &mut ProgramTestContext) -> i64 {
+    context.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp
+}
+
+/// Create the admin council, signed by the payer as master authority
+async fn create_council(
+    context: &mut ProgramTestContext,
+    config: Pubkey,
+    mint: Pubkey,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<(), BanksClientError> {
+    let instruction = Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::CreateCouncil {
+            config,
+            mint,
+            council: pda(&[b"council", config.as_ref()]),
+            council_authority: pda(&[b"council_authority", config.as_ref()]),
+            master_authority: context.payer.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::CreateCouncil { members, threshold }.data(),
+    };
+    send(context, instruction, &[]).await
+}
+
+/// Signed by the council authority PDA, so it only runs through a council proposal
+fn update_council(config: Pubkey, mint: Pubkey, members: Vec<Pubkey>, threshold: u8) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::UpdateCouncil {
+            config,
+            mint,
+            council: pda(&[b"council", config.as_ref()]),
+            council_authority: pda(&[b"council_authority", config.as_ref()]),
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::UpdateCouncil { members, threshold }.data(),
+    }
+}
+
+/// Propose `instruction` to the council as proposal `id`
+async fn create_council_proposal(
+    context: &mut ProgramTestContext,
+    config: Pubkey,
+    mint: Pubkey,
+    id: u64,
+    instruction: &Instruction,
+    proposer: &Keypair,
+    expires_at: i64,
+) -> Result<(), BanksClientError> {
+    let council = pda(&[b"council", config.as_ref()]);
+    let create = Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::CreateCouncilProposal {
+            config,
+            mint,
+            council,
+            proposal: pda(&[b"council_proposal", council.as_ref(), &id.to_le_bytes()]),
+            member: proposer.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::CreateCouncilProposal {
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|meta| ProposalAccount {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: instruction.data.clone(),
+            expires_at,
+        }
+        .data(),
+    };
+    send(context, create, &[proposer]).await
+}
+
+async fn approve_council_proposal(
+    context: &mut ProgramTestContext,
+    config: Pubkey,
+    mint: Pubkey,
+    id: u64,
+    approver: &Keypair,
+) -> Result<(), BanksClientError> {
+    let council = pda(&[b"council", config.as_ref()]);
+    let approve = Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::ApproveCouncilProposal {
+            config,
+            mint,
+            council,
+            proposal: pda(&[b"council_proposal", council.as_ref(), &id.to_le_bytes()]),
+            member: approver.pubkey(),
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::ApproveCouncilProposal {}.data(),
+    };
+    send(context, approve, &[approver]).await
+}
+
+/// Execute proposal `id`, passing `instruction`'s accounts as remaining accounts
+#[allow(clippy::too_many_arguments)]
+async fn execute_council_proposal(
+    context: &mut ProgramTestContext,
+    config: Pubkey,
+    mint: Pubkey,
+    id: u64,
+    instruction: &Instruction,
+    proposer: Pubkey,
+    executor: &Keypair,
+) -> Result<(), BanksClientError> {
+    let council = pda(&[b"council", config.as_ref()]);
+
+    // The council authority PDA signs inside the program, so it is not a transaction signer
+    let mut accounts = sss_token::accounts::ExecuteCouncilProposal {
+        config,
+        mint,
+        council,
+        council_authority: pda(&[b"council_authority", config.as_ref()]),
+        proposal: pda(&[b"council_proposal", council.as_ref(), &id.to_le_bytes()]),
+        proposer,
+        member: executor.pubkey(),
+        event_authority: event_authority(),
+        program: sss_token::ID,
+    }
+    .to_account_metas(None);
+    accounts.extend(instruction.accounts.iter().map(|meta| AccountMeta {
+        is_signer: false,
+        ..meta.clone()
+    }));
+    let execute = Instruction {
+        program_id: sss_token::ID,
+        accounts,
+        data: sss_token::instruction::ExecuteCouncilProposal {}.data(),
+    };
+    send(context, execute, &[executor]).await
+}
+
+/// Propose `new_master_authority`, signed by the payer as master authority
+async fn propose_master_authority(
+    context: &mut ProgramTestContext,
+    config: Pubkey,
+    mint: Pubkey,
+    new_master_authority: Pubkey,
+) -> Result<(), BanksClientError> {
+    let instruction = Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::ProposeMasterAuthority {
+            config,
+            mint,
+            master_authority: context.payer.pubkey(),
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::ProposeMasterAuthority { new_master_authority }.data(),
+    };
+    send(context, instruction, &[]).await
+}
+
+fn accept_master_authority(config: Pubkey, mint: Pubkey, new_master_authority: Pubkey) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::AcceptMasterAuthority {
+            config,
+            mint,
+            new_master_authority,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::AcceptMasterAuthority {}.data(),
+    }
+}
+
+fn transfer_authority(config: Pubkey, mint: Pubkey, master_authority: Pubkey, new_master_authority: Pubkey) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::TransferAuthority {
+            config,
+            mint,
+            master_authority,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::TransferAuthority { new_master_authority }.data(),
+    }
+}
+
+fn cancel_master_authority_transfer(config: Pubkey, mint: Pubkey, master_authority: Pubkey) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::CancelMasterAuthorityTransfer {
+            config,
+            mint,
+            master_authority,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::CancelMasterAuthorityTransfer {}.data(),
+    }
+}
+
+fn role_assignment(config: Pubkey, role: RoleKind, holder: Pubkey) -> Pubkey {
+    pda(&[b"role", config.as_ref(), role.seed(), holder.as_ref()])
+}
+
+fn grant_role(config: Pubkey, mint: Pubkey, holder: Pubkey, master_authority: Pubkey, role: RoleKind) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::GrantRole {
+            config,
+            mint,
+            holder,
+            role_assignment: role_assignment(config, role, holder),
+            master_authority,
+            system_program: solana_sdk::system_program::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::GrantRole { role }.data(),
+    }
+}
+
+fn revoke_role(config: Pubkey, mint: Pubkey, holder: Pubkey, master_authority: Pubkey, role: RoleKind) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::RevokeRole {
+            config,
+            mint,
+            holder,
+            role_assignment: role_assignment(config, role, holder),
+            master_authority,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::RevokeRole { role }.data(),
+    }
+}
+
+fn update_roles(config: Pubkey, mint: Pubkey, master_authority: Pubkey, role_holder: Pubkey) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::UpdateRoles {
+            config,
+            mint,
+            master_authority,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::UpdateRoles {
+            new_blacklister: role_holder,
+            new_pauser: role_holder,
+            new_seizer: role_holder,
+        }
+        .data(),
+    }
+}
+
+/// Pause (`pause = true`) or unpause the operations in `mask`
+/// `role_assignment` is the pauser's RoleAssignment PDA, if it holds the role through one
+fn pause_or_unpause(
+    config: Pubkey,
+    mint: Pubkey,
+    pauser: Pubkey,
+    role_assignment: Option<Pubkey>,
+    mask: u8,
+    pause: bool,
+) -> Instruction {
+    let accounts = sss_token::accounts::Pause {
+        config,
+        mint,
+        pauser,
+        role_assignment,
+        token_program: spl_token_2022::ID,
+        event_authority: event_authority(),
+        program: sss_token::ID,
+    }
+    .to_account_metas(None);
+    let data = if pause {
+        sss_token::instruction::Pause { mask }.data()
+    } else {
+        sss_token::instruction::Unpause { mask }.data()
+    };
+    Instruction {
+        program_id: sss_token::ID,
+        accounts,
+        data,
+    }
+}
+
+async fn fetch_token_metadata(context: &mut ProgramTestContext, mint: Pubkey) -> TokenMetadata {
+    let account = context.banks_client.get_account(mint).await.unwrap().unwrap();
+    let state = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+    state.get_variable_len_extension::<TokenMetadata>().unwrap()
+}
+
+async fn assert_rent_exempt(context: &mut ProgramTestContext, address: Pubkey) {
+    let account = context.banks_client.get_account(address).await.unwrap().unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert!(rent.is_exempt(account.lamports, account.data.len()));
+}
+
+/// `role_assignment` is the authority's Metadata RoleAssignment PDA, if it holds the role through one
+fn update_metadata(
+    config: Pubkey,
+    mint: Pubkey,
+    authority: Pubkey,
+    role_assignment: Option<Pubkey>,
+    name: Option<&str>,
+    symbol: Option<&str>,
+    uri: Option<&str>,
+) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::UpdateMetadata {
+            config,
+            mint,
+            authority,
+            role_assignment,
+            system_program: solana_sdk::system_program::ID,
+            token_program: spl_token_2022::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::UpdateMetadata {
+            name: name.map(str::to_string),
+            symbol: symbol.map(str::to_string),
+            uri: uri.map(str::to_string),
+        }
+        .data(),
+    }
+}
+
+fn set_metadata_field(config: Pubkey, mint: Pubkey, master_authority: Pubkey, key: &str, value: &str) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::SetMetadataField {
+            config,
+            mint,
+            master_authority,
+            system_program: solana_sdk::system_program::ID,
+            token_program: spl_token_2022::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::SetMetadataField {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+        .data(),
+    }
+}
+
+fn remove_metadata_field(config: Pubkey, mint: Pubkey, master_authority: Pubkey, key: &str) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::RemoveMetadataField {
+            config,
+            mint,
+            master_authority,
+            token_program: spl_token_2022::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::RemoveMetadataField { key: key.to_string() }.data(),
+    }
+}
+
+fn blacklist_entry(config: Pubkey, user: Pubkey) -> Pubkey {
+    pda(&[b"blacklist", config.as_ref(), user.as_ref()])
+}
+
+fn blacklist_args(expires_at: Option<i64>) -> sss_token::instruction::AddToBlacklist {
+    sss_token::instruction::AddToBlacklist {
+        reason_code: BlacklistReason::Sanctions,
+        reason: "OFAC SDN match".to_string(),
+        case_id: "CASE-001".to_string(),
+        expires_at,
+    }
+}
+
+/// Token accounts to freeze in the same instruction go in `remaining_accounts` (writable)
+fn add_to_blacklist(
+    config: Pubkey,
+    mint: Pubkey,
+    blacklister: Pubkey,
+    user: Pubkey,
+    args: sss_token::instruction::AddToBlacklist,
+) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::AddToBlacklist {
+            config,
+            mint,
+            blacklister,
+            role_assignment: None,
+            user,
+            blacklist_entry: blacklist_entry(config, user),
+            freeze_authority: pda(&[b"freeze_authority", mint.as_ref()]),
+            system_program: solana_sdk::system_program::ID,
+            token_program: spl_token_2022::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+/// The users' BlacklistEntry PDAs are passed in `remaining_accounts`, in the same order
+fn batch_add_to_blacklist(config: Pubkey, mint: Pubkey, blacklister: Pubkey, users: &[Pubkey]) -> Instruction {
+    let mut accounts = sss_token::accounts::BatchAddToBlacklist {
+        config,
+        mint,
+        blacklister,
+        role_assignment: None,
+        system_program: solana_sdk::system_program::ID,
+        event_authority: event_authority(),
+        program: sss_token::ID,
+    }
+    .to_account_metas(None);
+    accounts.extend(users.iter().map(|user| AccountMeta::new(blacklist_entry(config, *user), false)));
+    let args = blacklist_args(None);
+    Instruction {
+        program_id: sss_token::ID,
+        accounts,
+        data: sss_token::instruction::BatchAddToBlacklist {
+            users: users.to_vec(),
+            reason_code: args.reason_code,
+            reason: args.reason,
+            case_id: args.case_id,
+            expires_at: args.expires_at,
+        }
+        .data(),
+    }
+}
+
+fn batch_remove_from_blacklist(config: Pubkey, mint: Pubkey, blacklister: Pubkey, users: &[Pubkey]) -> Instruction {
+    let mut accounts = sss_token::accounts::BatchRemoveFromBlacklist {
+        config,
+        mint,
+        blacklister,
+        role_assignment: None,
+        event_authority: event_authority(),
+        program: sss_token::ID,
+    }
+    .to_account_metas(None);
+    accounts.extend(users.iter().map(|user| AccountMeta::new(blacklist_entry(config, *user), false)));
+    Instruction {
+        program_id: sss_token::ID,
+        accounts,
+        data: sss_token::instruction::BatchRemoveFromBlacklist { users: users.to_vec() }.data(),
+    }
+}
+
+fn remove_from_blacklist(config: Pubkey, mint: Pubkey, blacklister: Pubkey, user: Pubkey) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::RemoveFromBlacklist {
+            config,
+            mint,
+            blacklister,
+            role_assignment: None,
+            user,
+            blacklist_entry: blacklist_entry(config, user),
+            system_program: solana_sdk::system_program::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::RemoveFromBlacklist {}.data(),
+    }
+}
+
+fn purge_expired_blacklist_entry(config: Pubkey, mint: Pubkey, user: Pubkey, added_by: Pubkey, caller: Pubkey) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::PurgeExpiredBlacklistEntry {
+            config,
+            mint,
+            user,
+            blacklist_entry: blacklist_entry(config, user),
+            added_by,
+            caller,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::PurgeExpiredBlacklistEntry {}.data(),
+    }
+}
+
+fn escrow(config: Pubkey) -> Pubkey {
+    pda(&[b"escrow", config.as_ref()])
+}
+
+fn seizure_case(config: Pubkey, case_id: &str) -> Pubkey {
+    pda(&[b"seizure_case", config.as_ref(), case_id.as_bytes()])
+}
+
+fn initialize_escrow(config: Pubkey, mint: Pubkey, master_authority: Pubkey) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::InitializeEscrow {
+            config,
+            mint,
+            escrow: escrow(config),
+            freeze_authority: pda(&[b"freeze_authority", mint.as_ref()]),
+            master_authority,
+            system_program: solana_sdk::system_program::ID,
+            token_program: spl_token_2022::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::InitializeEscrow {}.data(),
+    }
+}
+
+/// `blacklist_entry` is the source owner's BlacklistEntry PDA, when seizing on the strength of it
+/// On transfer-hook mints the caller appends `transfer_hook_accounts(mint, source owner, config)`
+fn seize(
+    config: Pubkey,
+    mint: Pubkey,
+    source_token: Pubkey,
+    seizer: Pubkey,
+    blacklist_entry: Option<Pubkey>,
+    amount: Option<u64>,
+    case_id: &str,
+) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::Seize {
+            config,
+            mint,
+            source_token,
+            escrow: escrow(config),
+            seizure_case: seizure_case(config, case_id),
+            seizer,
+            role_assignment: None,
+            blacklist_entry,
+            freeze_authority: pda(&[b"freeze_authority", mint.as_ref()]),
+            permanent_delegate: pda(&[b"permanent_delegate", mint.as_ref()]),
+            system_program: solana_sdk::system_program::ID,
+            token_program: spl_token_2022::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::Seize {
+            amount,
+            case_id: case_id.to_string(),
+        }
+        .data(),
+    }
+}
+
+/// `sources` become the first remaining accounts; on transfer-hook mints the caller appends
+/// `transfer_hook_accounts(mint, owner, config)` after them
+fn seize_many(
+    config: Pubkey,
+    mint: Pubkey,
+    owner: Pubkey,
+    seizer: Pubkey,
+    blacklist_entry: Option<Pubkey>,
+    sources: &[Pubkey],
+    case_id: &str,
+) -> Instruction {
+    let mut accounts = sss_token::accounts::SeizeMany {
+        config,
+        mint,
+        owner,
+        escrow: escrow(config),
+        seizure_case: seizure_case(config, case_id),
+        seizer,
+        role_assignment: None,
+        blacklist_entry,
+        freeze_authority: pda(&[b"freeze_authority", mint.as_ref()]),
+        permanent_delegate: pda(&[b"permanent_delegate", mint.as_ref()]),
+        system_program: solana_sdk::system_program::ID,
+        token_program: spl_token_2022::ID,
+        event_authority: event_authority(),
+        program: sss_token::ID,
+    }
+    .to_account_metas(None);
+    accounts.extend(sources.iter().map(|source| AccountMeta::new(*source, false)));
+    Instruction {
+        program_id: sss_token::ID,
+        accounts,
+        data: sss_token::instruction::SeizeMany {
+            case_id: case_id.to_string(),
+            source_count: sources.len() as u8,
+        }
+        .data(),
+    }
+}
+
+/// `legal_role` is the authority's Legal RoleAssignment PDA, when releasing through the role
+/// On transfer-hook mints the caller appends `transfer_hook_accounts(mint, config, destination owner)`
+#[allow(clippy::too_many_arguments)]
+fn release_seized_funds(
+    config: Pubkey,
+    mint: Pubkey,
+    destination: Pubkey,
+    authority: Pubkey,
+    legal_role: Option<Pubkey>,
+    case_id: &str,
+    amount: u64,
+    target: ReleaseTarget,
+) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::ReleaseSeizedFunds {
+            config,
+            mint,
+            escrow: escrow(config),
+            seizure_case: seizure_case(config, case_id),
+            destination,
+            authority,
+            role_assignment: legal_role,
+            token_program: spl_token_2022::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::ReleaseSeizedFunds {
+            case_id: case_id.to_string(),
+            amount,
+            target,
+        }
+        .data(),
+    }
+}
+
+fn set_treasury(config: Pubkey, mint: Pubkey, treasury: Pubkey, master_authority: Pubkey) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::SetTreasury {
+            config,
+            mint,
+            treasury,
+            master_authority,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::SetTreasury {}.data(),
+    }
+}
+
+/// Initialize a permanent-delegate mint with its escrow, and a frozen token account for `owner` holding `amount`
+async fn frozen_holding(context: &mut ProgramTestContext, owner: Pubkey, amount: u64) -> (Pubkey, Pubkey, Pubkey) {
+    let (mint, config) = initialize(context, true, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    send(context, initialize_escrow(config, mint, authority), &[]).await.unwrap();
+    let token_account = funded_token_account(context, config, mint, owner, amount).await;
+    send(context, freeze_or_thaw(config, mint, token_account, authority, true), &[])
+        .await
+        .unwrap();
+    (mint, config, token_account)
+}
+
+/// Accounts to migrate alongside the config go in `remaining_accounts` (writable)
+fn migrate_config(config: Pubkey, mint: Pubkey, master_authority: Pubkey, accounts: &[Pubkey]) -> Instruction {
+    let mut metas = sss_token::accounts::MigrateConfig {
+        config,
+        mint,
+        master_authority,
+        system_program: solana_sdk::system_program::ID,
+        event_authority: event_authority(),
+        program: sss_token::ID,
+    }
+    .to_account_metas(None);
+    metas.extend(accounts.iter().map(|account| AccountMeta::new(*account, false)));
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: metas,
+        data: sss_token::instruction::MigrateConfig {}.data(),
+    }
+}
+
+/// Serialize `value` behind the discriminator of `T`, as an account of `len` bytes
+fn account_data<T: Discriminator>(value: &impl AnchorSerialize, len: usize) -> Vec<u8> {
+    let mut data = T::DISCRIMINATOR.to_vec();
+    value.serialize(&mut data).unwrap();
+    data.resize(len, 0);
+    data
+}
+
+/// Overwrite a program account in place, funded for exactly its new size
+async fn write_account_data(context: &mut ProgramTestContext, address: Pubkey, data: Vec<u8>) {
+    let mut account = context.banks_client.get_account(address).await.unwrap().unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    account.lamports = rent.minimum_balance(data.len());
+    account.data = data;
+    context.set_account(&address, &account.into());
+}
+
+fn pending_admin_action(config: Pubkey, id: u64) -> Pubkey {
+    pda(&[b"admin_action", config.as_ref(), &id.to_le_bytes()])
+}
+
+/// `id` must be the config's `next_admin_action_id`
+fn queue_admin_action(
+    config: Pubkey,
+    mint: Pubkey,
+    master_authority: Pubkey,
+    id: u64,
+    action: AdminAction,
+) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::QueueAdminAction {
+            config,
+            mint,
+            pending_action: pending_admin_action(config, id),
+            master_authority,
+            system_program: solana_sdk::system_program::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::QueueAdminAction { action }.data(),
+    }
+}
+
+/// `minter_info` is the MinterInfo PDA for AddMinter, UpdateMinterQuota and UpdateMinterAllowance
+fn execute_admin_action(
+    config: Pubkey,
+    mint: Pubkey,
+    master_authority: Pubkey,
+    id: u64,
+    proposer: Pubkey,
+    minter_info: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::ExecuteAdminAction {
+            config,
+            mint,
+            pending_action: pending_admin_action(config, id),
+            proposer,
+            minter_info,
+            burner_info: None,
+            role_assignment: None,
+            treasury: None,
+            master_authority,
+            system_program: solana_sdk::system_program::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::ExecuteAdminAction {}.data(),
+    }
+}
+
+fn cancel_admin_action(config: Pubkey, mint: Pubkey, canceller: Pubkey, id: u64, proposer: Pubkey) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::CancelAdminAction {
+            config,
+            mint,
+            pending_action: pending_admin_action(config, id),
+            proposer,
+            canceller,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::CancelAdminAction {}.data(),
+    }
+}
+
+/// Queue `action` and execute it right away, signed by the payer as master authority
+/// Only possible while no admin delay is set, e.g. to configure the first delay and guardian
+async fn apply_admin_action(context: &mut ProgramTestContext, config: Pubkey, mint: Pubkey, action: AdminAction) {
+    let authority = context.payer.pubkey();
+    let id = fetch_config(context, config).await.next_admin_action_id;
+    send(context, queue_admin_action(config, mint, authority, id, action), &[])
+        .await
+        .unwrap();
+    send(context, execute_admin_action(config, mint, authority, id, authority, None), &[])
+        .await
+        .unwrap();
+}
+
+/// Set the clock to `unix_timestamp`
+async fn set_clock(context: &mut ProgramTestContext, unix_timestamp: i64) {
+    let offset = unix_timestamp - now(context).await;
+    advance_clock(context, offset).await;
+}
+
+fn reserve_attestation(config: Pubkey) -> Pubkey {
+    pda(&[b"reserves", config.as_ref()])
+}
+
+fn post_reserve_attestation(
+    config: Pubkey,
+    mint: Pubkey,
+    attestor: Pubkey,
+    reserves: u64,
+    as_of: i64,
+    report_uri_hash: [u8; 32],
+) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::PostReserveAttestation {
+            config,
+            mint,
+            reserve_attestation: reserve_attestation(config),
+            attestor,
+            role_assignment: role_assignment(config, RoleKind::Attestor, attestor),
+            system_program: solana_sdk::system_program::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::PostReserveAttestation {
+            reserves,
+            as_of,
+            report_uri_hash,
+        }
+        .data(),
+    }
+}
+
+fn set_reserve_policy(
+    config: Pubkey,
+    mint: Pubkey,
+    master_authority: Pubkey,
+    reserve_gating: bool,
+    max_attestation_age: i64,
+    collateral_ratio_bps: u16,
+) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::SetReservePolicy {
+            config,
+            mint,
+            master_authority,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::SetReservePolicy {
+            reserve_gating,
+            max_attestation_age,
+            collateral_ratio_bps,
+        }
+        .data(),
+    }
+}
+
+/// `mint_tokens` passing the config's reserve attestation, as reserve gating requires
+fn gated_mint_tokens(config: Pubkey, mint: Pubkey, minter: Pubkey, token_account: Pubkey, amount: u64) -> Instruction {
+    let mut instruction = mint_tokens(config, mint, minter, token_account, amount);
+    instruction.accounts[7] = AccountMeta::new_readonly(reserve_attestation(config), false);
+    instruction
+}
+
+fn mint_request(config: Pubkey, fiat_reference: &str) -> Pubkey {
+    pda(&[b"mint_request", config.as_ref(), fiat_reference.as_bytes()])
+}
+
+fn used_fiat_reference(config: Pubkey, fiat_reference: &str) -> Pubkey {
+    pda(&[b"fiat_reference", config.as_ref(), fiat_reference.as_bytes()])
+}
+
+fn set_mint_approval_policy(
+    config: Pubkey,
+    mint: Pubkey,
+    master_authority: Pubkey,
+    approvals_required: u8,
+    requests_only: bool,
+) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::SetMintApprovalPolicy {
+            config,
+            mint,
+            master_authority,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::SetMintApprovalPolicy {
+            approvals_required,
+            requests_only,
+        }
+        .data(),
+    }
+}
+
+fn create_mint_request(
+    config: Pubkey,
+    mint: Pubkey,
+    minter: Pubkey,
+    recipient: Pubkey,
+    amount: u64,
+    fiat_reference: &str,
+    expires_at: i64,
+) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::CreateMintRequest {
+            config,
+            mint,
+            minter_info: pda(&[b"minter", config.as_ref(), minter.as_ref()]),
+            recipient,
+            mint_request: mint_request(config, fiat_reference),
+            used_fiat_reference: used_fiat_reference(config, fiat_reference),
+            minter,
+            system_program: solana_sdk::system_program::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::CreateMintRequest {
+            amount,
+            fiat_reference: fiat_reference.to_string(),
+            expires_at,
+        }
+        .data(),
+    }
+}
+
+/// `revoked` are earlier approvers whose closed MintApprover RoleAssignment PDAs are passed
+fn approve_mint_request(
+    config: Pubkey,
+    mint: Pubkey,
+    approver: Pubkey,
+    fiat_reference: &str,
+    revoked: &[Pubkey],
+) -> Instruction {
+    let mut accounts = sss_token::accounts::ApproveMintRequest {
+        config,
+        mint,
+        mint_request: mint_request(config, fiat_reference),
+        approver,
+        role_assignment: role_assignment(config, RoleKind::MintApprover, approver),
+        event_authority: event_authority(),
+        program: sss_token::ID,
+    }
+    .to_account_metas(None);
+    accounts.extend(
+        revoked
+            .iter()
+            .map(|holder| AccountMeta::new_readonly(role_assignment(config, RoleKind::MintApprover, *holder), false)),
+    );
+    Instruction {
+        program_id: sss_token::ID,
+        accounts,
+        data: sss_token::instruction::ApproveMintRequest {}.data(),
+    }
+}
+
+/// `approvers` have their MintApprover RoleAssignment PDAs passed as remaining accounts
+fn execute_mint_request(
+    config: Pubkey,
+    mint: Pubkey,
+    minter: Pubkey,
+    recipient: Pubkey,
+    fiat_reference: &str,
+    approvers: &[Pubkey],
+) -> Instruction {
+    let mut accounts = sss_token::accounts::ExecuteMintRequest {
+        config,
+        mint,
+        mint_authority: pda(&[b"mint_authority", mint.as_ref()]),
+        minter_info: pda(&[b"minter", config.as_ref(), minter.as_ref()]),
+        mint_request: mint_request(config, fiat_reference),
+        used_fiat_reference: used_fiat_reference(config, fiat_reference),
+        minter,
+        recipient,
+        token_program: spl_token_2022::ID,
+        system_program: solana_sdk::system_program::ID,
+        reserve_attestation: None,
+        event_authority: event_authority(),
+        program: sss_token::ID,
+    }
+    .to_account_metas(None);
+    accounts.extend(
+        approvers
+            .iter()
+            .map(|holder| AccountMeta::new_readonly(role_assignment(config, RoleKind::MintApprover, *holder), false)),
+    );
+    Instruction {
+        program_id: sss_token::ID,
+        accounts,
+        data: sss_token::instruction::ExecuteMintRequest {}.data(),
+    }
+}
+
+/// `role_assignment` is the authority's MintApprover RoleAssignment PDA, if it is not the master authority
+fn reject_mint_request(
+    config: Pubkey,
+    mint: Pubkey,
+    minter: Pubkey,
+    authority: Pubkey,
+    role_assignment: Option<Pubkey>,
+    fiat_reference: &str,
+) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::RejectMintRequest {
+            config,
+            mint,
+            mint_request: mint_request(config, fiat_reference),
+            minter,
+            authority,
+            role_assignment,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::RejectMintRequest {}.data(),
+    }
+}
+
+fn close_expired_mint_request(
+    config: Pubkey,
+    mint: Pubkey,
+    minter: Pubkey,
+    caller: Pubkey,
+    fiat_reference: &str,
+) -> Instruction {
+    Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::CloseExpiredMintRequest {
+            config,
+            mint,
+            mint_request: mint_request(config, fiat_reference),
+            minter,
+            caller,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::CloseExpiredMintRequest {}.data(),
+    }
+}
+
+/// Register `minter` and grant the MintApprover role to each of `approvers`
+async fn setup_mint_requests(
+    context: &mut ProgramTestContext,
+    config: Pubkey,
+    mint: Pubkey,
+    minter: &Keypair,
+    approvers: &[&Keypair],
+) {
+    let authority = context.payer.pubkey();
+    fund(context, minter).await;
+    send(context, add_minter(config, mint, minter.pubkey(), authority, u64::MAX), &[])
+        .await
+        .unwrap();
+    for approver in approvers {
+        fund(context, approver).await;
+        let instruction = grant_role(config, mint, approver.pubkey(), authority, RoleKind::MintApprover);
+        send(context, instruction, &[]).await.unwrap();
+    }
+}
+
+/// Move the clock forward by `seconds`
+async fn advance_clock(context: &mut ProgramTestContext, seconds: i64) {
+    let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    context.set_sysvar(&Clock {
+        unix_timestamp: clock.unix_timestamp + seconds,
+        ..clock
+    });
+}
+
+fn assert_hook_error(result: Result<(), BanksClientError>, error: TransferHookError) {
+    assert_error_code(result, anchor_lang::error::ERROR_CODE_OFFSET + error as u32);
+}
+
+/// Create a council proposal for `instruction`, collect approvals and execute it
+async fn run_council_proposal(
+    context: &mut ProgramTestContext,
+    config: Pubkey,
+    mint: Pubkey,
+    id: u64,
+    instruction: Instruction,
+    proposer: &Keypair,
+    approvers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let expires_at = now(context).await + 24 * 60 * 60;
+    create_council_proposal(context, config, mint, id, &instruction, proposer, expires_at).await?;
+    for approver in approvers {
+        approve_council_proposal(context, config, mint, id, approver).await?;
+    }
+    execute_council_proposal(context, config, mint, id, &instruction, proposer.pubkey(), proposer).await
+}
+
+// ============================================
+// INITIALIZE TESTS
+// ============================================
+
+#[tokio::test]
+async fn test_initialize_sss2_creates_mint_with_extensions() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, true, 0).await;
+    let mint = mint.pubkey();
+
+    let account = context.banks_client.get_account(mint).await.unwrap().unwrap();
+    assert_eq!(account.owner, spl_token_2022::ID);
+    let state = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+
+    // Mint and freeze authorities are the sss_token PDAs
+    assert_eq!(state.base.decimals, 6);
+    assert_eq!(
+        Option::<Pubkey>::from(state.base.mint_authority),
+        Some(pda(&[b"mint_authority", mint.as_ref()]))
+    );
+    assert_eq!(
+        Option::<Pubkey>::from(state.base.freeze_authority),
+        Some(pda(&[b"freeze_authority", mint.as_ref()]))
+    );
+
+    let mut extensions = state.get_extension_types().unwrap();
+    extensions.sort_by_key(|extension| *extension as u16);
+    assert_eq!(
+        extensions,
+        vec![
+            ExtensionType::DefaultAccountState,
+            ExtensionType::PermanentDelegate,
+            ExtensionType::TransferHook,
+            ExtensionType::MetadataPointer,
+            ExtensionType::TokenMetadata,
+        ]
+    );
+
+    let delegate = state.get_extension::<PermanentDelegate>().unwrap();
+    assert_eq!(
+        delegate.delegate,
+        OptionalNonZeroPubkey(pda(&[b"permanent_delegate", mint.as_ref()]))
+    );
+
+    let hook = state.get_extension::<TransferHook>().unwrap();
+    assert_eq!(hook.program_id, OptionalNonZeroPubkey(sss_token::TRANSFER_HOOK_PROGRAM_ID));
+    assert_eq!(hook.authority, OptionalNonZeroPubkey(config));
+
+    let default_state = state.get_extension::<DefaultAccountState>().unwrap();
+    assert_eq!(default_state.state, AccountState::Frozen as u8);
+
+    let pointer = state.get_extension::<MetadataPointer>().unwrap();
+    assert_eq!(pointer.metadata_address, OptionalNonZeroPubkey(mint));
+    assert_eq!(pointer.authority, OptionalNonZeroPubkey(config));
+
+    let metadata = state.get_variable_len_extension::<TokenMetadata>().unwrap();
+    assert_eq!(metadata.mint, mint);
+    assert_eq!(metadata.update_authority, OptionalNonZeroPubkey(config));
+    assert_eq!(metadata.name, "Test USD");
+    assert_eq!(metadata.symbol, "TUSD");
+    assert_eq!(metadata.uri, "https://example.com/tusd.json");
+
+    let stablecoin = fetch_config(&mut context, config).await;
+    assert!(stablecoin.enable_permanent_delegate);
+    assert!(stablecoin.enable_transfer_hook);
+    assert!(stablecoin.default_account_frozen);
+}
+
+#[tokio::test]
+async fn test_initialize_sss1_minimal_stablecoin() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+
+    // SSS-1 only carries the metadata extensions
+    let account = context.banks_client.get_account(mint).await.unwrap().unwrap();
+    let state = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+    let mut extensions = state.get_extension_types().unwrap();
+    extensions.sort_by_key(|extension| *extension as u16);
+    assert_eq!(extensions, vec![ExtensionType::MetadataPointer, ExtensionType::TokenMetadata]);
+    assert_eq!(state.base.supply, 0);
+
+    // The initializer holds every legacy role
+    let stablecoin = fetch_config(&mut context, config).await;
+    assert_eq!(stablecoin.master_authority, authority);
+    assert_eq!(stablecoin.mint, mint);
+    assert_eq!(stablecoin.name, "Test USD");
+    assert_eq!(stablecoin.symbol, "TUSD");
+    assert_eq!(stablecoin.decimals, 6);
+    assert_eq!(stablecoin.pause_mask, 0);
+    assert!(!stablecoin.enable_permanent_delegate);
+    assert!(!stablecoin.enable_transfer_hook);
+    assert!(!stablecoin.default_account_frozen);
+    assert_eq!(stablecoin.blacklister, authority);
+    assert_eq!(stablecoin.pauser, authority);
+    assert_eq!(stablecoin.seizer, authority);
+    assert_eq!(stablecoin.max_supply, 0);
+    assert_eq!(stablecoin.version, sss_token::ACCOUNT_VERSION);
+}
+
+/// Initialize with one oversized metadata string and check nothing was created
+async fn assert_initialize_rejected(args: sss_token::instruction::Initialize) {
+    let mut context = start().await;
+    let mint = Keypair::new();
+    let instruction = initialize_instruction(context.payer.pubkey(), mint.pubkey(), args);
+    let result = send(&mut context, instruction, &[&mint]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+
+    let config = pda(&[b"config", mint.pubkey().as_ref()]);
+    assert!(context.banks_client.get_account(config).await.unwrap().is_none());
+    assert!(context.banks_client.get_account(mint.pubkey()).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_initialize_name_too_long() {
+    let mut args = initialize_args(false, false, false, 0);
+    args.name = "x".repeat(101);
+    assert_initialize_rejected(args).await;
+}
+
+#[tokio::test]
+async fn test_initialize_symbol_too_long() {
+    let mut args = initialize_args(false, false, false, 0);
+    args.symbol = "x".repeat(11);
+    assert_initialize_rejected(args).await;
+}
+
+#[tokio::test]
+async fn test_initialize_uri_too_long() {
+    let mut args = initialize_args(false, false, false, 0);
+    args.uri = "x".repeat(201);
+    assert_initialize_rejected(args).await;
+}
+
+// ============================================
+// MINTER TESTS
+// ============================================
+
+#[tokio::test]
+async fn test_add_minter() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let minter = Pubkey::new_unique();
+
+    let authority = context.payer.pubkey();
+    send(&mut context, add_minter(config, mint, minter, authority, 1_000_000), &[])
+        .await
+        .unwrap();
+
+    let minter_info: MinterInfo = fetch_account(&mut context, pda(&[b"minter", config.as_ref(), minter.as_ref()])).await;
+    assert_eq!(minter_info.authority, minter);
+    assert_eq!(minter_info.quota, 1_000_000);
+    assert_eq!(minter_info.minted, 0);
+    assert_eq!(minter_info.period_limit, 0);
+    assert_eq!(minter_info.version, sss_token::ACCOUNT_VERSION);
+}
+
+#[tokio::test]
+async fn test_add_minter_by_unauthorized() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let minter = Pubkey::new_unique();
+
+    let outsider = Keypair::new();
+    fund(&mut context, &outsider).await;
+    let result = send(&mut context, add_minter(config, mint, minter, outsider.pubkey(), 1_000_000), &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    assert!(!account_exists(&mut context, pda(&[b"minter", config.as_ref(), minter.as_ref()])).await);
+}
+
+#[tokio::test]
+async fn test_mint_tokens() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let minter_info = |minter: Pubkey| pda(&[b"minter", config.as_ref(), minter.as_ref()]);
+
+    let minter = Keypair::new();
+    let authority = context.payer.pubkey();
+    send(&mut context, add_minter(config, mint, minter.pubkey(), authority, 1_000_000), &[])
+        .await
+        .unwrap();
+    let token_account = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+
+    send(&mut context, mint_tokens(config, mint, minter.pubkey(), token_account, 400_000), &[&minter])
+        .await
+        .unwrap();
+    assert_eq!(fetch_token_account(&mut context, token_account).await.amount, 400_000);
+    assert_eq!(supply(&mut context, mint).await, 400_000);
+    let info: MinterInfo = fetch_account(&mut context, minter_info(minter.pubkey())).await;
+    assert_eq!(info.minted, 400_000);
+
+    // Minting up to the quota succeeds; one more token does not
+    send(&mut context, mint_tokens(config, mint, minter.pubkey(), token_account, 600_000), &[&minter])
+        .await
+        .unwrap();
+    let result = send(&mut context, mint_tokens(config, mint, minter.pubkey(), token_account, 1), &[&minter]).await;
+    assert_custom_error(result, StablecoinError::QuotaExceeded);
+    assert_eq!(fetch_token_account(&mut context, token_account).await.amount, 1_000_000);
+}
+
+#[tokio::test]
+async fn test_mint_tokens_without_minter_signature() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+
+    let minter = Keypair::new();
+    let authority = context.payer.pubkey();
+    send(&mut context, add_minter(config, mint, minter.pubkey(), authority, 1_000_000), &[])
+        .await
+        .unwrap();
+    let token_account = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+
+    // The minter passed as a non-signer
+    let mut instruction = mint_tokens(config, mint, minter.pubkey(), token_account, 1_000);
+    for meta in instruction.accounts.iter_mut().filter(|meta| meta.pubkey == minter.pubkey()) {
+        meta.is_signer = false;
+    }
+    let result = send(&mut context, instruction, &[]).await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::AccountNotSigner as u32);
+
+    // An impostor signing against the minter's MinterInfo
+    let impostor = Keypair::new();
+    let mut instruction = mint_tokens(config, mint, impostor.pubkey(), token_account, 1_000);
+    instruction.accounts[3].pubkey = pda(&[b"minter", config.as_ref(), minter.pubkey().as_ref()]);
+    let result = send(&mut context, instruction, &[&impostor]).await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::ConstraintSeeds as u32);
+
+    let info: MinterInfo = fetch_account(&mut context, pda(&[b"minter", config.as_ref(), minter.pubkey().as_ref()])).await;
+    assert_eq!(info.minted, 0);
+    assert_eq!(supply(&mut context, mint).await, 0);
+}
+
+#[tokio::test]
+async fn test_remove_minter() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+
+    let minter = Keypair::new();
+    let authority = context.payer.pubkey();
+    send(&mut context, add_minter(config, mint, minter.pubkey(), authority, 1_000_000), &[])
+        .await
+        .unwrap();
+    let token_account = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    send(&mut context, mint_tokens(config, mint, minter.pubkey(), token_account, 1_000), &[&minter])
+        .await
+        .unwrap();
+
+    send(&mut context, remove_minter(config, mint, minter.pubkey(), authority), &[])
+        .await
+        .unwrap();
+    let info: MinterInfo = fetch_account(&mut context, pda(&[b"minter", config.as_ref(), minter.pubkey().as_ref()])).await;
+    assert_eq!(info.quota, 0);
+    assert_eq!(info.minted, 1_000);
+
+    let result = send(&mut context, mint_tokens(config, mint, minter.pubkey(), token_account, 1), &[&minter]).await;
+    assert_custom_error(result, StablecoinError::QuotaExceeded);
+}
+
+#[tokio::test]
+async fn test_remove_minter_by_unauthorized() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let minter = Pubkey::new_unique();
+    let authority = context.payer.pubkey();
+    send(&mut context, add_minter(config, mint, minter, authority, 1_000_000), &[])
+        .await
+        .unwrap();
+
+    let outsider = Keypair::new();
+    let result = send(&mut context, remove_minter(config, mint, minter, outsider.pubkey()), &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    let info: MinterInfo = fetch_account(&mut context, pda(&[b"minter", config.as_ref(), minter.as_ref()])).await;
+    assert_eq!(info.quota, 1_000_000);
+}
+
+#[tokio::test]
+async fn test_mint_tokens_over_quota() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+
+    let minter = Keypair::new();
+    let authority = context.payer.pubkey();
+    send(&mut context, add_minter(config, mint, minter.pubkey(), authority, 1_000_000), &[])
+        .await
+        .unwrap();
+    let token_account = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+
+    let result = send(&mut context, mint_tokens(config, mint, minter.pubkey(), token_account, 2_000_000), &[&minter]).await;
+    assert_custom_error(result, StablecoinError::QuotaExceeded);
+    assert_eq!(fetch_token_account(&mut context, token_account).await.amount, 0);
+    let info: MinterInfo = fetch_account(&mut context, pda(&[b"minter", config.as_ref(), minter.pubkey().as_ref()])).await;
+    assert_eq!(info.minted, 0);
+}
+
+#[tokio::test]
+async fn test_update_minter_quota() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+
+    let minter = Keypair::new();
+    let authority = context.payer.pubkey();
+    send(&mut context, add_minter(config, mint, minter.pubkey(), authority, 1_000), &[])
+        .await
+        .unwrap();
+    let token_account = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    send(&mut context, mint_tokens(config, mint, minter.pubkey(), token_account, 1_000), &[&minter])
+        .await
+        .unwrap();
+
+    // The minted total is kept and counts against the new quota
+    send(&mut context, update_minter_quota(config, mint, minter.pubkey(), authority, 5_000), &[])
+        .await
+        .unwrap();
+    let info: MinterInfo = fetch_account(&mut context, pda(&[b"minter", config.as_ref(), minter.pubkey().as_ref()])).await;
+    assert_eq!(info.quota, 5_000);
+    assert_eq!(info.minted, 1_000);
+
+    send(&mut context, mint_tokens(config, mint, minter.pubkey(), token_account, 4_000), &[&minter])
+        .await
+        .unwrap();
+    let result = send(&mut context, mint_tokens(config, mint, minter.pubkey(), token_account, 1), &[&minter]).await;
+    assert_custom_error(result, StablecoinError::QuotaExceeded);
+}
+
+#[tokio::test]
+async fn test_update_minter_quota_by_unauthorized() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let minter = Pubkey::new_unique();
+    let authority = context.payer.pubkey();
+    send(&mut context, add_minter(config, mint, minter, authority, 1_000_000), &[])
+        .await
+        .unwrap();
+
+    let outsider = Keypair::new();
+    let result = send(&mut context, update_minter_quota(config, mint, minter, outsider.pubkey(), u64::MAX), &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    let info: MinterInfo = fetch_account(&mut context, pda(&[b"minter", config.as_ref(), minter.as_ref()])).await;
+    assert_eq!(info.quota, 1_000_000);
+}
+
+#[tokio::test]
+async fn test_mint_tokens_over_period_allowance() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+
+    let minter = Keypair::new();
+    let authority = context.payer.pubkey();
+    send(&mut context, add_minter(config, mint, minter.pubkey(), authority, 1_000_000), &[])
+        .await
+        .unwrap();
+    send(&mut context, update_minter_allowance(config, mint, minter.pubkey(), authority, 86_400, 1_000), &[])
+        .await
+        .unwrap();
+    let token_account = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+
+    send(&mut context, mint_tokens(config, mint, minter.pubkey(), token_account, 1_000), &[&minter])
+        .await
+        .unwrap();
+    let result = send(&mut context, mint_tokens(config, mint, minter.pubkey(), token_account, 1), &[&minter]).await;
+    assert_custom_error(result, StablecoinError::PeriodAllowanceExceeded);
+
+    // The window resets once it has elapsed
+    advance_clock(&mut context, 86_400).await;
+    send(&mut context, mint_tokens(config, mint, minter.pubkey(), token_account, 1_000), &[&minter])
+        .await
+        .unwrap();
+    let info: MinterInfo = fetch_account(&mut context, pda(&[b"minter", config.as_ref(), minter.pubkey().as_ref()])).await;
+    assert_eq!(info.period_minted, 1_000);
+    assert_eq!(info.minted, 2_000);
+}
+
+#[tokio::test]
+async fn test_mint_tokens_over_max_supply() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 1_000_000).await;
+    let mint = mint.pubkey();
+
+    let minter = Keypair::new();
+    let authority = context.payer.pubkey();
+    send(&mut context, add_minter(config, mint, minter.pubkey(), authority, 10_000_000), &[])
+        .await
+        .unwrap();
+    let token_account = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    send(&mut context, mint_tokens(config, mint, minter.pubkey(), token_account, 900_000), &[&minter])
+        .await
+        .unwrap();
+
+    // The quota allows it, the supply cap does not
+    let result = send(&mut context, mint_tokens(config, mint, minter.pubkey(), token_account, 200_000), &[&minter]).await;
+    assert_custom_error(result, StablecoinError::SupplyCapExceeded);
+    assert_eq!(supply(&mut context, mint).await, 900_000);
+
+    send(&mut context, update_max_supply(config, mint, authority, 2_000_000), &[])
+        .await
+        .unwrap();
+    assert_eq!(fetch_config(&mut context, config).await.max_supply, 2_000_000);
+    send(&mut context, mint_tokens(config, mint, minter.pubkey(), token_account, 200_000), &[&minter])
+        .await
+        .unwrap();
+    assert_eq!(supply(&mut context, mint).await, 1_100_000);
+}
+
+#[tokio::test]
+async fn test_update_max_supply_by_unauthorized() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 1_000_000).await;
+    let mint = mint.pubkey();
+
+    let outsider = Keypair::new();
+    let result = send(&mut context, update_max_supply(config, mint, outsider.pubkey(), 0), &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    assert_eq!(fetch_config(&mut context, config).await.max_supply, 1_000_000);
+}
+
+// ============================================
+// BURNER TESTS
+// ============================================
+
+#[tokio::test]
+async fn test_add_and_remove_burner() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let burner = Keypair::new();
+    let burner_info = pda(&[b"burner", config.as_ref(), burner.pubkey().as_ref()]);
+    let token_account = funded_token_account(&mut context, config, mint, burner.pubkey(), 1_000).await;
+
+    let authority = context.payer.pubkey();
+    send(&mut context, add_burner(config, mint, burner.pubkey(), authority, Some(500)), &[])
+        .await
+        .unwrap();
+    let info: BurnerInfo = fetch_account(&mut context, burner_info).await;
+    assert_eq!(info.authority, burner.pubkey());
+    assert_eq!(info.burn_limit, Some(500));
+    assert_eq!(info.burned, 0);
+    assert!(info.active);
+
+    let result = send(&mut context, add_burner(config, mint, burner.pubkey(), authority, None), &[]).await;
+    assert_custom_error(result, StablecoinError::BurnerAlreadyActive);
+
+    send(&mut context, burn_tokens(config, mint, token_account, burner.pubkey(), 200), &[&burner])
+        .await
+        .unwrap();
+
+    // Removal deactivates the burner but keeps its burned total
+    send(&mut context, remove_burner(config, mint, burner.pubkey(), authority), &[])
+        .await
+        .unwrap();
+    let info: BurnerInfo = fetch_account(&mut context, burner_info).await;
+    assert!(!info.active);
+    assert_eq!(info.burned, 200);
+    let result = send(&mut context, burn_tokens(config, mint, token_account, burner.pubkey(), 100), &[&burner]).await;
+    assert_custom_error(result, StablecoinError::BurnerInactive);
+
+    // Re-adding reactivates it with the new limit, and the burned total carries over
+    send(&mut context, add_burner(config, mint, burner.pubkey(), authority, Some(300)), &[])
+        .await
+        .unwrap();
+    let info: BurnerInfo = fetch_account(&mut context, burner_info).await;
+    assert!(info.active);
+    assert_eq!(info.burn_limit, Some(300));
+    assert_eq!(info.burned, 200);
+    let result = send(&mut context, burn_tokens(config, mint, token_account, burner.pubkey(), 101), &[&burner]).await;
+    assert_custom_error(result, StablecoinError::BurnLimitExceeded);
+}
+
+#[tokio::test]
+async fn test_add_burner_by_unauthorized() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let burner = Pubkey::new_unique();
+
+    let outsider = Keypair::new();
+    fund(&mut context, &outsider).await;
+    let result = send(&mut context, add_burner(config, mint, burner, outsider.pubkey(), None), &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    assert!(!account_exists(&mut context, pda(&[b"burner", config.as_ref(), burner.as_ref()])).await);
+}
+
+#[tokio::test]
+async fn test_burn_tokens() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let burner = Keypair::new();
+    let token_account = funded_token_account(&mut context, config, mint, burner.pubkey(), 1_000).await;
+
+    let authority = context.payer.pubkey();
+    send(&mut context, add_burner(config, mint, burner.pubkey(), authority, None), &[])
+        .await
+        .unwrap();
+    send(&mut context, burn_tokens(config, mint, token_account, burner.pubkey(), 400), &[&burner])
+        .await
+        .unwrap();
+
+    assert_eq!(fetch_token_account(&mut context, token_account).await.amount, 600);
+    assert_eq!(supply(&mut context, mint).await, 600);
+    let info: BurnerInfo = fetch_account(&mut context, pda(&[b"burner", config.as_ref(), burner.pubkey().as_ref()])).await;
+    assert_eq!(info.burned, 400);
+}
+
+#[tokio::test]
+async fn test_burn_tokens_by_unregistered_burner() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+
+    // A holder without a BurnerInfo cannot burn through the program
+    let holder = Keypair::new();
+    let token_account = funded_token_account(&mut context, config, mint, holder.pubkey(), 1_000).await;
+    let result = send(&mut context, burn_tokens(config, mint, token_account, holder.pubkey(), 100), &[&holder]).await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::AccountNotInitialized as u32);
+
+    // A burner cannot go past its burn limit
+    let authority = context.payer.pubkey();
+    send(&mut context, add_burner(config, mint, holder.pubkey(), authority, Some(1_000)), &[])
+        .await
+        .unwrap();
+    let result = send(&mut context, burn_tokens(config, mint, token_account, holder.pubkey(), 1_001), &[&holder]).await;
+    assert_custom_error(result, StablecoinError::BurnLimitExceeded);
+    assert_eq!(fetch_token_account(&mut context, token_account).await.amount, 1_000);
+}
+
+#[tokio::test]
+async fn test_burn_tokens_more_than_balance() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let burner = Keypair::new();
+    let token_account = funded_token_account(&mut context, config, mint, burner.pubkey(), 100).await;
+
+    let authority = context.payer.pubkey();
+    send(&mut context, add_burner(config, mint, burner.pubkey(), authority, None), &[])
+        .await
+        .unwrap();
+    let result = send(&mut context, burn_tokens(config, mint, token_account, burner.pubkey(), 200), &[&burner]).await;
+    assert_error_code(result, TokenError::InsufficientFunds as u32);
+    assert_eq!(fetch_token_account(&mut context, token_account).await.amount, 100);
+    let info: BurnerInfo = fetch_account(&mut context, pda(&[b"burner", config.as_ref(), burner.pubkey().as_ref()])).await;
+    assert_eq!(info.burned, 0);
+}
+
+#[tokio::test]
+async fn test_burn_from_for_redemption() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, false, false, 0).await;
+    let mint = mint.pubkey();
+    let token_account = funded_token_account(&mut context, config, mint, Pubkey::new_unique(), 1_000).await;
+
+    let burner = Keypair::new();
+    let authority = context.payer.pubkey();
+    send(&mut context, add_burner(config, mint, burner.pubkey(), authority, Some(700)), &[])
+        .await
+        .unwrap();
+
+    // The holder does not sign; the permanent delegate PDA does
+    send(&mut context, burn_from(config, mint, token_account, burner.pubkey(), 600, "REDEEM-1"), &[&burner])
+        .await
+        .unwrap();
+    assert_eq!(fetch_token_account(&mut context, token_account).await.amount, 400);
+    assert_eq!(supply(&mut context, mint).await, 400);
+    let info: BurnerInfo = fetch_account(&mut context, pda(&[b"burner", config.as_ref(), burner.pubkey().as_ref()])).await;
+    assert_eq!(info.burned, 600);
+
+    let result = send(&mut context, burn_from(config, mint, token_account, burner.pubkey(), 101, "REDEEM-2"), &[&burner]).await;
+    assert_custom_error(result, StablecoinError::BurnLimitExceeded);
+    let result = send(&mut context, burn_from(config, mint, token_account, burner.pubkey(), 0, "REDEEM-2"), &[&burner]).await;
+    assert_custom_error(result, StablecoinError::InvalidAmount);
+}
+
+#[tokio::test]
+async fn test_burn_from_permanent_delegate_disabled() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let token_account = funded_token_account(&mut context, config, mint, Pubkey::new_unique(), 1_000).await;
+
+    let burner = Keypair::new();
+    let authority = context.payer.pubkey();
+    send(&mut context, add_burner(config, mint, burner.pubkey(), authority, None), &[])
+        .await
+        .unwrap();
+    let result = send(&mut context, burn_from(config, mint, token_account, burner.pubkey(), 100, "REDEEM-1"), &[&burner]).await;
+    assert_custom_error(result, StablecoinError::PermanentDelegateNotEnabled);
+    assert_eq!(fetch_token_account(&mut context, token_account).await.amount, 1_000);
+}
+
+// ============================================
+// FREEZE / THAW TESTS
+// ============================================
+
+#[tokio::test]
+async fn test_freeze_and_thaw_token_account() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let seizer = context.payer.pubkey();
+
+    let holder = Keypair::new();
+    fund(&mut context, &holder).await;
+    let source = funded_token_account(&mut context, config, mint, holder.pubkey(), 1_000).await;
+    let destination = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+
+    // Frozen accounts cannot send
+    send(&mut context, freeze_or_thaw(config, mint, source, seizer, true), &[])
+        .await
+        .unwrap();
+    assert_eq!(fetch_token_account(&mut context, source).await.state, AccountState::Frozen);
+    let result = transfer(&mut context, mint, source, destination, &holder, 100).await;
+    assert_error_code(result, TokenError::AccountFrozen as u32);
+
+    // Thawed accounts can send again
+    send(&mut context, freeze_or_thaw(config, mint, source, seizer, false), &[])
+        .await
+        .unwrap();
+    assert_eq!(fetch_token_account(&mut context, source).await.state, AccountState::Initialized);
+    transfer(&mut context, mint, source, destination, &holder, 100).await.unwrap();
+    assert_eq!(fetch_token_account(&mut context, source).await.amount, 900);
+    assert_eq!(fetch_token_account(&mut context, destination).await.amount, 100);
+}
+
+/// Freeze or thaw (through the alias or the `_pda` instruction) as a non-seizer
+async fn assert_unauthorized_freeze_or_thaw(freeze: bool, pda_instruction: bool) {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let token_account = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    if !freeze {
+        let seizer = context.payer.pubkey();
+        send(&mut context, freeze_or_thaw(config, mint, token_account, seizer, true), &[])
+            .await
+            .unwrap();
+    }
+    let state_before = fetch_token_account(&mut context, token_account).await.state;
+
+    let outsider = Keypair::new();
+    let mut instruction = freeze_or_thaw(config, mint, token_account, outsider.pubkey(), freeze);
+    if pda_instruction {
+        instruction.data = if freeze {
+            sss_token::instruction::FreezeTokenAccountPda {}.data()
+        } else {
+            sss_token::instruction::ThawTokenAccountPda {}.data()
+        };
+    }
+    let result = send(&mut context, instruction, &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    assert_eq!(fetch_token_account(&mut context, token_account).await.state, state_before);
+}
+
+#[tokio::test]
+async fn test_freeze_by_unauthorized() {
+    assert_unauthorized_freeze_or_thaw(true, false).await;
+}
+
+#[tokio::test]
+async fn test_thaw_by_unauthorized() {
+    assert_unauthorized_freeze_or_thaw(false, false).await;
+}
+
+#[tokio::test]
+async fn test_freeze_pda_by_unauthorized() {
+    assert_unauthorized_freeze_or_thaw(true, true).await;
+}
+
+#[tokio::test]
+async fn test_thaw_pda_by_unauthorized() {
+    assert_unauthorized_freeze_or_thaw(false, true).await;
+}
+
+// ============================================
+// AUTHORITY TESTS
+// ============================================
+
+#[tokio::test]
+async fn test_transfer_authority() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let old_authority = context.payer.pubkey();
+    let new_authority = Keypair::new();
+    fund(&mut context, &new_authority).await;
+
+    // Proposing only records the pending authority
+    send(&mut context, transfer_authority(config, mint, old_authority, new_authority.pubkey()), &[])
+        .await
+        .unwrap();
+    let state = fetch_config(&mut context, config).await;
+    assert_eq!(state.master_authority, old_authority);
+    assert_eq!(state.pending_master_authority, Some(new_authority.pubkey()));
+
+    send(&mut context, accept_master_authority(config, mint, new_authority.pubkey()), &[&new_authority])
+        .await
+        .unwrap();
+    let state = fetch_config(&mut context, config).await;
+    assert_eq!(state.master_authority, new_authority.pubkey());
+    assert_eq!(state.pending_master_authority, None);
+
+    // The old authority has lost its privileges
+    let result = send(&mut context, add_minter(config, mint, Pubkey::new_unique(), old_authority, 1), &[]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    send(&mut context, add_minter(config, mint, Pubkey::new_unique(), new_authority.pubkey(), 1), &[&new_authority])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_transfer_authority_by_unauthorized() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+
+    let outsider = Keypair::new();
+    let result = send(&mut context, transfer_authority(config, mint, outsider.pubkey(), outsider.pubkey()), &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    let state = fetch_config(&mut context, config).await;
+    assert_eq!(state.master_authority, context.payer.pubkey());
+    assert_eq!(state.pending_master_authority, None);
+}
+
+#[tokio::test]
+async fn test_accept_master_authority_by_wrong_signer() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let proposed = Pubkey::new_unique();
+    propose_master_authority(&mut context, config, mint, proposed).await.unwrap();
+
+    let impostor = Keypair::new();
+    let result = send(&mut context, accept_master_authority(config, mint, impostor.pubkey()), &[&impostor]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    let state = fetch_config(&mut context, config).await;
+    assert_eq!(state.master_authority, context.payer.pubkey());
+    assert_eq!(state.pending_master_authority, Some(proposed));
+}
+
+#[tokio::test]
+async fn test_cancel_master_authority_transfer() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let proposed = Keypair::new();
+    propose_master_authority(&mut context, config, mint, proposed.pubkey()).await.unwrap();
+
+    let authority = context.payer.pubkey();
+    send(&mut context, cancel_master_authority_transfer(config, mint, authority), &[])
+        .await
+        .unwrap();
+    assert_eq!(fetch_config(&mut context, config).await.pending_master_authority, None);
+
+    let result = send(&mut context, accept_master_authority(config, mint, proposed.pubkey()), &[&proposed]).await;
+    assert_custom_error(result, StablecoinError::NoPendingAuthority);
+    refresh_blockhash(&mut context).await;
+    let result = send(&mut context, cancel_master_authority_transfer(config, mint, authority), &[]).await;
+    assert_custom_error(result, StablecoinError::NoPendingAuthority);
+}
+
+// ============================================
+// ROLE TESTS
+// ============================================
+
+#[tokio::test]
+async fn test_grant_and_revoke_role() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let first = Keypair::new();
+    let second = Keypair::new();
+
+    for holder in [&first, &second] {
+        send(&mut context, grant_role(config, mint, holder.pubkey(), authority, RoleKind::Pauser), &[])
+            .await
+            .unwrap();
+        let assignment: RoleAssignment =
+            fetch_account(&mut context, role_assignment(config, RoleKind::Pauser, holder.pubkey())).await;
+        assert_eq!(assignment.holder, holder.pubkey());
+        assert_eq!(assignment.role, RoleKind::Pauser);
+    }
+
+    // Both holders can pause with their RoleAssignment PDA
+    let first_role = role_assignment(config, RoleKind::Pauser, first.pubkey());
+    let second_role = role_assignment(config, RoleKind::Pauser, second.pubkey());
+    send(&mut context, pause_or_unpause(config, mint, first.pubkey(), Some(first_role), PAUSE_MINT, true), &[&first])
+        .await
+        .unwrap();
+    send(&mut context, pause_or_unpause(config, mint, second.pubkey(), Some(second_role), PAUSE_MINT, false), &[&second])
+        .await
+        .unwrap();
+    assert_eq!(fetch_config(&mut context, config).await.pause_mask, 0);
+
+    // Revoking closes the PDA, so the holder can no longer pause
+    send(&mut context, revoke_role(config, mint, first.pubkey(), authority, RoleKind::Pauser), &[])
+        .await
+        .unwrap();
+    assert!(!account_exists(&mut context, first_role).await);
+    let result = send(&mut context, pause_or_unpause(config, mint, first.pubkey(), None, PAUSE_MINT, true), &[&first]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    let result =
+        send(&mut context, pause_or_unpause(config, mint, first.pubkey(), Some(first_role), PAUSE_MINT, true), &[&first]).await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::AccountNotInitialized as u32);
+
+    // The legacy config.pauser still works without a PDA
+    send(&mut context, pause_or_unpause(config, mint, authority, None, PAUSE_MINT, true), &[])
+        .await
+        .unwrap();
+    assert_eq!(fetch_config(&mut context, config).await.pause_mask, PAUSE_MINT);
+}
+
+#[tokio::test]
+async fn test_update_roles() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let role_holder = Keypair::new();
+
+    send(&mut context, update_roles(config, mint, authority, role_holder.pubkey()), &[])
+        .await
+        .unwrap();
+    let state = fetch_config(&mut context, config).await;
+    assert_eq!(state.blacklister, role_holder.pubkey());
+    assert_eq!(state.pauser, role_holder.pubkey());
+    assert_eq!(state.seizer, role_holder.pubkey());
+
+    // The previous holder (the master authority) no longer has the role
+    let result = send(&mut context, pause_or_unpause(config, mint, authority, None, PAUSE_MINT, true), &[]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    send(&mut context, pause_or_unpause(config, mint, role_holder.pubkey(), None, PAUSE_MINT, true), &[&role_holder])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_update_roles_by_unauthorized() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+
+    let outsider = Keypair::new();
+    let result = send(&mut context, update_roles(config, mint, outsider.pubkey(), outsider.pubkey()), &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    let state = fetch_config(&mut context, config).await;
+    assert_eq!(state.pauser, context.payer.pubkey());
+    assert_eq!(state.blacklister, context.payer.pubkey());
+    assert_eq!(state.seizer, context.payer.pubkey());
+}
+
+// ============================================
+// PAUSE TESTS
+// ============================================
+
+#[tokio::test]
+async fn test_pause_and_unpause() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let token_account = funded_token_account(&mut context, config, mint, authority, 1_000).await;
+    send(&mut context, add_burner(config, mint, authority, authority, None), &[])
+        .await
+        .unwrap();
+
+    send(&mut context, pause_or_unpause(config, mint, authority, None, PAUSE_MINT, true), &[])
+        .await
+        .unwrap();
+    assert_eq!(fetch_config(&mut context, config).await.pause_mask, PAUSE_MINT);
+
+    // Only minting is blocked
+    let result = send(&mut context, mint_tokens(config, mint, authority, token_account, 100), &[]).await;
+    assert_custom_error(result, StablecoinError::TokenPaused);
+    send(&mut context, burn_tokens(config, mint, token_account, authority, 100), &[])
+        .await
+        .unwrap();
+
+    send(&mut context, pause_or_unpause(config, mint, authority, None, PAUSE_MINT, false), &[])
+        .await
+        .unwrap();
+    assert_eq!(fetch_config(&mut context, config).await.pause_mask, 0);
+    send(&mut context, mint_tokens(config, mint, authority, token_account, 200), &[])
+        .await
+        .unwrap();
+    assert_eq!(fetch_token_account(&mut context, token_account).await.amount, 1_100);
+
+    for mask in [0, 1 << 7] {
+        let result = send(&mut context, pause_or_unpause(config, mint, authority, None, mask, true), &[]).await;
+        assert_custom_error(result, StablecoinError::InvalidPauseMask);
+    }
+}
+
+#[tokio::test]
+async fn test_pause_by_unauthorized() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+
+    let outsider = Keypair::new();
+    let result = send(&mut context, pause_or_unpause(config, mint, outsider.pubkey(), None, PAUSE_ALL, true), &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    assert_eq!(fetch_config(&mut context, config).await.pause_mask, 0);
+}
+
+#[tokio::test]
+async fn test_unpause_by_unauthorized() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    send(&mut context, pause_or_unpause(config, mint, authority, None, PAUSE_ALL, true), &[])
+        .await
+        .unwrap();
+
+    let outsider = Keypair::new();
+    let result = send(&mut context, pause_or_unpause(config, mint, outsider.pubkey(), None, PAUSE_ALL, false), &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    assert_eq!(fetch_config(&mut context, config).await.pause_mask, PAUSE_ALL);
+}
+
+#[tokio::test]
+async fn test_mint_tokens_when_paused() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+
+    let minter = Keypair::new();
+    let authority = context.payer.pubkey();
+    send(&mut context, add_minter(config, mint, minter.pubkey(), authority, 1_000_000), &[])
+        .await
+        .unwrap();
+    let token_account = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    send(&mut context, pause_or_unpause(config, mint, authority, None, PAUSE_MINT, true), &[])
+        .await
+        .unwrap();
+
+    let result = send(&mut context, mint_tokens(config, mint, minter.pubkey(), token_account, 1_000), &[&minter]).await;
+    assert_custom_error(result, StablecoinError::TokenPaused);
+    assert_eq!(fetch_token_account(&mut context, token_account).await.amount, 0);
+    let info: MinterInfo = fetch_account(&mut context, pda(&[b"minter", config.as_ref(), minter.pubkey().as_ref()])).await;
+    assert_eq!(info.minted, 0);
+}
+
+#[tokio::test]
+async fn test_burn_tokens_when_paused() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let burner = Keypair::new();
+    let token_account = funded_token_account(&mut context, config, mint, burner.pubkey(), 1_000).await;
+
+    let authority = context.payer.pubkey();
+    send(&mut context, add_burner(config, mint, burner.pubkey(), authority, None), &[])
+        .await
+        .unwrap();
+    send(&mut context, pause_or_unpause(config, mint, authority, None, PAUSE_BURN, true), &[])
+        .await
+        .unwrap();
+
+    let result = send(&mut context, burn_tokens(config, mint, token_account, burner.pubkey(), 100), &[&burner]).await;
+    assert_custom_error(result, StablecoinError::TokenPaused);
+    assert_eq!(fetch_token_account(&mut context, token_account).await.amount, 1_000);
+}
+
+// ============================================
+// EVENT TESTS
+// ============================================
+
+#[tokio::test]
+async fn test_event_authority_pda() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let minter = Pubkey::new_unique();
+
+    let authority = context.payer.pubkey();
+    let events: Vec<MinterAdded> = simulate_events(&mut context, add_minter(config, mint, minter, authority, 1_000), &[]).await;
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(event.mint, mint);
+    assert_eq!(event.authority, authority);
+    assert_eq!(event.minter, minter);
+    assert_eq!(event.quota, 1_000);
+}
+
+// ============================================
+// METADATA TESTS
+// ============================================
+
+#[tokio::test]
+async fn test_update_metadata() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+
+    send(&mut context, update_metadata(config, mint, authority, None, Some("Renamed Test USD Coin"), None, None), &[])
+        .await
+        .unwrap();
+    let metadata = fetch_token_metadata(&mut context, mint).await;
+    assert_eq!(metadata.name, "Renamed Test USD Coin");
+    assert_eq!(metadata.symbol, "TUSD");
+    assert_eq!(metadata.uri, "https://example.com/tusd.json");
+    let state = fetch_config(&mut context, config).await;
+    assert_eq!(state.name, "Renamed Test USD Coin");
+    assert_eq!(state.symbol, "TUSD");
+    assert_rent_exempt(&mut context, mint).await;
+
+    // A Metadata role holder can update it too
+    let holder = Keypair::new();
+    fund(&mut context, &holder).await;
+    send(&mut context, grant_role(config, mint, holder.pubkey(), authority, RoleKind::Metadata), &[])
+        .await
+        .unwrap();
+    let role = role_assignment(config, RoleKind::Metadata, holder.pubkey());
+    let uri = "https://example.com/metadata/renamed-test-usd-coin.json";
+    send(&mut context, update_metadata(config, mint, holder.pubkey(), Some(role), None, Some("RTUSD"), Some(uri)), &[&holder])
+        .await
+        .unwrap();
+    let metadata = fetch_token_metadata(&mut context, mint).await;
+    assert_eq!(metadata.name, "Renamed Test USD Coin");
+    assert_eq!(metadata.symbol, "RTUSD");
+    assert_eq!(metadata.uri, uri);
+    let state = fetch_config(&mut context, config).await;
+    assert_eq!(state.symbol, "RTUSD");
+    assert_eq!(state.uri, uri);
+    assert_rent_exempt(&mut context, mint).await;
+}
+
+#[tokio::test]
+async fn test_update_metadata_by_unauthorized() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+
+    let outsider = Keypair::new();
+    let result = send(&mut context, update_metadata(config, mint, outsider.pubkey(), None, Some("Fake"), None, None), &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    assert_eq!(fetch_token_metadata(&mut context, mint).await.name, "Test USD");
+    assert_eq!(fetch_config(&mut context, config).await.name, "Test USD");
+}
+
+#[tokio::test]
+async fn test_update_metadata_symbol_too_long() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+
+    let symbol = "S".repeat(11);
+    let result = send(&mut context, update_metadata(config, mint, authority, None, Some("New"), Some(&symbol), None), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+    let metadata = fetch_token_metadata(&mut context, mint).await;
+    assert_eq!(metadata.name, "Test USD");
+    assert_eq!(metadata.symbol, "TUSD");
+}
+
+#[tokio::test]
+async fn test_set_and_remove_metadata_field() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let fields = [
+        ("issuer_legal_entity", "Test Issuer Ltd"),
+        ("jurisdiction", "US"),
+        ("attestation_url", "https://example.com/attestations"),
+    ];
+
+    for (key, value) in fields {
+        send(&mut context, set_metadata_field(config, mint, authority, key, value), &[])
+            .await
+            .unwrap();
+        assert_rent_exempt(&mut context, mint).await;
+    }
+    let metadata = fetch_token_metadata(&mut context, mint).await;
+    let expected: Vec<_> = fields.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    assert_eq!(metadata.additional_metadata, expected);
+
+    // Setting an existing key overwrites it in place
+    send(&mut context, set_metadata_field(config, mint, authority, "jurisdiction", "EU"), &[])
+        .await
+        .unwrap();
+    let metadata = fetch_token_metadata(&mut context, mint).await;
+    assert_eq!(metadata.additional_metadata[1], ("jurisdiction".to_string(), "EU".to_string()));
+    assert_eq!(metadata.additional_metadata.len(), 3);
+
+    // Removing a key shrinks the mint
+    let size_before = context.banks_client.get_account(mint).await.unwrap().unwrap().data.len();
+    send(&mut context, remove_metadata_field(config, mint, authority, "jurisdiction"), &[])
+        .await
+        .unwrap();
+    let size_after = context.banks_client.get_account(mint).await.unwrap().unwrap().data.len();
+    assert!(size_after < size_before);
+    let metadata = fetch_token_metadata(&mut context, mint).await;
+    assert!(metadata.additional_metadata.iter().all(|(k, _)| k != "jurisdiction"));
+    assert_eq!(metadata.additional_metadata.len(), 2);
+}
+
+#[tokio::test]
+async fn test_set_metadata_field_reserved_key() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+
+    let long_key = "k".repeat(MAX_METADATA_KEY_LEN + 1);
+    let long_value = "v".repeat(MAX_METADATA_VALUE_LEN + 1);
+    for (key, value) in [
+        ("name", "Fake"),
+        ("symbol", "FAKE"),
+        ("uri", "https://example.com/fake.json"),
+        ("", "value"),
+        (long_key.as_str(), "value"),
+        ("jurisdiction", long_value.as_str()),
+    ] {
+        let result = send(&mut context, set_metadata_field(config, mint, authority, key, value), &[]).await;
+        assert_custom_error(result, StablecoinError::InvalidAccount);
+    }
+    let metadata = fetch_token_metadata(&mut context, mint).await;
+    assert_eq!(metadata.name, "Test USD");
+    assert!(metadata.additional_metadata.is_empty());
+}
+
+#[tokio::test]
+async fn test_set_metadata_field_too_many_fields() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+
+    for i in 0..MAX_METADATA_FIELDS {
+        send(&mut context, set_metadata_field(config, mint, authority, &format!("field_{i}"), "value"), &[])
+            .await
+            .unwrap();
+    }
+    let result = send(&mut context, set_metadata_field(config, mint, authority, "one_too_many", "value"), &[]).await;
+    assert_custom_error(result, StablecoinError::TooManyMetadataFields);
+
+    // Overwriting an existing key still works at the limit
+    send(&mut context, set_metadata_field(config, mint, authority, "field_0", "updated"), &[])
+        .await
+        .unwrap();
+    let metadata = fetch_token_metadata(&mut context, mint).await;
+    assert_eq!(metadata.additional_metadata.len(), MAX_METADATA_FIELDS);
+    assert_eq!(metadata.additional_metadata[0].1, "updated");
+}
+
+#[tokio::test]
+async fn test_remove_metadata_field_not_found() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+
+    let result = send(&mut context, remove_metadata_field(config, mint, authority, "jurisdiction"), &[]).await;
+    assert_custom_error(result, StablecoinError::MetadataFieldNotFound);
+
+    send(&mut context, set_metadata_field(config, mint, authority, "jurisdiction", "US"), &[])
+        .await
+        .unwrap();
+    let outsider = Keypair::new();
+    let result = send(&mut context, remove_metadata_field(config, mint, outsider.pubkey(), "jurisdiction"), &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    assert_eq!(fetch_token_metadata(&mut context, mint).await.additional_metadata.len(), 1);
+}
+
+// ============================================
+// BLACKLIST TESTS
+// ============================================
+
+#[tokio::test]
+async fn test_add_to_blacklist() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let user = Keypair::new();
+    let source = funded_token_account(&mut context, config, mint, user.pubkey(), 1_000).await;
+    let destination = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+
+    send(&mut context, add_to_blacklist(config, mint, authority, user.pubkey(), blacklist_args(None)), &[])
+        .await
+        .unwrap();
+    let entry: BlacklistEntry = fetch_account(&mut context, blacklist_entry(config, user.pubkey())).await;
+    assert_eq!(entry.user, user.pubkey());
+    assert_eq!(entry.reason_code, BlacklistReason::Sanctions);
+    assert_eq!(entry.reason, "OFAC SDN match");
+    assert_eq!(entry.case_id, "CASE-001");
+    assert_eq!(entry.added_by, authority);
+    assert_eq!(entry.timestamp, now(&mut context).await);
+    assert_eq!(entry.expires_at, None);
+
+    // The transfer hook now rejects the user's transfers
+    let result = transfer(&mut context, mint, source, destination, &user, 100).await;
+    assert_hook_error(result, TransferHookError::SenderBlacklisted);
+
+    // A temporary hold must expire in the future
+    let other = Pubkey::new_unique();
+    let expires_at = now(&mut context).await;
+    let result = send(&mut context, add_to_blacklist(config, mint, authority, other, blacklist_args(Some(expires_at))), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+    assert!(!account_exists(&mut context, blacklist_entry(config, other)).await);
+}
+
+#[tokio::test]
+async fn test_add_to_blacklist_already_blacklisted() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let user = Pubkey::new_unique();
+
+    send(&mut context, add_to_blacklist(config, mint, authority, user, blacklist_args(None)), &[])
+        .await
+        .unwrap();
+    let mut args = blacklist_args(None);
+    args.case_id = "CASE-002".to_string();
+    let result = send(&mut context, add_to_blacklist(config, mint, authority, user, args), &[]).await;
+    // The system program rejects creating the entry a second time
+    assert_error_code(result, 0);
+    let entry: BlacklistEntry = fetch_account(&mut context, blacklist_entry(config, user)).await;
+    assert_eq!(entry.case_id, "CASE-001");
+}
+
+#[tokio::test]
+async fn test_add_to_blacklist_by_unauthorized() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let user = Pubkey::new_unique();
+
+    let outsider = Keypair::new();
+    fund(&mut context, &outsider).await;
+    let result = send(&mut context, add_to_blacklist(config, mint, outsider.pubkey(), user, blacklist_args(None)), &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    assert!(!account_exists(&mut context, blacklist_entry(config, user)).await);
+}
+
+#[tokio::test]
+async fn test_add_to_blacklist_compliance_disabled() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let user = Pubkey::new_unique();
+
+    let result = send(&mut context, add_to_blacklist(config, mint, authority, user, blacklist_args(None)), &[]).await;
+    assert_custom_error(result, StablecoinError::ComplianceNotEnabled);
+    assert!(!account_exists(&mut context, blacklist_entry(config, user)).await);
+}
+
+#[tokio::test]
+async fn test_add_to_blacklist_reason_too_long() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let user = Pubkey::new_unique();
+
+    let mut args = blacklist_args(None);
+    args.reason = "R".repeat(101);
+    let result = send(&mut context, add_to_blacklist(config, mint, authority, user, args), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+
+    let mut args = blacklist_args(None);
+    args.case_id = "C".repeat(65);
+    let result = send(&mut context, add_to_blacklist(config, mint, authority, user, args), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+    assert!(!account_exists(&mut context, blacklist_entry(config, user)).await);
+}
+
+#[tokio::test]
+async fn test_add_to_blacklist_freezes_token_accounts() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let user = Pubkey::new_unique();
+    let main_account = funded_token_account(&mut context, config, mint, user, 1_000).await;
+    let second_account = create_auxiliary_token_account(&mut context, mint, user).await;
+
+    // An account that is already frozen is skipped
+    send(&mut context, freeze_or_thaw(config, mint, second_account, authority, true), &[])
+        .await
+        .unwrap();
+
+    let mut instruction = add_to_blacklist(config, mint, authority, user, blacklist_args(None));
+    instruction.accounts.push(AccountMeta::new(main_account, false));
+    instruction.accounts.push(AccountMeta::new(second_account, false));
+    let events: Vec<AccountFrozen> = simulate_events(&mut context, instruction.clone(), &[]).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].token_account, main_account);
+
+    send(&mut context, instruction, &[]).await.unwrap();
+    assert!(account_exists(&mut context, blacklist_entry(config, user)).await);
+    assert_eq!(fetch_token_account(&mut context, main_account).await.state, AccountState::Frozen);
+    assert_eq!(fetch_token_account(&mut context, second_account).await.state, AccountState::Frozen);
+
+    // Without token accounts only the entry is created
+    let other_user = Pubkey::new_unique();
+    let other_account = funded_token_account(&mut context, config, mint, other_user, 1_000).await;
+    send(&mut context, add_to_blacklist(config, mint, authority, other_user, blacklist_args(None)), &[])
+        .await
+        .unwrap();
+    assert!(account_exists(&mut context, blacklist_entry(config, other_user)).await);
+    assert_eq!(fetch_token_account(&mut context, other_account).await.state, AccountState::Initialized);
+}
+
+#[tokio::test]
+async fn test_add_to_blacklist_freeze_foreign_token_account() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let (other_mint, _) = initialize(&mut context, true, true, false, 0).await;
+    let other_mint = other_mint.pubkey();
+    let authority = context.payer.pubkey();
+    let user = Pubkey::new_unique();
+
+    // Owned by someone else, and holding a different mint
+    let foreign_owner = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    let foreign_mint = create_token_account(&mut context, other_mint, user).await;
+    for token_account in [foreign_owner, foreign_mint] {
+        let mut instruction = add_to_blacklist(config, mint, authority, user, blacklist_args(None));
+        instruction.accounts.push(AccountMeta::new(token_account, false));
+        let result = send(&mut context, instruction, &[]).await;
+        assert_custom_error(result, StablecoinError::InvalidAccount);
+        assert_eq!(fetch_token_account(&mut context, token_account).await.state, AccountState::Initialized);
+    }
+    assert!(!account_exists(&mut context, blacklist_entry(config, user)).await);
+}
+
+#[tokio::test]
+async fn test_batch_add_and_remove_blacklist() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let users: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+    send(&mut context, add_to_blacklist(config, mint, authority, users[0], blacklist_args(None)), &[])
+        .await
+        .unwrap();
+
+    // Users that already have an entry are skipped
+    let instruction = batch_add_to_blacklist(config, mint, authority, &users);
+    let events: Vec<BlacklistBatchUpdated> = simulate_events(&mut context, instruction.clone(), &[]).await;
+    assert!(events[0].added);
+    assert_eq!((events[0].requested, events[0].applied), (3, 2));
+    send(&mut context, instruction.clone(), &[]).await.unwrap();
+    for user in &users {
+        let entry: BlacklistEntry = fetch_account(&mut context, blacklist_entry(config, *user)).await;
+        assert_eq!(entry.user, *user);
+    }
+
+    // Replaying the batch applies nothing
+    refresh_blockhash(&mut context).await;
+    let events: Vec<BlacklistBatchUpdated> = simulate_events(&mut context, instruction.clone(), &[]).await;
+    assert_eq!((events[0].requested, events[0].applied), (3, 0));
+    send(&mut context, instruction, &[]).await.unwrap();
+
+    // Removal closes existing entries and skips missing ones
+    let removed = [users[0], users[1], Pubkey::new_unique()];
+    let instruction = batch_remove_from_blacklist(config, mint, authority, &removed);
+    let events: Vec<BlacklistBatchUpdated> = simulate_events(&mut context, instruction.clone(), &[]).await;
+    assert!(!events[0].added);
+    assert_eq!((events[0].requested, events[0].applied), (3, 2));
+    send(&mut context, instruction, &[]).await.unwrap();
+    assert!(!account_exists(&mut context, blacklist_entry(config, users[0])).await);
+    assert!(!account_exists(&mut context, blacklist_entry(config, users[1])).await);
+    assert!(account_exists(&mut context, blacklist_entry(config, users[2])).await);
+}
+
+#[tokio::test]
+async fn test_batch_add_to_blacklist_mismatched_entry() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let users: Vec<Pubkey> = (0..2).map(|_| Pubkey::new_unique()).collect();
+
+    // Entries in the wrong order
+    let mut instruction = batch_add_to_blacklist(config, mint, authority, &users);
+    let last = instruction.accounts.len() - 1;
+    instruction.accounts.swap(last - 1, last);
+    let result = send(&mut context, instruction, &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+
+    // One entry missing
+    let mut instruction = batch_add_to_blacklist(config, mint, authority, &users);
+    instruction.accounts.pop();
+    let result = send(&mut context, instruction, &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+
+    // Empty and oversized batches
+    let result = send(&mut context, batch_add_to_blacklist(config, mint, authority, &[]), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAmount);
+    let mut instruction = batch_add_to_blacklist(config, mint, authority, &[]);
+    instruction.data = sss_token::instruction::BatchAddToBlacklist {
+        users: vec![Pubkey::new_unique(); MAX_BLACKLIST_BATCH + 1],
+        reason_code: BlacklistReason::Sanctions,
+        reason: String::new(),
+        case_id: String::new(),
+        expires_at: None,
+    }
+    .data();
+    let result = send(&mut context, instruction, &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAmount);
+
+    for user in &users {
+        assert!(!account_exists(&mut context, blacklist_entry(config, *user)).await);
+    }
+}
+
+#[tokio::test]
+async fn test_remove_from_blacklist() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let blacklister = Keypair::new();
+    fund(&mut context, &blacklister).await;
+    send(&mut context, update_roles(config, mint, authority, blacklister.pubkey()), &[])
+        .await
+        .unwrap();
+
+    let user = Keypair::new();
+    let source = funded_token_account(&mut context, config, mint, user.pubkey(), 1_000).await;
+    let destination = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    send(&mut context, add_to_blacklist(config, mint, blacklister.pubkey(), user.pubkey(), blacklist_args(None)), &[&blacklister])
+        .await
+        .unwrap();
+
+    // The entry is closed and its rent returned to the blacklister
+    let entry_address = blacklist_entry(config, user.pubkey());
+    let rent = context.banks_client.get_balance(entry_address).await.unwrap();
+    let balance = context.banks_client.get_balance(blacklister.pubkey()).await.unwrap();
+    send(&mut context, remove_from_blacklist(config, mint, blacklister.pubkey(), user.pubkey()), &[&blacklister])
+        .await
+        .unwrap();
+    assert!(!account_exists(&mut context, entry_address).await);
+    assert_eq!(context.banks_client.get_balance(blacklister.pubkey()).await.unwrap(), balance + rent);
+
+    // The user can transfer again
+    transfer(&mut context, mint, source, destination, &user, 100).await.unwrap();
+    assert_eq!(fetch_token_account(&mut context, destination).await.amount, 100);
+}
+
+#[tokio::test]
+async fn test_remove_from_blacklist_by_unauthorized() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let user = Pubkey::new_unique();
+    send(&mut context, add_to_blacklist(config, mint, authority, user, blacklist_args(None)), &[])
+        .await
+        .unwrap();
+
+    let outsider = Keypair::new();
+    let result = send(&mut context, remove_from_blacklist(config, mint, outsider.pubkey(), user), &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    assert!(account_exists(&mut context, blacklist_entry(config, user)).await);
+}
+
+#[tokio::test]
+async fn test_remove_from_blacklist_not_blacklisted() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+
+    let result = send(&mut context, remove_from_blacklist(config, mint, authority, Pubkey::new_unique()), &[]).await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::AccountNotInitialized as u32);
+}
+
+#[tokio::test]
+async fn test_purge_expired_blacklist_entry() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let blacklister = Keypair::new();
+    fund(&mut context, &blacklister).await;
+    let authority = context.payer.pubkey();
+    send(&mut context, update_roles(config, mint, authority, blacklister.pubkey()), &[])
+        .await
+        .unwrap();
+
+    let user = Keypair::new();
+    let source = funded_token_account(&mut context, config, mint, user.pubkey(), 1_000).await;
+    let destination = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    let expires_at = now(&mut context).await + 3_600;
+    let args = blacklist_args(Some(expires_at));
+    send(&mut context, add_to_blacklist(config, mint, blacklister.pubkey(), user.pubkey(), args), &[&blacklister])
+        .await
+        .unwrap();
+    let result = transfer(&mut context, mint, source, destination, &user, 100).await;
+    assert_hook_error(result, TransferHookError::SenderBlacklisted);
+
+    // Once expired the entry no longer blocks transfers, even before it is purged
+    advance_clock(&mut context, 3_600).await;
+    transfer(&mut context, mint, source, destination, &user, 100).await.unwrap();
+
+    // Anyone can purge it; the rent goes back to the blacklister that added it
+    let entry_address = blacklist_entry(config, user.pubkey());
+    let rent = context.banks_client.get_balance(entry_address).await.unwrap();
+    let balance = context.banks_client.get_balance(blacklister.pubkey()).await.unwrap();
+    let caller = Keypair::new();
+    send(
+        &mut context,
+        purge_expired_blacklist_entry(config, mint, user.pubkey(), blacklister.pubkey(), caller.pubkey()),
+        &[&caller],
+    )
+    .await
+    .unwrap();
+    assert!(!account_exists(&mut context, entry_address).await);
+    assert_eq!(context.banks_client.get_balance(blacklister.pubkey()).await.unwrap(), balance + rent);
+}
+
+#[tokio::test]
+async fn test_purge_unexpired_blacklist_entry() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let caller = Keypair::new();
+
+    // Entries without an expiry can never be purged
+    let permanent = Pubkey::new_unique();
+    send(&mut context, add_to_blacklist(config, mint, authority, permanent, blacklist_args(None)), &[])
+        .await
+        .unwrap();
+    let result = send(&mut context, purge_expired_blacklist_entry(config, mint, permanent, authority, caller.pubkey()), &[&caller]).await;
+    assert_custom_error(result, StablecoinError::BlacklistEntryNotExpired);
+
+    // Temporary holds cannot be purged before they expire
+    let held = Pubkey::new_unique();
+    let expires_at = now(&mut context).await + 3_600;
+    send(&mut context, add_to_blacklist(config, mint, authority, held, blacklist_args(Some(expires_at))), &[])
+        .await
+        .unwrap();
+    let result = send(&mut context, purge_expired_blacklist_entry(config, mint, held, authority, caller.pubkey()), &[&caller]).await;
+    assert_custom_error(result, StablecoinError::BlacklistEntryNotExpired);
+
+    // The rent recipient must be the blacklister recorded on the entry
+    advance_clock(&mut context, 3_600).await;
+    let result = send(&mut context, purge_expired_blacklist_entry(config, mint, held, caller.pubkey(), caller.pubkey()), &[&caller]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+
+    // Purging is blocked while blacklist changes are paused
+    send(&mut context, pause_or_unpause(config, mint, authority, None, PAUSE_BLACKLIST, true), &[])
+        .await
+        .unwrap();
+    let caller = Keypair::new();
+    let result = send(&mut context, purge_expired_blacklist_entry(config, mint, held, authority, caller.pubkey()), &[&caller]).await;
+    assert_custom_error(result, StablecoinError::TokenPaused);
+    assert!(account_exists(&mut context, blacklist_entry(config, held)).await);
+    assert!(account_exists(&mut context, blacklist_entry(config, permanent)).await);
+}
+
+// ============================================
+// SEIZURE TESTS
+// ============================================
+
+#[tokio::test]
+async fn test_seize_tokens() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    send(&mut context, initialize_escrow(config, mint, authority), &[])
+        .await
+        .unwrap();
+    let user = Pubkey::new_unique();
+    let source = funded_token_account(&mut context, config, mint, user, 1_000).await;
+    send(&mut context, add_to_blacklist(config, mint, authority, user, blacklist_args(None)), &[])
+        .await
+        .unwrap();
+
+    // A blacklisted owner's unfrozen account can be seized; the transfer goes through the hook
+    let entry = blacklist_entry(config, user);
+    let mut instruction = seize(config, mint, source, authority, Some(entry), Some(400), "CASE-001");
+    instruction.accounts.extend(transfer_hook_accounts(mint, user, config));
+    send(&mut context, instruction, &[]).await.unwrap();
+
+    assert_eq!(fetch_token_account(&mut context, escrow(config)).await.amount, 400);
+    let source_state = fetch_token_account(&mut context, source).await;
+    assert_eq!(source_state.amount, 600);
+    assert_eq!(source_state.state, AccountState::Frozen);
+    let case: SeizureCase = fetch_account(&mut context, seizure_case(config, "CASE-001")).await;
+    assert_eq!(case.case_id, "CASE-001");
+    assert_eq!(case.source_owner, user);
+    assert_eq!(case.source_token, source);
+    assert_eq!(case.seizer, authority);
+    assert_eq!(case.amount, 400);
+    assert_eq!(case.released, 0);
+    assert_eq!(case.seized_at, now(&mut context).await);
+}
+
+#[tokio::test]
+async fn test_seize_from_frozen_account() {
+    let mut context = start().await;
+    let user = Pubkey::new_unique();
+    let (mint, config, source) = frozen_holding(&mut context, user, 1_000).await;
+    let authority = context.payer.pubkey();
+
+    // A frozen account can be seized without a blacklist entry, and is frozen again afterwards
+    send(&mut context, seize(config, mint, source, authority, None, Some(250), "CASE-001"), &[])
+        .await
+        .unwrap();
+    assert_eq!(fetch_token_account(&mut context, escrow(config)).await.amount, 250);
+    let source_state = fetch_token_account(&mut context, source).await;
+    assert_eq!(source_state.amount, 750);
+    assert_eq!(source_state.state, AccountState::Frozen);
+}
+
+#[tokio::test]
+async fn test_seize_by_unauthorized() {
+    let mut context = start().await;
+    let (mint, config, source) = frozen_holding(&mut context, Pubkey::new_unique(), 1_000).await;
+
+    let outsider = Keypair::new();
+    fund(&mut context, &outsider).await;
+    let instruction = seize(config, mint, source, outsider.pubkey(), None, Some(100), "CASE-001");
+    let result = send(&mut context, instruction, &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    assert_eq!(fetch_token_account(&mut context, source).await.amount, 1_000);
+    assert_eq!(fetch_token_account(&mut context, escrow(config)).await.amount, 0);
+}
+
+#[tokio::test]
+async fn test_seize_more_than_balance() {
+    let mut context = start().await;
+    let (mint, config, source) = frozen_holding(&mut context, Pubkey::new_unique(), 100).await;
+    let authority = context.payer.pubkey();
+
+    let result = send(&mut context, seize(config, mint, source, authority, None, Some(200), "CASE-001"), &[]).await;
+    assert_error_code(result, TokenError::InsufficientFunds as u32);
+    assert_eq!(fetch_token_account(&mut context, source).await.amount, 100);
+    assert!(!account_exists(&mut context, seizure_case(config, "CASE-001")).await);
+}
+
+#[tokio::test]
+async fn test_seize_permanent_delegate_disabled() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    send(&mut context, initialize_escrow(config, mint, authority), &[])
+        .await
+        .unwrap();
+    let source = funded_token_account(&mut context, config, mint, Pubkey::new_unique(), 1_000).await;
+    send(&mut context, freeze_or_thaw(config, mint, source, authority, true), &[])
+        .await
+        .unwrap();
+
+    let result = send(&mut context, seize(config, mint, source, authority, None, Some(100), "CASE-001"), &[]).await;
+    assert_custom_error(result, StablecoinError::PermanentDelegateNotEnabled);
+    assert_eq!(fetch_token_account(&mut context, source).await.amount, 1_000);
+}
+
+#[tokio::test]
+async fn test_seize_zero_amount() {
+    let mut context = start().await;
+    let (mint, config, source) = frozen_holding(&mut context, Pubkey::new_unique(), 1_000).await;
+    let authority = context.payer.pubkey();
+
+    let result = send(&mut context, seize(config, mint, source, authority, None, Some(0), "CASE-001"), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAmount);
+
+    // Seizing the entire balance of an empty account
+    let empty = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    send(&mut context, freeze_or_thaw(config, mint, empty, authority, true), &[])
+        .await
+        .unwrap();
+    let result = send(&mut context, seize(config, mint, empty, authority, None, None, "CASE-001"), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAmount);
+}
+
+#[tokio::test]
+async fn test_seize_from_unflagged_holder() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    send(&mut context, initialize_escrow(config, mint, authority), &[])
+        .await
+        .unwrap();
+    let user = Pubkey::new_unique();
+    let source = funded_token_account(&mut context, config, mint, user, 1_000).await;
+
+    // Neither frozen nor blacklisted
+    let mut instruction = seize(config, mint, source, authority, None, Some(100), "CASE-001");
+    instruction.accounts.extend(transfer_hook_accounts(mint, user, config));
+    let result = send(&mut context, instruction, &[]).await;
+    assert_custom_error(result, StablecoinError::SeizureNotPermitted);
+
+    // Blacklisted, but the entry has expired
+    let expires_at = now(&mut context).await + 3_600;
+    send(&mut context, add_to_blacklist(config, mint, authority, user, blacklist_args(Some(expires_at))), &[])
+        .await
+        .unwrap();
+    advance_clock(&mut context, 3_600).await;
+    let entry = blacklist_entry(config, user);
+    let mut instruction = seize(config, mint, source, authority, Some(entry), Some(100), "CASE-001");
+    instruction.accounts.extend(transfer_hook_accounts(mint, user, config));
+    let result = send(&mut context, instruction, &[]).await;
+    assert_custom_error(result, StablecoinError::SeizureNotPermitted);
+    assert_eq!(fetch_token_account(&mut context, source).await.amount, 1_000);
+}
+
+#[tokio::test]
+async fn test_seize_wrong_mint_token_account() {
+    let mut context = start().await;
+    let (mint, config, _) = frozen_holding(&mut context, Pubkey::new_unique(), 1_000).await;
+    let (_, _, other_source) = frozen_holding(&mut context, Pubkey::new_unique(), 1_000).await;
+    let authority = context.payer.pubkey();
+
+    let instruction = seize(config, mint, other_source, authority, None, Some(100), "CASE-001");
+    let result = send(&mut context, instruction, &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+    assert_eq!(fetch_token_account(&mut context, other_source).await.amount, 1_000);
+}
+
+#[tokio::test]
+async fn test_set_treasury() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    assert_eq!(fetch_config(&mut context, config).await.treasury, Pubkey::default());
+
+    let treasury = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    let outsider = Keypair::new();
+    fund(&mut context, &outsider).await;
+    let result = send(&mut context, set_treasury(config, mint, treasury, outsider.pubkey()), &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+
+    // The treasury must hold the config mint
+    let (other_mint, _) = initialize(&mut context, true, false, false, 0).await;
+    let foreign = create_token_account(&mut context, other_mint.pubkey(), Pubkey::new_unique()).await;
+    let result = send(&mut context, set_treasury(config, mint, foreign, authority), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+    assert_eq!(fetch_config(&mut context, config).await.treasury, Pubkey::default());
+
+    let instruction = set_treasury(config, mint, treasury, authority);
+    let events: Vec<TreasuryUpdated> = simulate_events(&mut context, instruction.clone(), &[]).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].mint, mint);
+    assert_eq!(events[0].authority, authority);
+    assert_eq!(events[0].old_treasury, Pubkey::default());
+    assert_eq!(events[0].new_treasury, treasury);
+    send(&mut context, instruction, &[]).await.unwrap();
+    assert_eq!(fetch_config(&mut context, config).await.treasury, treasury);
+
+    let replacement = create_auxiliary_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    let instruction = set_treasury(config, mint, replacement, authority);
+    let events: Vec<TreasuryUpdated> = simulate_events(&mut context, instruction.clone(), &[]).await;
+    assert_eq!(events[0].old_treasury, treasury);
+    assert_eq!(events[0].new_treasury, replacement);
+    send(&mut context, instruction, &[]).await.unwrap();
+    assert_eq!(fetch_config(&mut context, config).await.treasury, replacement);
+}
+
+#[tokio::test]
+async fn test_seize_duplicate_case_id() {
+    let mut context = start().await;
+    let (mint, config, source) = frozen_holding(&mut context, Pubkey::new_unique(), 1_000).await;
+    let authority = context.payer.pubkey();
+
+    send(&mut context, seize(config, mint, source, authority, None, Some(100), "CASE-001"), &[])
+        .await
+        .unwrap();
+    // The SeizureCase PDA for the id already exists
+    let result = send(&mut context, seize(config, mint, source, authority, None, Some(200), "CASE-001"), &[]).await;
+    assert_error_code(result, 0);
+
+    let result = send(&mut context, seize(config, mint, source, authority, None, Some(100), ""), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+    // An id over MAX_CASE_ID_LEN is not a valid PDA seed, so the SeizureCase address cannot be derived
+    let mut instruction = seize(config, mint, source, authority, None, Some(100), "CASE-002");
+    instruction.data = sss_token::instruction::Seize {
+        amount: Some(100),
+        case_id: "C".repeat(MAX_CASE_ID_LEN + 1),
+    }
+    .data();
+    let result = send(&mut context, instruction, &[]).await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::ProgramFailedToComplete)
+    );
+
+    let case_id = "C".repeat(MAX_CASE_ID_LEN);
+    send(&mut context, seize(config, mint, source, authority, None, Some(100), &case_id), &[])
+        .await
+        .unwrap();
+    assert_eq!(fetch_token_account(&mut context, source).await.amount, 800);
+    assert_eq!(fetch_token_account(&mut context, escrow(config)).await.amount, 200);
+}
+
+#[tokio::test]
+async fn test_release_seized_funds() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    send(&mut context, initialize_escrow(config, mint, authority), &[])
+        .await
+        .unwrap();
+    let user = Pubkey::new_unique();
+    let source = funded_token_account(&mut context, config, mint, user, 1_000).await;
+    send(&mut context, add_to_blacklist(config, mint, authority, user, blacklist_args(None)), &[])
+        .await
+        .unwrap();
+    let entry = blacklist_entry(config, user);
+    let mut instruction = seize(config, mint, source, authority, Some(entry), Some(600), "CASE-001");
+    instruction.accounts.extend(transfer_hook_accounts(mint, user, config));
+    send(&mut context, instruction, &[]).await.unwrap();
+
+    let treasury_owner = Pubkey::new_unique();
+    let treasury = create_token_account(&mut context, mint, treasury_owner).await;
+    send(&mut context, set_treasury(config, mint, treasury, authority), &[])
+        .await
+        .unwrap();
+
+    // The master authority pays the treasury; the hook accounts are forwarded to Token-2022
+    let mut instruction =
+        release_seized_funds(config, mint, treasury, authority, None, "CASE-001", 200, ReleaseTarget::Treasury);
+    instruction.accounts.extend(transfer_hook_accounts(mint, config, treasury_owner));
+    let events: Vec<SeizedFundsReleased> = simulate_events(&mut context, instruction.clone(), &[]).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].authority, authority);
+    assert_eq!(events[0].case_id, "CASE-001");
+    assert_eq!(events[0].destination, treasury);
+    assert_eq!(events[0].target, ReleaseTarget::Treasury);
+    assert_eq!(events[0].amount, 200);
+    assert_eq!(events[0].remaining, 400);
+    send(&mut context, instruction, &[]).await.unwrap();
+    assert_eq!(fetch_token_account(&mut context, treasury).await.amount, 200);
+    assert_eq!(fetch_token_account(&mut context, escrow(config)).await.amount, 400);
+    let case: SeizureCase = fetch_account(&mut context, seizure_case(config, "CASE-001")).await;
+    assert_eq!(case.released, 200);
+
+    // A Legal role holder returns the rest to a claimant, in two partial releases
+    let legal = Keypair::new();
+    fund(&mut context, &legal).await;
+    send(&mut context, grant_role(config, mint, legal.pubkey(), authority, RoleKind::Legal), &[])
+        .await
+        .unwrap();
+    let legal_role = Some(role_assignment(config, RoleKind::Legal, legal.pubkey()));
+    let claimant_owner = Pubkey::new_unique();
+    let claimant = create_token_account(&mut context, mint, claimant_owner).await;
+    for amount in [150, 250] {
+        let mut instruction = release_seized_funds(
+            config,
+            mint,
+            claimant,
+            legal.pubkey(),
+            legal_role,
+            "CASE-001",
+            amount,
+            ReleaseTarget::Claimant,
+        );
+        instruction.accounts.extend(transfer_hook_accounts(mint, config, claimant_owner));
+        send(&mut context, instruction, &[&legal]).await.unwrap();
+    }
+    assert_eq!(fetch_token_account(&mut context, claimant).await.amount, 400);
+    assert_eq!(fetch_token_account(&mut context, escrow(config)).await.amount, 0);
+    let case: SeizureCase = fetch_account(&mut context, seizure_case(config, "CASE-001")).await;
+    assert_eq!(case.amount, 600);
+    assert_eq!(case.released, 600);
+}
+
+#[tokio::test]
+async fn test_release_more_than_escrowed() {
+    let mut context = start().await;
+    let (mint, config, source) = frozen_holding(&mut context, Pubkey::new_unique(), 1_000).await;
+    let authority = context.payer.pubkey();
+    send(&mut context, seize(config, mint, source, authority, None, Some(300), "CASE-001"), &[])
+        .await
+        .unwrap();
+    let claimant = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    let release = |authority: Pubkey, amount: u64| {
+        release_seized_funds(config, mint, claimant, authority, None, "CASE-001", amount, ReleaseTarget::Claimant)
+    };
+
+    let result = send(&mut context, release(authority, 301), &[]).await;
+    assert_custom_error(result, StablecoinError::ReleaseExceedsEscrow);
+    let result = send(&mut context, release(authority, 0), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAmount);
+
+    // Neither master authority nor Legal role holder
+    let outsider = Keypair::new();
+    fund(&mut context, &outsider).await;
+    let result = send(&mut context, release(outsider.pubkey(), 100), &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+
+    // The limit is what remains after earlier releases
+    send(&mut context, release(authority, 200), &[]).await.unwrap();
+    let result = send(&mut context, release(authority, 101), &[]).await;
+    assert_custom_error(result, StablecoinError::ReleaseExceedsEscrow);
+    send(&mut context, release(authority, 100), &[]).await.unwrap();
+    assert_eq!(fetch_token_account(&mut context, claimant).await.amount, 300);
+    assert_eq!(fetch_token_account(&mut context, escrow(config)).await.amount, 0);
+}
+
+#[tokio::test]
+async fn test_release_to_non_treasury_account() {
+    let mut context = start().await;
+    let (mint, config, source) = frozen_holding(&mut context, Pubkey::new_unique(), 1_000).await;
+    let authority = context.payer.pubkey();
+    send(&mut context, seize(config, mint, source, authority, None, Some(300), "CASE-001"), &[])
+        .await
+        .unwrap();
+    let treasury = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    let other = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    let release = |destination: Pubkey, target: ReleaseTarget| {
+        release_seized_funds(config, mint, destination, authority, None, "CASE-001", 100, target)
+    };
+
+    // No treasury is configured yet
+    let result = send(&mut context, release(treasury, ReleaseTarget::Treasury), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidTreasury);
+
+    send(&mut context, set_treasury(config, mint, treasury, authority), &[])
+        .await
+        .unwrap();
+    let result = send(&mut context, release(other, ReleaseTarget::Treasury), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidTreasury);
+
+    // The destination must be a token account of the config mint other than the escrow
+    let (other_mint, _) = initialize(&mut context, true, false, false, 0).await;
+    let foreign = create_token_account(&mut context, other_mint.pubkey(), Pubkey::new_unique()).await;
+    let result = send(&mut context, release(foreign, ReleaseTarget::Claimant), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+    let result = send(&mut context, release(escrow(config), ReleaseTarget::Claimant), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+
+    // The seizer cannot redirect a seizure away from the escrow
+    let mut instruction = seize(config, mint, source, authority, None, Some(100), "CASE-002");
+    instruction.accounts[3].pubkey = other;
+    let result = send(&mut context, instruction, &[]).await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::ConstraintSeeds as u32);
+
+    send(&mut context, release(treasury, ReleaseTarget::Treasury), &[])
+        .await
+        .unwrap();
+    assert_eq!(fetch_token_account(&mut context, treasury).await.amount, 100);
+    assert_eq!(fetch_token_account(&mut context, other).await.amount, 0);
+}
+
+#[tokio::test]
+async fn test_seize_entire_balance() {
+    let mut context = start().await;
+    let (mint, config, source) = frozen_holding(&mut context, Pubkey::new_unique(), 1_000).await;
+    let authority = context.payer.pubkey();
+
+    send(&mut context, seize(config, mint, source, authority, None, None, "CASE-001"), &[])
+        .await
+        .unwrap();
+    assert_eq!(fetch_token_account(&mut context, escrow(config)).await.amount, 1_000);
+    let source_state = fetch_token_account(&mut context, source).await;
+    assert_eq!(source_state.amount, 0);
+    assert_eq!(source_state.state, AccountState::Frozen);
+    let case: SeizureCase = fetch_account(&mut context, seizure_case(config, "CASE-001")).await;
+    assert_eq!(case.amount, 1_000);
+}
+
+#[tokio::test]
+async fn test_seize_many() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, true, true, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    send(&mut context, initialize_escrow(config, mint, authority), &[])
+        .await
+        .unwrap();
+    let owner = Pubkey::new_unique();
+    let main_account = funded_token_account(&mut context, config, mint, owner, 500).await;
+    let second_account = create_auxiliary_token_account(&mut context, mint, owner).await;
+    send(&mut context, mint_tokens(config, mint, authority, second_account, 300), &[])
+        .await
+        .unwrap();
+    let empty_account = create_auxiliary_token_account(&mut context, mint, owner).await;
+    send(&mut context, add_to_blacklist(config, mint, authority, owner, blacklist_args(None)), &[])
+        .await
+        .unwrap();
+
+    // Sources first, then the hook accounts forwarded to Token-2022; the empty account is skipped
+    let sources = [main_account, second_account, empty_account];
+    let entry = Some(blacklist_entry(config, owner));
+    let mut instruction = seize_many(config, mint, owner, authority, entry, &sources, "CASE-001");
+    instruction.accounts.extend(transfer_hook_accounts(mint, owner, config));
+    let events: Vec<Seized> = simulate_events(&mut context, instruction.clone(), &[]).await;
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].source_token, main_account);
+    assert_eq!(events[0].amount, 500);
+    assert_eq!(events[1].source_token, second_account);
+    assert_eq!(events[1].amount, 300);
+    assert!(events.iter().all(|event| event.dest_token == escrow(config) && event.case_id == "CASE-001"));
+    send(&mut context, instruction, &[]).await.unwrap();
+
+    assert_eq!(fetch_token_account(&mut context, escrow(config)).await.amount, 800);
+    for source in [main_account, second_account] {
+        let source_state = fetch_token_account(&mut context, source).await;
+        assert_eq!(source_state.amount, 0);
+        assert_eq!(source_state.state, AccountState::Frozen);
+    }
+    assert_eq!(fetch_token_account(&mut context, empty_account).await.state, AccountState::Initialized);
+    let case: SeizureCase = fetch_account(&mut context, seizure_case(config, "CASE-001")).await;
+    assert_eq!(case.source_owner, owner);
+    assert_eq!(case.source_token, Pubkey::default());
+    assert_eq!(case.amount, 800);
+}
+
+#[tokio::test]
+async fn test_seize_many_foreign_owner_account() {
+    let mut context = start().await;
+    let owner = Pubkey::new_unique();
+    let (mint, config, owned) = frozen_holding(&mut context, owner, 1_000).await;
+    let authority = context.payer.pubkey();
+    let innocent = funded_token_account(&mut context, config, mint, Pubkey::new_unique(), 1_000).await;
+    send(&mut context, freeze_or_thaw(config, mint, innocent, authority, true), &[])
+        .await
+        .unwrap();
+
+    // Another holder's account aborts the whole sweep
+    let instruction = seize_many(config, mint, owner, authority, None, &[owned, innocent], "CASE-001");
+    let result = send(&mut context, instruction, &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+
+    // Without a blacklist entry every source must be frozen
+    let unfrozen = create_auxiliary_token_account(&mut context, mint, owner).await;
+    send(&mut context, mint_tokens(config, mint, authority, unfrozen, 500), &[])
+        .await
+        .unwrap();
+    let instruction = seize_many(config, mint, owner, authority, None, &[owned, unfrozen], "CASE-001");
+    let result = send(&mut context, instruction, &[]).await;
+    assert_custom_error(result, StablecoinError::SeizureNotPermitted);
+
+    let instruction = seize_many(config, mint, owner, authority, None, &[], "CASE-001");
+    let result = send(&mut context, instruction, &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAmount);
+
+    assert!(!account_exists(&mut context, seizure_case(config, "CASE-001")).await);
+    assert_eq!(fetch_token_account(&mut context, owned).await.amount, 1_000);
+    assert_eq!(fetch_token_account(&mut context, innocent).await.amount, 1_000);
+    assert_eq!(fetch_token_account(&mut context, unfrozen).await.amount, 500);
+    assert_eq!(fetch_token_account(&mut context, escrow(config)).await.amount, 0);
+}
+
+// ============================================
+// MIGRATION TESTS
+// ============================================
+
+#[tokio::test]
+async fn test_migrate_config() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, true, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let minter = Pubkey::new_unique();
+    send(&mut context, add_minter(config, mint, minter, authority, 1_000), &[])
+        .await
+        .unwrap();
+    let user = Pubkey::new_unique();
+    send(&mut context, add_to_blacklist(config, mint, authority, user, blacklist_args(None)), &[])
+        .await
+        .unwrap();
+    let legal = Pubkey::new_unique();
+    send(&mut context, grant_role(config, mint, legal, authority, RoleKind::Legal), &[])
+        .await
+        .unwrap();
+
+    // Rewrite the config, minter and blacklist entry in their pre-versioning layouts
+    let current = fetch_config(&mut context, config).await;
+    let legacy_config = StablecoinConfigV0 {
+        master_authority: current.master_authority,
+        mint,
+        name: current.name.clone(),
+        symbol: current.symbol.clone(),
+        uri: current.uri.clone(),
+        decimals: current.decimals,
+        paused: true,
+        bump: current.bump,
+        enable_permanent_delegate: current.enable_permanent_delegate,
+        enable_transfer_hook: current.enable_transfer_hook,
+        default_account_frozen: current.default_account_frozen,
+        blacklister: current.blacklister,
+        pauser: current.pauser,
+        seizer: current.seizer,
+    };
+    let data = account_data::<StablecoinConfig>(&legacy_config, StablecoinConfigV0::LEN);
+    write_account_data(&mut context, config, data).await;
+
+    let minter_info = pda(&[b"minter", config.as_ref(), minter.as_ref()]);
+    let current: MinterInfo = fetch_account(&mut context, minter_info).await;
+    let legacy_minter = MinterInfoV0 {
+        authority: minter,
+        quota: 1_000,
+        minted: 250,
+        bump: current.bump,
+    };
+    write_account_data(&mut context, minter_info, account_data::<MinterInfo>(&legacy_minter, MinterInfoV0::LEN)).await;
+
+    let entry = blacklist_entry(config, user);
+    let current: BlacklistEntry = fetch_account(&mut context, entry).await;
+    let legacy_entry = BlacklistEntryV0 {
+        user,
+        reason: "OFAC SDN match".to_string(),
+        timestamp: current.timestamp,
+        bump: current.bump,
+    };
+    write_account_data(&mut context, entry, account_data::<BlacklistEntry>(&legacy_entry, BlacklistEntryV0::LEN)).await;
+
+    // The RoleAssignment is already current and is not counted
+    let role = role_assignment(config, RoleKind::Legal, legal);
+    let instruction = migrate_config(config, mint, authority, &[minter_info, entry, role]);
+    let events: Vec<ConfigMigrated> = simulate_events(&mut context, instruction.clone(), &[]).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].authority, authority);
+    assert_eq!(events[0].from_version, 0);
+    assert_eq!(events[0].to_version, ACCOUNT_VERSION);
+    assert_eq!(events[0].accounts_migrated, 3);
+    send(&mut context, instruction, &[]).await.unwrap();
+
+    // New fields take their defaults; the old pause switch maps to the mint and burn bits
+    let migrated = fetch_config(&mut context, config).await;
+    assert_eq!(migrated.version, ACCOUNT_VERSION);
+    assert_eq!(migrated.master_authority, authority);
+    assert_eq!(migrated.name, legacy_config.name);
+    assert_eq!(migrated.pause_mask, PAUSE_MINT | PAUSE_BURN);
+    assert_eq!(migrated.max_supply, 0);
+    assert_eq!(migrated.treasury, Pubkey::default());
+    assert_eq!(migrated.pending_master_authority, None);
+    let account = context.banks_client.get_account(config).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), StablecoinConfig::LEN);
+    assert_rent_exempt(&mut context, config).await;
+
+    let migrated: MinterInfo = fetch_account(&mut context, minter_info).await;
+    assert_eq!(migrated.version, ACCOUNT_VERSION);
+    assert_eq!(migrated.quota, 1_000);
+    assert_eq!(migrated.minted, 250);
+    assert_eq!(migrated.period_limit, 0);
+    assert_rent_exempt(&mut context, minter_info).await;
+
+    let migrated: BlacklistEntry = fetch_account(&mut context, entry).await;
+    assert_eq!(migrated.version, ACCOUNT_VERSION);
+    assert_eq!(migrated.user, user);
+    assert_eq!(migrated.reason, "OFAC SDN match");
+    assert_eq!(migrated.reason_code, BlacklistReason::Other);
+    assert_eq!(migrated.added_by, Pubkey::default());
+    assert_eq!(migrated.expires_at, None);
+    assert_rent_exempt(&mut context, entry).await;
+
+    // Running it again finds nothing to upgrade
+    let instruction = migrate_config(config, mint, authority, &[minter_info, entry, role]);
+    let events: Vec<ConfigMigrated> = simulate_events(&mut context, instruction, &[]).await;
+    assert_eq!(events[0].from_version, ACCOUNT_VERSION);
+    assert_eq!(events[0].accounts_migrated, 0);
+}
+
+#[tokio::test]
+async fn test_migrate_config_by_unauthorized() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+
+    let outsider = Keypair::new();
+    fund(&mut context, &outsider).await;
+    let result = send(&mut context, migrate_config(config, mint, outsider.pubkey(), &[]), &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+
+    // A MinterInfo of another config
+    let (other_mint, other_config) = initialize(&mut context, false, false, false, 0).await;
+    let minter = Pubkey::new_unique();
+    send(&mut context, add_minter(other_config, other_mint.pubkey(), minter, authority, 1_000), &[])
+        .await
+        .unwrap();
+    let foreign_minter = pda(&[b"minter", other_config.as_ref(), minter.as_ref()]);
+    let result = send(&mut context, migrate_config(config, mint, authority, &[foreign_minter]), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+
+    // An account that is not one of the program's account types
+    let token_account = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    let result = send(&mut context, migrate_config(config, mint, authority, &[token_account]), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+}
+
+#[tokio::test]
+async fn test_mint_with_outdated_config() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let token_account = funded_token_account(&mut context, config, mint, Pubkey::new_unique(), 100).await;
+
+    // The current layout with an older version number
+    let mut outdated = fetch_config(&mut context, config).await;
+    outdated.version = 0;
+    write_account_data(&mut context, config, account_data::<StablecoinConfig>(&outdated, StablecoinConfig::LEN)).await;
+
+    let result = send(&mut context, mint_tokens(config, mint, authority, token_account, 100), &[]).await;
+    assert_custom_error(result, StablecoinError::OutdatedAccountVersion);
+    let result = send(&mut context, freeze_or_thaw(config, mint, token_account, authority, true), &[]).await;
+    assert_custom_error(result, StablecoinError::OutdatedAccountVersion);
+
+    send(&mut context, migrate_config(config, mint, authority, &[]), &[])
+        .await
+        .unwrap();
+    assert_eq!(fetch_config(&mut context, config).await.version, ACCOUNT_VERSION);
+    send(&mut context, mint_tokens(config, mint, authority, token_account, 100), &[])
+        .await
+        .unwrap();
+    assert_eq!(fetch_token_account(&mut context, token_account).await.amount, 200);
+}
+
+// ============================================
+// ADMIN TIMELOCK TESTS
+// ============================================
+
+#[tokio::test]
+async fn test_queue_and_execute_admin_action() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+
+    // A separate master authority, so its balance only reflects rent
+    let admin = Keypair::new();
+    fund(&mut context, &admin).await;
+    propose_master_authority(&mut context, config, mint, admin.pubkey()).await.unwrap();
+    send(&mut context, accept_master_authority(config, mint, admin.pubkey()), &[&admin])
+        .await
+        .unwrap();
+
+    // Without a delay the guardian and the delay itself are set in one go
+    let guardian = Pubkey::new_unique();
+    for (id, action) in [(0, AdminAction::SetGuardian { guardian }), (1, AdminAction::SetAdminDelay { delay: 3_600 })] {
+        send(&mut context, queue_admin_action(config, mint, admin.pubkey(), id, action), &[&admin])
+            .await
+            .unwrap();
+        send(&mut context, execute_admin_action(config, mint, admin.pubkey(), id, admin.pubkey(), None), &[&admin])
+            .await
+            .unwrap();
+        assert!(!account_exists(&mut context, pending_admin_action(config, id)).await);
+    }
+    let state = fetch_config(&mut context, config).await;
+    assert_eq!(state.guardian, guardian);
+    assert_eq!(state.admin_delay, 3_600);
+    assert_eq!(state.next_admin_action_id, 2);
+
+    let minter = Pubkey::new_unique();
+    let action = AdminAction::AddMinter { minter, quota: 1_000 };
+    let instruction = queue_admin_action(config, mint, admin.pubkey(), 2, action);
+    let events: Vec<AdminActionQueued> = simulate_events(&mut context, instruction.clone(), &[&admin]).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].action_id, 2);
+    assert_eq!(events[0].action, action);
+    send(&mut context, instruction, &[&admin]).await.unwrap();
+
+    let queued_at = now(&mut context).await;
+    let pending: PendingAdminAction = fetch_account(&mut context, pending_admin_action(config, 2)).await;
+    assert_eq!(pending.id, 2);
+    assert_eq!(pending.action, action);
+    assert_eq!(pending.proposer, admin.pubkey());
+    assert_eq!(pending.queued_at, queued_at);
+    assert_eq!(pending.eta, queued_at + 3_600);
+    assert_eq!(fetch_config(&mut context, config).await.next_admin_action_id, 3);
+
+    let minter_info = pda(&[b"minter", config.as_ref(), minter.as_ref()]);
+    let execute = execute_admin_action(config, mint, admin.pubkey(), 2, admin.pubkey(), Some(minter_info));
+    let result = send(&mut context, execute.clone(), &[&admin]).await;
+    assert_custom_error(result, StablecoinError::TimelockNotElapsed);
+
+    refresh_blockhash(&mut context).await;
+    set_clock(&mut context, pending.eta).await;
+    let events: Vec<AdminActionExecuted> = simulate_events(&mut context, execute.clone(), &[&admin]).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].action_id, 2);
+    assert_eq!(events[0].action, action);
+
+    // The action's rent goes back to the proposer, who pays for the MinterInfo
+    let admin_before = context.banks_client.get_balance(admin.pubkey()).await.unwrap();
+    let action_rent = context.banks_client.get_balance(pending_admin_action(config, 2)).await.unwrap();
+    send(&mut context, execute, &[&admin]).await.unwrap();
+    let info: MinterInfo = fetch_account(&mut context, minter_info).await;
+    assert_eq!(info.authority, minter);
+    assert_eq!(info.quota, 1_000);
+    assert!(!account_exists(&mut context, pending_admin_action(config, 2)).await);
+    let minter_rent = context.banks_client.get_balance(minter_info).await.unwrap();
+    let admin_after = context.banks_client.get_balance(admin.pubkey()).await.unwrap();
+    assert_eq!(admin_after, admin_before + action_rent - minter_rent);
+}
+
+#[tokio::test]
+async fn test_execute_admin_action_before_delay() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+
+    let result = send(
+        &mut context,
+        queue_admin_action(config, mint, authority, 0, AdminAction::SetAdminDelay { delay: MAX_ADMIN_DELAY + 1 }),
+        &[],
+    )
+    .await;
+    assert_custom_error(result, StablecoinError::InvalidAmount);
+    let result = send(
+        &mut context,
+        queue_admin_action(config, mint, authority, 0, AdminAction::SetAdminDelay { delay: -1 }),
+        &[],
+    )
+    .await;
+    assert_custom_error(result, StablecoinError::InvalidAmount);
+
+    apply_admin_action(&mut context, config, mint, AdminAction::SetAdminDelay { delay: MAX_ADMIN_DELAY }).await;
+    let guardian = Pubkey::new_unique();
+    send(&mut context, queue_admin_action(config, mint, authority, 1, AdminAction::SetGuardian { guardian }), &[])
+        .await
+        .unwrap();
+    let pending: PendingAdminAction = fetch_account(&mut context, pending_admin_action(config, 1)).await;
+    assert_eq!(pending.eta, now(&mut context).await + MAX_ADMIN_DELAY);
+
+    // One second short of the eta
+    set_clock(&mut context, pending.eta - 1).await;
+    let execute = execute_admin_action(config, mint, authority, 1, authority, None);
+    let result = send(&mut context, execute.clone(), &[]).await;
+    assert_custom_error(result, StablecoinError::TimelockNotElapsed);
+    assert_eq!(fetch_config(&mut context, config).await.guardian, Pubkey::default());
+
+    refresh_blockhash(&mut context).await;
+    set_clock(&mut context, pending.eta).await;
+    send(&mut context, execute, &[]).await.unwrap();
+    assert_eq!(fetch_config(&mut context, config).await.guardian, guardian);
+}
+
+#[tokio::test]
+async fn test_cancel_admin_action_by_guardian() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let guardian = Keypair::new();
+    fund(&mut context, &guardian).await;
+    apply_admin_action(&mut context, config, mint, AdminAction::SetGuardian { guardian: guardian.pubkey() }).await;
+    apply_admin_action(&mut context, config, mint, AdminAction::SetAdminDelay { delay: 3_600 }).await;
+
+    let minter = Pubkey::new_unique();
+    let action = AdminAction::AddMinter { minter, quota: 1_000 };
+    send(&mut context, queue_admin_action(config, mint, authority, 2, action), &[])
+        .await
+        .unwrap();
+    let cancel = cancel_admin_action(config, mint, guardian.pubkey(), 2, authority);
+    let events: Vec<AdminActionCancelled> = simulate_events(&mut context, cancel.clone(), &[&guardian]).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].cancelled_by, guardian.pubkey());
+    assert_eq!(events[0].action_id, 2);
+    assert_eq!(events[0].action, action);
+    send(&mut context, cancel, &[&guardian]).await.unwrap();
+    assert!(!account_exists(&mut context, pending_admin_action(config, 2)).await);
+
+    // A cancelled action can no longer be executed
+    let result = send(&mut context, execute_admin_action(config, mint, authority, 2, authority, None), &[]).await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::AccountNotInitialized as u32);
+
+    // The master authority can cancel too
+    send(&mut context, queue_admin_action(config, mint, authority, 3, action), &[])
+        .await
+        .unwrap();
+    send(&mut context, cancel_admin_action(config, mint, authority, 3, authority), &[])
+        .await
+        .unwrap();
+    assert!(!account_exists(&mut context, pending_admin_action(config, 3)).await);
+    assert!(!account_exists(&mut context, pda(&[b"minter", config.as_ref(), minter.as_ref()])).await);
+}
+
+#[tokio::test]
+async fn test_cancel_admin_action_by_unauthorized() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let attacker = Keypair::new();
+    fund(&mut context, &attacker).await;
+
+    // No guardian configured: only the master authority can cancel
+    let action = AdminAction::SetGuardian { guardian: attacker.pubkey() };
+    send(&mut context, queue_admin_action(config, mint, authority, 0, action), &[])
+        .await
+        .unwrap();
+    let cancel = cancel_admin_action(config, mint, attacker.pubkey(), 0, authority);
+    let result = send(&mut context, cancel, &[&attacker]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+
+    // The rent must go back to the recorded proposer
+    let result = send(&mut context, cancel_admin_action(config, mint, authority, 0, attacker.pubkey()), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+
+    let guardian = Keypair::new();
+    apply_admin_action(&mut context, config, mint, AdminAction::SetGuardian { guardian: guardian.pubkey() }).await;
+    let cancel = cancel_admin_action(config, mint, attacker.pubkey(), 0, authority);
+    let result = send(&mut context, cancel, &[&attacker]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    assert!(account_exists(&mut context, pending_admin_action(config, 0)).await);
+
+    send(&mut context, cancel_admin_action(config, mint, authority, 0, authority), &[])
+        .await
+        .unwrap();
+    assert!(!account_exists(&mut context, pending_admin_action(config, 0)).await);
+}
+
+#[tokio::test]
+async fn test_add_minter_directly_when_timelocked() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, true, false, 1_000_000).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let minter = Pubkey::new_unique();
+    send(&mut context, add_minter(config, mint, minter, authority, 1_000), &[])
+        .await
+        .unwrap();
+    send(&mut context, update_minter_allowance(config, mint, minter, authority, 3_600, 500), &[])
+        .await
+        .unwrap();
+    let token_account = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    apply_admin_action(&mut context, config, mint, AdminAction::SetAdminDelay { delay: 3_600 }).await;
+
+    let holder = Pubkey::new_unique();
+    for instruction in [
+        add_minter(config, mint, holder, authority, 1_000),
+        add_burner(config, mint, holder, authority, None),
+        update_roles(config, mint, authority, holder),
+        transfer_authority(config, mint, authority, holder),
+        grant_role(config, mint, holder, authority, RoleKind::Legal),
+        set_treasury(config, mint, token_account, authority),
+        // Loosening a limit
+        update_minter_quota(config, mint, minter, authority, 2_000),
+        update_minter_allowance(config, mint, minter, authority, 3_600, 600),
+        update_max_supply(config, mint, authority, 2_000_000),
+        update_max_supply(config, mint, authority, 0),
+    ] {
+        let result = send(&mut context, instruction, &[]).await;
+        assert_custom_error(result, StablecoinError::TimelockRequired);
+    }
+    let result = propose_master_authority(&mut context, config, mint, holder).await;
+    assert_custom_error(result, StablecoinError::TimelockRequired);
+
+    // Tightening a limit still applies immediately
+    send(&mut context, update_minter_quota(config, mint, minter, authority, 500), &[])
+        .await
+        .unwrap();
+    send(&mut context, update_minter_allowance(config, mint, minter, authority, 3_600, 400), &[])
+        .await
+        .unwrap();
+    send(&mut context, update_max_supply(config, mint, authority, 500_000), &[])
+        .await
+        .unwrap();
+    let info: MinterInfo = fetch_account(&mut context, pda(&[b"minter", config.as_ref(), minter.as_ref()])).await;
+    assert_eq!(info.quota, 500);
+    assert_eq!(info.period_limit, 400);
+    assert_eq!(fetch_config(&mut context, config).await.max_supply, 500_000);
+
+    // Emergency instructions are unaffected by the delay
+    send(&mut context, pause_or_unpause(config, mint, authority, None, PAUSE_MINT, true), &[])
+        .await
+        .unwrap();
+    send(&mut context, pause_or_unpause(config, mint, authority, None, PAUSE_MINT, false), &[])
+        .await
+        .unwrap();
+    send(&mut context, freeze_or_thaw(config, mint, token_account, authority, true), &[])
+        .await
+        .unwrap();
+    let user = Pubkey::new_unique();
+    send(&mut context, add_to_blacklist(config, mint, authority, user, blacklist_args(None)), &[])
+        .await
+        .unwrap();
+    assert_eq!(fetch_token_account(&mut context, token_account).await.state, AccountState::Frozen);
+    assert!(account_exists(&mut context, blacklist_entry(config, user)).await);
+}
+
+// ============================================
+// RESERVE ATTESTATION TESTS
+// ============================================
+
+#[tokio::test]
+async fn test_post_reserve_attestation() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let attestor = Keypair::new();
+    fund(&mut context, &attestor).await;
+    send(&mut context, grant_role(config, mint, attestor.pubkey(), authority, RoleKind::Attestor), &[])
+        .await
+        .unwrap();
+
+    // The first post creates the attestation
+    let first_as_of = now(&mut context).await - 60;
+    let instruction = post_reserve_attestation(config, mint, attestor.pubkey(), 1_000_000, first_as_of, [7; 32]);
+    let events: Vec<ReserveAttested> = simulate_events(&mut context, instruction.clone(), &[&attestor]).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].attestor, attestor.pubkey());
+    assert_eq!(events[0].reserves, 1_000_000);
+    assert_eq!(events[0].as_of, first_as_of);
+    assert_eq!(events[0].report_uri_hash, [7; 32]);
+    send(&mut context, instruction, &[&attestor]).await.unwrap();
+    let attestation: ReserveAttestation = fetch_account(&mut context, reserve_attestation(config)).await;
+    assert_eq!(attestation.config, config);
+    assert_eq!(attestation.reserves, 1_000_000);
+    assert_eq!(attestation.as_of, first_as_of);
+    assert_eq!(attestation.attestor, attestor.pubkey());
+    assert_eq!(attestation.posted_at, now(&mut context).await);
+
+    // as_of must not be in the future and must be newer than the current attestation
+    let current = now(&mut context).await;
+    for (as_of, error) in [
+        (current + 1, StablecoinError::InvalidAmount),
+        (first_as_of, StablecoinError::StaleAttestation),
+        (first_as_of - 1, StablecoinError::StaleAttestation),
+    ] {
+        let instruction = post_reserve_attestation(config, mint, attestor.pubkey(), 2_000_000, as_of, [8; 32]);
+        let result = send(&mut context, instruction, &[&attestor]).await;
+        assert_custom_error(result, error);
+    }
+
+    // A newer post overwrites it
+    let instruction = post_reserve_attestation(config, mint, attestor.pubkey(), 2_000_000, current, [8; 32]);
+    send(&mut context, instruction, &[&attestor]).await.unwrap();
+    let attestation: ReserveAttestation = fetch_account(&mut context, reserve_attestation(config)).await;
+    assert_eq!(attestation.reserves, 2_000_000);
+    assert_eq!(attestation.as_of, current);
+    assert_eq!(attestation.report_uri_hash, [8; 32]);
+
+    // Ratios below 100% are rejected
+    let result = send(&mut context, set_reserve_policy(config, mint, authority, true, 3_600, 9_999), &[]).await;
+    assert_custom_error(result, StablecoinError::InvalidAmount);
+
+    // At 200% collateral the reserves back a supply of 1_000_000
+    send(&mut context, set_reserve_policy(config, mint, authority, true, 3_600, 20_000), &[])
+        .await
+        .unwrap();
+    send(&mut context, add_minter(config, mint, authority, authority, u64::MAX), &[])
+        .await
+        .unwrap();
+    let token_account = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+
+    let result = send(&mut context, mint_tokens(config, mint, authority, token_account, 1), &[]).await;
+    assert_custom_error(result, StablecoinError::StaleAttestation);
+    send(&mut context, gated_mint_tokens(config, mint, authority, token_account, 1_000_000), &[])
+        .await
+        .unwrap();
+    let result = send(&mut context, gated_mint_tokens(config, mint, authority, token_account, 1), &[]).await;
+    assert_custom_error(result, StablecoinError::ReservesExceeded);
+
+    // Past max_attestation_age the attestation no longer backs minting
+    set_clock(&mut context, current + 3_601).await;
+    let result = send(&mut context, gated_mint_tokens(config, mint, authority, token_account, 2), &[]).await;
+    assert_custom_error(result, StablecoinError::StaleAttestation);
+    assert_eq!(supply(&mut context, mint).await, 1_000_000);
+}
+
+#[tokio::test]
+async fn test_post_reserve_attestation_by_unauthorized() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+
+    // The master authority has no Attestor role of its own
+    let as_of = now(&mut context).await;
+    let instruction = post_reserve_attestation(config, mint, authority, 1_000, as_of, [0; 32]);
+    let result = send(&mut context, instruction, &[]).await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::AccountNotInitialized as u32);
+
+    let outsider = Keypair::new();
+    fund(&mut context, &outsider).await;
+    let instruction = set_reserve_policy(config, mint, outsider.pubkey(), true, 0, 10_000);
+    let result = send(&mut context, instruction, &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+
+    // With an admin delay, the policy and the Attestor role change only through queued actions
+    apply_admin_action(&mut context, config, mint, AdminAction::SetAdminDelay { delay: 3_600 }).await;
+    let result = send(&mut context, set_reserve_policy(config, mint, authority, true, 0, 10_000), &[]).await;
+    assert_custom_error(result, StablecoinError::TimelockRequired);
+    let attestor = Keypair::new();
+    fund(&mut context, &attestor).await;
+    let instruction = grant_role(config, mint, attestor.pubkey(), authority, RoleKind::Attestor);
+    let result = send(&mut context, instruction, &[]).await;
+    assert_custom_error(result, StablecoinError::TimelockRequired);
+
+    let action = AdminAction::GrantRole {
+        role: RoleKind::Attestor,
+        holder: attestor.pubkey(),
+    };
+    send(&mut context, queue_admin_action(config, mint, authority, 1, action), &[])
+        .await
+        .unwrap();
+    let pending: PendingAdminAction = fetch_account(&mut context, pending_admin_action(config, 1)).await;
+    set_clock(&mut context, pending.eta).await;
+    // GrantRole needs the RoleAssignment PDA in the role_assignment slot
+    let mut execute = execute_admin_action(config, mint, authority, 1, authority, None);
+    execute.accounts[6] = AccountMeta::new(role_assignment(config, RoleKind::Attestor, attestor.pubkey()), false);
+    send(&mut context, execute, &[]).await.unwrap();
+
+    let as_of = now(&mut context).await;
+    let instruction = post_reserve_attestation(config, mint, attestor.pubkey(), 1_000, as_of, [0; 32]);
+    send(&mut context, instruction, &[&attestor]).await.unwrap();
+    let attestation: ReserveAttestation = fetch_account(&mut context, reserve_attestation(config)).await;
+    assert_eq!(attestation.attestor, attestor.pubkey());
+}
+
+// ============================================
+// MINT REQUEST TESTS
+// ============================================
+
+#[tokio::test]
+async fn test_mint_request_workflow() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let minter = Keypair::new();
+    let approvers = [Keypair::new(), Keypair::new()];
+    setup_mint_requests(&mut context, config, mint, &minter, &[&approvers[0], &approvers[1]]).await;
+    send(&mut context, set_mint_approval_policy(config, mint, authority, 2, false), &[])
+        .await
+        .unwrap();
+    let recipient = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    let fiat_reference = "WIRE-2024-000123";
+    let request = mint_request(config, fiat_reference);
+
+    // Zero amounts, empty references and out-of-range expiries are rejected
+    let current = now(&mut context).await;
+    for (amount, reference, expires_at, error) in [
+        (0, fiat_reference, current + 3_600, StablecoinError::InvalidAmount),
+        (1_000, "", current + 3_600, StablecoinError::InvalidAccount),
+        (1_000, fiat_reference, current, StablecoinError::InvalidAmount),
+        (1_000, fiat_reference, current + 7 * 24 * 60 * 60 + 1, StablecoinError::InvalidAmount),
+    ] {
+        let instruction = create_mint_request(config, mint, minter.pubkey(), recipient, amount, reference, expires_at);
+        let result = send(&mut context, instruction, &[&minter]).await;
+        assert_custom_error(result, error);
+    }
+
+    let expires_at = current + 3_600;
+    let instruction = create_mint_request(config, mint, minter.pubkey(), recipient, 1_000, fiat_reference, expires_at);
+    let events: Vec<MintRequestCreated> = simulate_events(&mut context, instruction.clone(), &[&minter]).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].request, request);
+    assert_eq!(events[0].minter, minter.pubkey());
+    assert_eq!(events[0].recipient, recipient);
+    assert_eq!(events[0].amount, 1_000);
+    assert_eq!(events[0].fiat_reference, fiat_reference);
+    assert_eq!(events[0].expires_at, expires_at);
+    send(&mut context, instruction, &[&minter]).await.unwrap();
+    let pending: MintRequest = fetch_account(&mut context, request).await;
+    assert_eq!(pending.config, config);
+    assert_eq!(pending.minter, minter.pubkey());
+    assert_eq!(pending.recipient, recipient);
+    assert_eq!(pending.amount, 1_000);
+    assert_eq!(pending.fiat_reference, fiat_reference);
+    assert!(pending.approvals.is_empty());
+    assert_eq!(pending.expires_at, expires_at);
+    assert_eq!(pending.version, ACCOUNT_VERSION);
+
+    // One of two approvals is not enough
+    let instruction = approve_mint_request(config, mint, approvers[0].pubkey(), fiat_reference, &[]);
+    let events: Vec<MintRequestApproved> = simulate_events(&mut context, instruction.clone(), &[&approvers[0]]).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].approver, approvers[0].pubkey());
+    assert_eq!(events[0].approvals, 1);
+    assert_eq!(events[0].approvals_required, 2);
+    send(&mut context, instruction, &[&approvers[0]]).await.unwrap();
+    let approver_keys = [approvers[0].pubkey(), approvers[1].pubkey()];
+    let instruction = execute_mint_request(config, mint, minter.pubkey(), recipient, fiat_reference, &approver_keys);
+    let result = send(&mut context, instruction, &[&minter]).await;
+    assert_custom_error(result, StablecoinError::ThresholdNotMet);
+
+    let instruction = approve_mint_request(config, mint, approvers[1].pubkey(), fiat_reference, &[]);
+    send(&mut context, instruction, &[&approvers[1]]).await.unwrap();
+
+    // Only the requesting minter can execute, even once approved
+    let other_minter = Keypair::new();
+    setup_mint_requests(&mut context, config, mint, &other_minter, &[]).await;
+    let instruction =
+        execute_mint_request(config, mint, other_minter.pubkey(), recipient, fiat_reference, &approver_keys);
+    let result = send(&mut context, instruction, &[&other_minter]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+
+    let instruction = execute_mint_request(config, mint, minter.pubkey(), recipient, fiat_reference, &approver_keys);
+    let minted: Vec<TokensMinted> = simulate_events(&mut context, instruction.clone(), &[&minter]).await;
+    assert_eq!(minted.len(), 1);
+    assert_eq!(minted[0].minter, minter.pubkey());
+    assert_eq!(minted[0].token_account, recipient);
+    assert_eq!(minted[0].amount, 1_000);
+    let executed: Vec<MintRequestExecuted> = simulate_events(&mut context, instruction.clone(), &[&minter]).await;
+    assert_eq!(executed.len(), 1);
+    assert_eq!(executed[0].request, request);
+    assert_eq!(executed[0].fiat_reference, fiat_reference);
+    assert_eq!(executed[0].approvers, approver_keys.to_vec());
+    send(&mut context, instruction, &[&minter]).await.unwrap();
+    assert_eq!(fetch_token_account(&mut context, recipient).await.amount, 1_000);
+    assert_eq!(supply(&mut context, mint).await, 1_000);
+    let minter_info = pda(&[b"minter", config.as_ref(), minter.pubkey().as_ref()]);
+    let minter_info: MinterInfo = fetch_account(&mut context, minter_info).await;
+    assert_eq!(minter_info.minted, 1_000);
+
+    // The executed request is closed and its fiat reference is spent
+    assert!(!account_exists(&mut context, request).await);
+    let used: UsedFiatReference = fetch_account(&mut context, used_fiat_reference(config, fiat_reference)).await;
+    assert_eq!(used.minter, minter.pubkey());
+    assert_eq!(used.recipient, recipient);
+    assert_eq!(used.amount, 1_000);
+    let instruction = approve_mint_request(config, mint, approvers[0].pubkey(), fiat_reference, &[]);
+    let result = send(&mut context, instruction, &[&approvers[0]]).await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::AccountNotInitialized as u32);
+    let instruction = create_mint_request(config, mint, minter.pubkey(), recipient, 1_000, fiat_reference, expires_at);
+    let result = send(&mut context, instruction, &[&minter]).await;
+    assert_custom_error(result, StablecoinError::FiatReferenceUsed);
+}
+
+#[tokio::test]
+async fn test_approve_own_mint_request() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let minter = Keypair::new();
+    let approver = Keypair::new();
+    setup_mint_requests(&mut context, config, mint, &minter, &[&approver]).await;
+    let recipient = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    let fiat_reference = "WIRE-2024-000124";
+    let expires_at = now(&mut context).await + 3_600;
+    let instruction = create_mint_request(config, mint, minter.pubkey(), recipient, 1_000, fiat_reference, expires_at);
+    send(&mut context, instruction, &[&minter]).await.unwrap();
+
+    // Holding the MintApprover role does not let the minter approve their own request
+    send(&mut context, grant_role(config, mint, minter.pubkey(), authority, RoleKind::MintApprover), &[])
+        .await
+        .unwrap();
+    let instruction = approve_mint_request(config, mint, minter.pubkey(), fiat_reference, &[]);
+    let result = send(&mut context, instruction, &[&minter]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+
+    // The same approver cannot approve twice
+    let instruction = approve_mint_request(config, mint, approver.pubkey(), fiat_reference, &[]);
+    send(&mut context, instruction.clone(), &[&approver]).await.unwrap();
+    refresh_blockhash(&mut context).await;
+    let result = send(&mut context, instruction, &[&approver]).await;
+    assert_custom_error(result, StablecoinError::AlreadyApproved);
+
+    // Signers without the MintApprover role can neither approve nor reject
+    let outsider = Keypair::new();
+    fund(&mut context, &outsider).await;
+    let instruction = approve_mint_request(config, mint, outsider.pubkey(), fiat_reference, &[]);
+    let result = send(&mut context, instruction, &[&outsider]).await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::AccountNotInitialized as u32);
+    let instruction = reject_mint_request(config, mint, minter.pubkey(), outsider.pubkey(), None, fiat_reference);
+    let result = send(&mut context, instruction, &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    let outsider_role = role_assignment(config, RoleKind::MintApprover, outsider.pubkey());
+    let instruction =
+        reject_mint_request(config, mint, minter.pubkey(), outsider.pubkey(), Some(outsider_role), fiat_reference);
+    let result = send(&mut context, instruction, &[&outsider]).await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::AccountNotInitialized as u32);
+
+    // A MintApprover rejects the request; its rent goes back to the minter
+    let minter_lamports = context.banks_client.get_balance(minter.pubkey()).await.unwrap();
+    let request_lamports = context
+        .banks_client
+        .get_balance(mint_request(config, fiat_reference))
+        .await
+        .unwrap();
+    let approver_role = role_assignment(config, RoleKind::MintApprover, approver.pubkey());
+    let instruction =
+        reject_mint_request(config, mint, minter.pubkey(), approver.pubkey(), Some(approver_role), fiat_reference);
+    let events: Vec<MintRequestRejected> = simulate_events(&mut context, instruction.clone(), &[&approver]).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].rejected_by, approver.pubkey());
+    assert_eq!(events[0].fiat_reference, fiat_reference);
+    assert!(!events[0].expired);
+    send(&mut context, instruction, &[&approver]).await.unwrap();
+    assert!(!account_exists(&mut context, mint_request(config, fiat_reference)).await);
+    assert_eq!(
+        context.banks_client.get_balance(minter.pubkey()).await.unwrap(),
+        minter_lamports + request_lamports
+    );
+    assert_eq!(supply(&mut context, mint).await, 0);
+}
+
+#[tokio::test]
+async fn test_execute_mint_request_without_approvals() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    let minter = Keypair::new();
+    let approvers = [Keypair::new(), Keypair::new()];
+    setup_mint_requests(&mut context, config, mint, &minter, &[&approvers[0], &approvers[1]]).await;
+    let recipient = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+    let fiat_reference = "WIRE-2024-000125";
+    let expires_at = now(&mut context).await + 3_600;
+    let instruction = create_mint_request(config, mint, minter.pubkey(), recipient, 1_000, fiat_reference, expires_at);
+    send(&mut context, instruction, &[&minter]).await.unwrap();
+
+    // The default policy still needs one approval
+    let instruction = execute_mint_request(config, mint, minter.pubkey(), recipient, fiat_reference, &[]);
+    let result = send(&mut context, instruction, &[&minter]).await;
+    assert_custom_error(result, StablecoinError::ThresholdNotMet);
+
+    // Approvals from revoked approvers are ignored; their closed role PDAs cannot be passed
+    let instruction = approve_mint_request(config, mint, approvers[0].pubkey(), fiat_reference, &[]);
+    send(&mut context, instruction, &[&approvers[0]]).await.unwrap();
+    send(&mut context, revoke_role(config, mint, approvers[0].pubkey(), authority, RoleKind::MintApprover), &[])
+        .await
+        .unwrap();
+    refresh_blockhash(&mut context).await;
+    let instruction = execute_mint_request(config, mint, minter.pubkey(), recipient, fiat_reference, &[]);
+    let result = send(&mut context, instruction, &[&minter]).await;
+    assert_custom_error(result, StablecoinError::ThresholdNotMet);
+    let revoked = [approvers[0].pubkey()];
+    let instruction = execute_mint_request(config, mint, minter.pubkey(), recipient, fiat_reference, &revoked);
+    let result = send(&mut context, instruction, &[&minter]).await;
+    assert_custom_error(result, StablecoinError::InvalidAccount);
+
+    // The next approval drops the revoked approver's
+    let instruction = approve_mint_request(config, mint, approvers[1].pubkey(), fiat_reference, &revoked);
+    send(&mut context, instruction, &[&approvers[1]]).await.unwrap();
+    let pending: MintRequest = fetch_account(&mut context, mint_request(config, fiat_reference)).await;
+    assert_eq!(pending.approvals, vec![approvers[1].pubkey()]);
+    let live = [approvers[1].pubkey()];
+    let instruction = execute_mint_request(config, mint, minter.pubkey(), recipient, fiat_reference, &live);
+    send(&mut context, instruction.clone(), &[&minter]).await.unwrap();
+    assert_eq!(supply(&mut context, mint).await, 1_000);
+
+    // An executed request is closed and cannot be executed again
+    refresh_blockhash(&mut context).await;
+    let result = send(&mut context, instruction, &[&minter]).await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::AccountNotInitialized as u32);
+
+    // Expired requests cannot be approved or executed, only closed by anyone
+    let fiat_reference = "WIRE-2024-000126";
+    let current = now(&mut context).await;
+    let expires_at = current + 600;
+    let instruction = create_mint_request(config, mint, minter.pubkey(), recipient, 500, fiat_reference, expires_at);
+    send(&mut context, instruction, &[&minter]).await.unwrap();
+    let instruction = approve_mint_request(config, mint, approvers[1].pubkey(), fiat_reference, &[]);
+    send(&mut context, instruction, &[&approvers[1]]).await.unwrap();
+    let caller = Keypair::new();
+    fund(&mut context, &caller).await;
+    let close = close_expired_mint_request(config, mint, minter.pubkey(), caller.pubkey(), fiat_reference);
+    let result = send(&mut context, close.clone(), &[&caller]).await;
+    assert_custom_error(result, StablecoinError::MintRequestNotExpired);
+
+    refresh_blockhash(&mut context).await;
+    set_clock(&mut context, expires_at).await;
+    let instruction = approve_mint_request(config, mint, approvers[1].pubkey(), fiat_reference, &[]);
+    let result = send(&mut context, instruction, &[&approvers[1]]).await;
+    assert_custom_error(result, StablecoinError::MintRequestExpired);
+    let instruction = execute_mint_request(config, mint, minter.pubkey(), recipient, fiat_reference, &live);
+    let result = send(&mut context, instruction, &[&minter]).await;
+    assert_custom_error(result, StablecoinError::MintRequestExpired);
+
+    let events: Vec<MintRequestRejected> = simulate_events(&mut context, close.clone(), &[&caller]).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].rejected_by, caller.pubkey());
+    assert!(events[0].expired);
+    send(&mut context, close, &[&caller]).await.unwrap();
+    assert!(!account_exists(&mut context, mint_request(config, fiat_reference)).await);
+    assert!(!account_exists(&mut context, used_fiat_reference(config, fiat_reference)).await);
+    assert_eq!(supply(&mut context, mint).await, 1_000);
+}
+
+#[tokio::test]
+async fn test_mint_tokens_when_requests_only() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let authority = context.payer.pubkey();
+    send(&mut context, add_minter(config, mint, authority, authority, u64::MAX), &[])
+        .await
+        .unwrap();
+    let token_account = create_token_account(&mut context, mint, Pubkey::new_unique()).await;
+
+    // Only the master authority sets the policy, within 1..=MAX_MINT_APPROVALS approvals
+    let outsider = Keypair::new();
+    fund(&mut context, &outsider).await;
+    let instruction = set_mint_approval_policy(config, mint, outsider.pubkey(), 1, true);
+    let result = send(&mut context, instruction, &[&outsider]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    for approvals_required in [0, MAX_MINT_APPROVALS as u8 + 1] {
+        let instruction = set_mint_approval_policy(config, mint, authority, approvals_required, true);
+        let result = send(&mut context, instruction, &[]).await;
+        assert_custom_error(result, StablecoinError::InvalidAmount);
+    }
+
+    send(&mut context, set_mint_approval_policy(config, mint, authority, 1, true), &[])
+        .await
+        .unwrap();
+    let config_state = fetch_config(&mut context, config).await;
+    assert_eq!(config_state.mint_approvals_required, 1);
+    assert!(config_state.mint_requests_only);
+    let result = send(&mut context, mint_tokens(config, mint, authority, token_account, 1_000), &[]).await;
+    assert_custom_error(result, StablecoinError::MintRequestRequired);
+
+    // With an admin delay, the policy changes only through a queued action
+    apply_admin_action(&mut context, config, mint, AdminAction::SetAdminDelay { delay: 3_600 }).await;
+    let result = send(&mut context, set_mint_approval_policy(config, mint, authority, 1, false), &[]).await;
+    assert_custom_error(result, StablecoinError::TimelockRequired);
+    let id = fetch_config(&mut context, config).await.next_admin_action_id;
+    let action = AdminAction::SetMintApprovalPolicy {
+        approvals_required: 1,
+        requests_only: false,
+    };
+    send(&mut context, queue_admin_action(config, mint, authority, id, action), &[])
+        .await
+        .unwrap();
+    let pending: PendingAdminAction = fetch_account(&mut context, pending_admin_action(config, id)).await;
+    set_clock(&mut context, pending.eta).await;
+    send(&mut context, execute_admin_action(config, mint, authority, id, authority, None), &[])
+        .await
+        .unwrap();
+    assert!(!fetch_config(&mut context, config).await.mint_requests_only);
+    send(&mut context, mint_tokens(config, mint, authority, token_account, 1_000), &[])
+        .await
+        .unwrap();
+    assert_eq!(supply(&mut context, mint).await, 1_000);
+}
+
+// ============================================
+// COUNCIL TESTS
+// ============================================
+
+#[tokio::test]
+async fn test_council_as_master_authority() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 1_000_000).await;
+    let mint = mint.pubkey();
+    let council = pda(&[b"council", config.as_ref()]);
+    let council_authority = pda(&[b"council_authority", config.as_ref()]);
+
+    // 2-of-2 council: the current master authority and a second member
+    let authority = context.payer.insecure_clone();
+    let second_member = Keypair::new();
+    fund(&mut context, &second_member).await;
+    create_council(&mut context, config, mint, vec![authority.pubkey(), second_member.pubkey()], 2)
+        .await
+        .unwrap();
+
+    // Creating the council does not hand over control
+    let account = context.banks_client.get_account(council).await.unwrap().unwrap();
+    let admin_council = AdminCouncil::try_deserialize(&mut account.data.as_slice()).unwrap();
+    assert_eq!(admin_council.members, vec![authority.pubkey(), second_member.pubkey()]);
+    assert_eq!(admin_council.threshold, 2);
+    assert_eq!(fetch_config(&mut context, config).await.master_authority, authority.pubkey());
+
+    // Hand master authority to the council: propose its authority PDA...
+    propose_master_authority(&mut context, config, mint, council_authority).await.unwrap();
+
+    // ...and accept through a council proposal signed by the PDA
+    let accept = accept_master_authority(config, mint, council_authority);
+
+    // A single approval is below the threshold
+    let result = run_council_proposal(&mut context, config, mint, 0, accept.clone(), &authority, &[]).await;
+    assert_custom_error(result, StablecoinError::ThresholdNotMet);
+
+    // Proposal 0 stays open, so the accept goes out again as proposal 1
+    run_council_proposal(&mut context, config, mint, 1, accept, &authority, &[&second_member])
+        .await
+        .unwrap();
+    let stablecoin = fetch_config(&mut context, config).await;
+    assert_eq!(stablecoin.master_authority, council_authority);
+    assert_eq!(stablecoin.pending_master_authority, None);
+
+    // Executed proposals are closed
+    let executed = pda(&[b"council_proposal", council.as_ref(), &1u64.to_le_bytes()]);
+    assert!(context.banks_client.get_account(executed).await.unwrap().is_none());
+
+    // The previous master authority can no longer act alone
+    let update_max_supply = |master_authority: Pubkey| Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::UpdateMaxSupply {
+            config,
+            mint,
+            master_authority,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::UpdateMaxSupply { new_max_supply: 2_000_000 }.data(),
+    };
+    let result = send(&mut context, update_max_supply(authority.pubkey()), &[]).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+
+    // Admin instructions now run through council proposals
+    run_council_proposal(
+        &mut context,
+        config,
+        mint,
+        2,
+        update_max_supply(council_authority),
+        &second_member,
+        &[&authority],
+    )
+    .await
+    .unwrap();
+    let stablecoin = fetch_config(&mut context, config).await;
+    assert_eq!(stablecoin.max_supply, 2_000_000);
+}
+
+#[tokio::test]
+async fn test_council_proposal_threshold_and_replay() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let council_authority = pda(&[b"council_authority", config.as_ref()]);
+
+    // 2-of-3 council
+    let authority = context.payer.insecure_clone();
+    let second_member = Keypair::new();
+    let third_member = Keypair::new();
+    fund(&mut context, &second_member).await;
+    fund(&mut context, &third_member).await;
+    let members = vec![authority.pubkey(), second_member.pubkey(), third_member.pubkey()];
+    create_council(&mut context, config, mint, members, 2).await.unwrap();
+    propose_master_authority(&mut context, config, mint, council_authority).await.unwrap();
+
+    let accept = accept_master_authority(config, mint, council_authority);
+    let expires_at = now(&mut context).await + 60 * 60;
+    create_council_proposal(&mut context, config, mint, 0, &accept, &authority, expires_at)
+        .await
+        .unwrap();
+
+    // The proposer's own approval is 1 of 2
+    let result =
+        execute_council_proposal(&mut context, config, mint, 0, &accept, authority.pubkey(), &authority).await;
+    assert_custom_error(result, StablecoinError::ThresholdNotMet);
+
+    // Approving twice does not count twice
+    approve_council_proposal(&mut context, config, mint, 0, &second_member).await.unwrap();
+    refresh_blockhash(&mut context).await;
+    let result = approve_council_proposal(&mut context, config, mint, 0, &second_member).await;
+    assert_custom_error(result, StablecoinError::AlreadyApproved);
+
+    // Any member can execute once the threshold is met
+    execute_council_proposal(&mut context, config, mint, 0, &accept, authority.pubkey(), &third_member)
+        .await
+        .unwrap();
+    assert_eq!(fetch_config(&mut context, config).await.master_authority, council_authority);
+
+    // The executed proposal is closed, so it cannot be replayed
+    let result =
+        execute_council_proposal(&mut context, config, mint, 0, &accept, authority.pubkey(), &second_member).await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::AccountNotInitialized as u32);
+}
+
+#[tokio::test]
+async fn test_council_proposal_by_non_member() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let council_authority = pda(&[b"council_authority", config.as_ref()]);
+
+    let authority = context.payer.insecure_clone();
+    let outsider = Keypair::new();
+    fund(&mut context, &outsider).await;
+    create_council(&mut context, config, mint, vec![authority.pubkey()], 1).await.unwrap();
+    propose_master_authority(&mut context, config, mint, council_authority).await.unwrap();
+
+    let accept = accept_master_authority(config, mint, council_authority);
+    let expires_at = now(&mut context).await + 60 * 60;
+    let result = create_council_proposal(&mut context, config, mint, 0, &accept, &outsider, expires_at).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+
+    create_council_proposal(&mut context, config, mint, 0, &accept, &authority, expires_at)
+        .await
+        .unwrap();
+    let result = approve_council_proposal(&mut context, config, mint, 0, &outsider).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    let result =
+        execute_council_proposal(&mut context, config, mint, 0, &accept, authority.pubkey(), &outsider).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    assert_eq!(fetch_config(&mut context, config).await.master_authority, authority.pubkey());
+}
+
+#[tokio::test]
+async fn test_council_proposal_expiry() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let council = pda(&[b"council", config.as_ref()]);
+    let council_authority = pda(&[b"council_authority", config.as_ref()]);
+
+    let authority = context.payer.insecure_clone();
+    let second_member = Keypair::new();
+    fund(&mut context, &second_member).await;
+    create_council(&mut context, config, mint, vec![authority.pubkey(), second_member.pubkey()], 2)
+        .await
+        .unwrap();
+    propose_master_authority(&mut context, config, mint, council_authority).await.unwrap();
+    let accept = accept_master_authority(config, mint, council_authority);
+
+    // Expiry must be in the future and within MAX_COUNCIL_PROPOSAL_TTL
+    let created_at = now(&mut context).await;
+    let result = create_council_proposal(&mut context, config, mint, 0, &accept, &authority, created_at).await;
+    assert_custom_error(result, StablecoinError::InvalidAmount);
+    let too_late = created_at + MAX_COUNCIL_PROPOSAL_TTL + 1;
+    let result = create_council_proposal(&mut context, config, mint, 0, &accept, &authority, too_late).await;
+    assert_custom_error(result, StablecoinError::InvalidAmount);
+
+    let expires_at = created_at + 60 * 60;
+    create_council_proposal(&mut context, config, mint, 0, &accept, &authority, expires_at)
+        .await
+        .unwrap();
+    let proposal = pda(&[b"council_proposal", council.as_ref(), &0u64.to_le_bytes()]);
+    let account = context.banks_client.get_account(proposal).await.unwrap().unwrap();
+    let stored = CouncilProposal::try_deserialize(&mut account.data.as_slice()).unwrap();
+    assert_eq!(stored.expires_at, expires_at);
+
+    // Past its expiry the proposal can neither gather approvals nor execute
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    context.set_sysvar(&Clock {
+        unix_timestamp: expires_at,
+        ..clock
+    });
+    let result = approve_council_proposal(&mut context, config, mint, 0, &second_member).await;
+    assert_custom_error(result, StablecoinError::CouncilProposalExpired);
+    let result =
+        execute_council_proposal(&mut context, config, mint, 0, &accept, authority.pubkey(), &authority).await;
+    assert_custom_error(result, StablecoinError::CouncilProposalExpired);
+    assert_eq!(fetch_config(&mut context, config).await.master_authority, authority.pubkey());
+}
+
+#[tokio::test]
+async fn test_council_proposal_instruction_not_allowed() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let council = pda(&[b"council", config.as_ref()]);
+    let council_authority = pda(&[b"council_authority", config.as_ref()]);
+
+    let authority = context.payer.insecure_clone();
+    create_council(&mut context, config, mint, vec![authority.pubkey()], 1).await.unwrap();
+    let expires_at = now(&mut context).await + 60 * 60;
+
+    // Role-signed instructions are not on the whitelist...
+    let pause = Instruction {
+        program_id: sss_token::ID,
+        accounts: sss_token::accounts::Pause {
+            config,
+            mint,
+            pauser: council_authority,
+            role_assignment: None,
+            token_program: spl_token_2022::ID,
+            event_authority: event_authority(),
+            program: sss_token::ID,
+        }
+        .to_account_metas(None),
+        data: sss_token::instruction::Pause { mask: sss_token::PAUSE_ALL }.data(),
+    };
+    let result = create_council_proposal(&mut context, config, mint, 0, &pause, &authority, expires_at).await;
+    assert_custom_error(result, StablecoinError::ProposalInstructionNotAllowed);
+
+    // ...and neither is the proposal flow itself
+    let execute = Instruction {
+        program_id: sss_token::ID,
+        accounts: vec![AccountMeta::new_readonly(council, false)],
+        data: sss_token::instruction::ExecuteCouncilProposal {}.data(),
+    };
+    let result = create_council_proposal(&mut context, config, mint, 0, &execute, &authority, expires_at).await;
+    assert_custom_error(result, StablecoinError::ProposalInstructionNotAllowed);
+}
+
+#[tokio::test]
+async fn test_execute_council_proposal_below_threshold() {
+    let mut context = start().await;
+    let (mint, config) = initialize(&mut context, false, false, false, 0).await;
+    let mint = mint.pubkey();
+    let council_authority = pda(&[b"council_authority", config.as_ref()]);
+
+    // 2-of-3 council
+    let authority = context.payer.insecure_clone();
+    let second_member = Keypair::new();
+    let third_member = Keypair::new();
+    fund(&mut context, &second_member).await;
+    fund(&mut context, &third_member).await;
+    let members = vec![authority.pubkey(), second_member.pubkey(), third_member.pubkey()];
+    create_council(&mut context, config, mint, members, 2).await.unwrap();
+    propose_master_authority(&mut context, config, mint, council_authority).await.unwrap();
+
+    // update_council needs the council authority signature
+    let mut direct = update_council(config, mint, vec![authority.pubkey()], 1);
+    direct.accounts[3].is_signer = false;
+    let result = send(&mut context, direct, &[]).await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::AccountNotSigner as u32);
+
+    // Proposal 0 gathers the proposer's and the second member's approval
+    let accept = accept_master_authority(config, mint, council_authority);
+    let expires_at = now(&mut context).await + 60 * 60;
+    create_council_proposal(&mut context, config, mint, 0, &accept, &authority, expires_at)
+        .await
+        .unwrap();
+    approve_council_proposal(&mut context, config, mint, 0, &second_member).await.unwrap();
+
+    // Proposal 1 removes the second member
+    let remaining = vec![authority.pubkey(), third_member.pubkey()];
+    let update = update_council(config, mint, remaining.clone(), 2);
+    run_council_proposal(&mut context, config, mint, 1, update, &authority, &[&third_member])
+        .await
+        .unwrap();
+    let account = context.banks_client.get_account(pda(&[b"council", config.as_ref()])).await.unwrap().unwrap();
+    assert_eq!(AdminCouncil::try_deserialize(&mut account.data.as_slice()).unwrap().members, remaining);
+
+    // The removed member's approval no longer counts
+    let result =
+        execute_council_proposal(&mut context, config, mint, 0, &accept, authority.pubkey(), &authority).await;
+    assert_custom_error(result, StablecoinError::ThresholdNotMet);
+    let result = approve_council_proposal(&mut context, config, mint, 0, &second_member).await;
+    assert_custom_error(result, StablecoinError::Unauthorized);
+    assert_eq!(fetch_config(&mut context, config).await.master_authority, authority.pubkey());
+
+    approve_council_proposal(&mut context, config, mint, 0, &third_member).await.unwrap();
+    execute_council_proposal(&mut context, config, mint, 0, &accept, authority.pubkey(), &third_member)
+        .await
+        .unwrap();
+    assert_eq!(fetch_config(&mut context, config).await.master_authority, council_authority);
+}
@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount};
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList};
+use spl_transfer_hook_interface::instruction::{ExecuteInstruction, TransferHookInstruction};
+use sss_token::{BlacklistEntry, StablecoinConfig, Versioned, PAUSE_TRANSFER};
 
 // Program ID
 declare_id!("8Zaw1qJer6QPw15yg2bBgkAEB5rP73QisHVv6djANpSB");
@@ -20,6 +23,8 @@ pub enum TransferHookError {
     InvalidMintAccount,
     #[msg("Transfer is paused")]
     TransferPaused,
+    #[msg("No pending authority transfer")]
+    NoPendingAuthority,
 }
 
 // ============================================
@@ -28,12 +33,13 @@ pub enum TransferHookError {
 
 /// Extra account required for transfer hook validation
 /// This account holds reference to the stablecoin config
+/// Pause state is not stored here: it is read from the StablecoinConfig on every transfer
 #[account]
 pub struct TransferHookData {
     pub stablecoin_program: Pubkey,
     pub mint: Pubkey,
     pub authority: Pubkey,
-    pub paused: bool,
+    pub pending_authority: Option<Pubkey>,
     pub bump: u8,
 }
 
@@ -42,10 +48,87 @@ impl TransferHookData {
         32 + // stablecoin_program
         32 + // mint
         32 + // authority
-        1 +  // paused
+        1 + 32 + // pending_authority
         1;   // bump
 }
 
+// ============================================
+// HELPERS
+// ============================================
+
+/// Extra accounts Token-2022 appends to every Execute call, in order:
+/// 5. sss_token program
+/// 6. config - ["config", mint] in sss_token
+/// 7. sender blacklist entry - ["blacklist", config, source owner] in sss_token
+/// 8. recipient blacklist entry - ["blacklist", config, destination owner] in sss_token
+/// Indices 0-4 are the interface accounts (source, mint, destination, authority, validation)
+/// and token account owners are read from bytes 32..64 of the token account data
+pub fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    let blacklist_seeds = |account_index: u8| {
+        [
+            Seed::Literal { bytes: b"blacklist".to_vec() },
+            Seed::AccountKey { index: 6 },
+            Seed::AccountData { account_index, data_index: 32, length: 32 },
+        ]
+    };
+
+    Ok(vec![
+        ExtraAccountMeta::new_with_pubkey(&sss_token::ID, false, false)?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal { bytes: b"config".to_vec() },
+                Seed::AccountKey { index: 1 },
+            ],
+            false,
+            false,
+        )?,
+        ExtraAccountMeta::new_external_pda_with_seeds(5, &blacklist_seeds(0), false, false)?,
+        ExtraAccountMeta::new_external_pda_with_seeds(5, &blacklist_seeds(2), false, false)?,
+    ])
+}
+
+/// Whether `authority` is one of the sss_token PDAs that move tokens on the issuer's behalf:
+/// the permanent delegate (seizures) or the config (transfers the program signs for itself)
+/// sss_token enforces its own pause flags and blacklist rules for those transfers
+fn is_program_authority(authority: &Pubkey, mint: &Pubkey, config: &Pubkey) -> bool {
+    if authority == config {
+        return true;
+    }
+    let (permanent_delegate, _) =
+        Pubkey::find_program_address(&[b"permanent_delegate", mint.as_ref()], &sss_token::ID);
+    *authority == permanent_delegate
+}
+
+/// Read the StablecoinConfig from `config_info`
+/// Configs written before versioning still carry a pause state, so they are decoded
+/// from the version 0 layout rather than rejected until `migrate_config` has run
+fn load_config(config_info: &AccountInfo) -> Result<StablecoinConfig> {
+    require_keys_eq!(*config_info.owner, sss_token::ID, TransferHookError::InvalidTransferHookAccount);
+    let data = config_info.try_borrow_data()?;
+    if data.len() < StablecoinConfig::LEN {
+        StablecoinConfig::decode_legacy(data.get(8..).unwrap_or_default())
+    } else {
+        StablecoinConfig::try_deserialize(&mut &data[..])
+    }
+}
+
+/// Whether `entry_info` holds a BlacklistEntry that is still in force at `now`
+/// An empty account means the owner was never blacklisted (or the entry was removed)
+fn is_blacklisted(entry_info: &AccountInfo, now: i64) -> Result<bool> {
+    if entry_info.data_is_empty() || *entry_info.owner != sss_token::ID {
+        return Ok(false);
+    }
+    let data = entry_info.try_borrow_data()?;
+    // Entries written before versioning keep blocking transfers until they are migrated
+    let entry = if data.len() < BlacklistEntry::LEN {
+        BlacklistEntry::decode_legacy(data.get(8..).unwrap_or_default())?
+    } else {
+        BlacklistEntry::try_deserialize(&mut &data[..])?
+    };
+    Ok(!entry.is_expired(now))
+}
+
 // ============================================
 // INSTRUCTIONS
 // ============================================
@@ -63,63 +146,61 @@ pub mod transfer_hook {
         hook_data.stablecoin_program = ctx.accounts.stablecoin_program.key();
         hook_data.mint = ctx.accounts.mint.key();
         hook_data.authority = ctx.accounts.authority.key();
-        hook_data.paused = false;
+        hook_data.pending_authority = None;
         hook_data.bump = ctx.bumps.hook_data;
 
         msg!("Transfer hook initialized for mint {}", ctx.accounts.mint.key());
         Ok(())
     }
 
-    /// Pause transfer hook validation
-    pub fn pause(ctx: Context<Pause>) -> Result<()> {
-        let hook_data = &mut ctx.accounts.hook_data;
-        hook_data.paused = true;
-        msg!("Transfer hook paused");
-        Ok(())
-    }
-
-    /// Unpause transfer hook validation
-    pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
-        let hook_data = &mut ctx.accounts.hook_data;
-        hook_data.paused = false;
-        msg!("Transfer hook unpaused");
-        Ok(())
-    }
-
-    /// The main transfer hook function called by Token-2022
-    /// This validates that neither sender nor recipient is blacklisted
-    pub fn extra_account_metas(
-        _ctx: Context<ExtraAccountMetas>,
+    /// Write the ExtraAccountMetaList Token-2022 uses to resolve the Execute accounts
+    /// Permissionless: the list is the same for every mint (see `extra_account_metas`)
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
     ) -> Result<()> {
-        // This instruction returns the accounts needed for the transfer hook
-        // The actual validation happens in the transfer hook instruction
+        let metas = extra_account_metas()?;
+        let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas)?;
+
+        msg!("Extra account metas initialized for mint {}", ctx.accounts.mint.key());
         Ok(())
     }
 
     /// Execute transfer hook validation
     /// This is called during every token transfer if the transfer hook extension is enabled
+    /// Transfers are rejected while the stablecoin has PAUSE_TRANSFER set (`sss_token::pause`)
+    /// Seizures and escrow releases signed by sss_token PDAs are not checked here
     pub fn execute(ctx: Context<ExecuteTransferHook>, amount: u64) -> Result<()> {
-        let hook_data = &ctx.accounts.hook_data;
-
-        // Check if transfer hook is paused
-        require!(!hook_data.paused, TransferHookError::TransferPaused);
-
         // Get sender and recipient addresses from the source and destination token accounts
         let sender = &ctx.accounts.source_token.owner;
         let recipient = &ctx.accounts.dest_token.owner;
 
-        let sender_blacklist_info = &ctx.accounts.sender_blacklist;
+        if is_program_authority(
+            ctx.accounts.owner.key,
+            &ctx.accounts.mint.key(),
+            &ctx.accounts.config.key(),
+        ) {
+            msg!("Issuer transfer: {} tokens from {} to {}", amount, sender, recipient);
+            return Ok(());
+        }
+
+        // Check if transfers are paused on the stablecoin
+        let config = load_config(&ctx.accounts.config)?;
+        require!(
+            !config.is_paused(PAUSE_TRANSFER),
+            TransferHookError::TransferPaused
+        );
+
+        // Expired entries no longer block transfers, even before they are purged
+        let now = Clock::get()?.unix_timestamp;
 
-        // If sender blacklist account exists and is valid, reject transfer
-        if sender_blacklist_info.data.borrow().len() > 0 {
+        // If sender blacklist entry exists and has not expired, reject transfer
+        if is_blacklisted(&ctx.accounts.sender_blacklist, now)? {
             return Err(TransferHookError::SenderBlacklisted.into());
         }
 
-        // Check if recipient is blacklisted
-        let recipient_blacklist_info = &ctx.accounts.recipient_blacklist;
-
-        // If recipient blacklist account exists and is valid, reject transfer
-        if recipient_blacklist_info.data.borrow().len() > 0 {
+        // If recipient blacklist entry exists and has not expired, reject transfer
+        if is_blacklisted(&ctx.accounts.recipient_blacklist, now)? {
             return Err(TransferHookError::RecipientBlacklisted.into());
         }
 
@@ -127,17 +208,62 @@ pub mod transfer_hook {
         Ok(())
     }
 
-    /// Update transfer hook authority
+    /// Token-2022 invokes the hook with the spl-transfer-hook-interface Execute
+    /// discriminator, which is not an Anchor discriminator, so route it to `execute` here
+    pub fn fallback<'info>(
+        program_id: &Pubkey,
+        accounts: &'info [AccountInfo<'info>],
+        data: &[u8],
+    ) -> Result<()> {
+        match TransferHookInstruction::unpack(data)? {
+            TransferHookInstruction::Execute { amount } => {
+                __private::__global::execute(program_id, accounts, &amount.to_le_bytes())
+            }
+            _ => Err(ProgramError::InvalidInstructionData.into()),
+        }
+    }
+
+    /// Propose a new transfer hook authority (step 1 of 2)
+    /// The new authority must call `accept_authority` to take over
     pub fn update_authority(
         ctx: Context<UpdateAuthority>,
         new_authority: Pubkey,
     ) -> Result<()> {
         let hook_data = &mut ctx.accounts.hook_data;
+        hook_data.pending_authority = Some(new_authority);
+
+        msg!("Proposed transfer hook authority {}", new_authority);
+        Ok(())
+    }
+
+    /// Accept a pending transfer hook authority transfer (step 2 of 2)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        let hook_data = &mut ctx.accounts.hook_data;
+        let new_authority = ctx.accounts.new_authority.key();
+
+        let pending = hook_data
+            .pending_authority
+            .ok_or(TransferHookError::NoPendingAuthority)?;
+        require_keys_eq!(pending, new_authority, TransferHookError::InvalidTransferHookAccount);
+
         hook_data.authority = new_authority;
+        hook_data.pending_authority = None;
 
         msg!("Updated transfer hook authority to {}", new_authority);
         Ok(())
     }
+
+    /// Cancel a pending transfer hook authority transfer
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        let hook_data = &mut ctx.accounts.hook_data;
+        let pending = hook_data
+            .pending_authority
+            .take()
+            .ok_or(TransferHookError::NoPendingAuthority)?;
+
+        msg!("Cancelled transfer hook authority transfer to {}", pending);
+        Ok(())
+    }
 }
 
 // ============================================
@@ -167,88 +293,117 @@ pub struct InitializeTransferHook<'info> {
 }
 
 #[derive(Accounts)]
-pub struct Pause<'info> {
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: The spl-transfer-hook-interface validation account, written by the handler
     #[account(
-        mut,
-        seeds = [b"transfer_hook", mint.key().as_ref()],
-        bump = hook_data.bump,
-        has_one = authority @ TransferHookError::InvalidTransferHookAccount
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(extra_account_metas()?.len())?,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
     )]
-    pub hook_data: Account<'info, TransferHookData>,
+    pub extra_account_meta_list: UncheckedAccount<'info>,
     
     pub mint: InterfaceAccount<'info, Mint>,
     
-    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
+/// Accounts in the order Token-2022 passes them to the hook:
+/// source, mint, destination, authority, validation account, then `extra_account_metas`
 #[derive(Accounts)]
-pub struct Unpause<'info> {
+pub struct ExecuteTransferHook<'info> {
     #[account(
-        mut,
-        seeds = [b"transfer_hook", mint.key().as_ref()],
-        bump = hook_data.bump,
-        has_one = authority @ TransferHookError::InvalidTransferHookAccount
+        constraint = source_token.mint == mint.key() @ TransferHookError::InvalidMintAccount
     )]
-    pub hook_data: Account<'info, TransferHookData>,
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
     
     pub mint: InterfaceAccount<'info, Mint>,
     
-    pub authority: Signer<'info>,
-}
-
-#[derive(Accounts)]
-pub struct ExtraAccountMetas<'info> {
     #[account(
-        seeds = [b"transfer_hook", mint.key().as_ref()],
-        bump = hook_data.bump
+        constraint = dest_token.mint == mint.key() @ TransferHookError::InvalidMintAccount
     )]
-    pub hook_data: Account<'info, TransferHookData>,
+    pub dest_token: InterfaceAccount<'info, TokenAccount>,
     
-    pub mint: InterfaceAccount<'info, Mint>,
-}
-
-#[derive(Accounts)]
-pub struct ExecuteTransferHook<'info> {
+    /// CHECK: The source owner or delegate that authorized the transfer
+    pub owner: UncheckedAccount<'info>,
+    
+    /// CHECK: The ExtraAccountMetaList validation account
     #[account(
-        seeds = [b"transfer_hook", mint.key().as_ref()],
-        bump = hook_data.bump
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
     )]
-    pub hook_data: Account<'info, TransferHookData>,
+    pub extra_account_meta_list: UncheckedAccount<'info>,
     
-    /// CHECK: The stablecoin program that created the blacklist
+    /// CHECK: The stablecoin program that created the config and blacklist
+    #[account(address = sss_token::ID @ TransferHookError::InvalidTransferHookAccount)]
     pub stablecoin_program: UncheckedAccount<'info>,
     
+    /// CHECK: The stablecoin config (extra account meta) - seeds: ["config", mint.key()] in sss_token
+    /// Source of the pause state enforced on every transfer; decoded by `load_config`
+    /// so configs that have not been migrated yet are still accepted
     #[account(
-        constraint = source_token.mint == hook_data.mint @ TransferHookError::InvalidMintAccount
+        seeds = [b"config", mint.key().as_ref()],
+        bump,
+        seeds::program = sss_token::ID
     )]
-    pub source_token: InterfaceAccount<'info, TokenAccount>,
+    pub config: UncheckedAccount<'info>,
     
+    /// CHECK: Optional account for sender blacklist check - seeds: ["blacklist", config.key(), owner] in sss_token
+    /// If this account holds an unexpired BlacklistEntry, the sender is blacklisted
     #[account(
-        constraint = dest_token.mint == hook_data.mint @ TransferHookError::InvalidMintAccount
+        seeds = [b"blacklist", config.key().as_ref(), source_token.owner.as_ref()],
+        bump,
+        seeds::program = sss_token::ID
     )]
-    pub dest_token: InterfaceAccount<'info, TokenAccount>,
+    pub sender_blacklist: UncheckedAccount<'info>,
     
-    /// CHECK: Optional account for sender blacklist check
-    /// If this account exists and has data, the sender is blacklisted
+    /// CHECK: Optional account for recipient blacklist check - seeds: ["blacklist", config.key(), owner] in sss_token
+    /// If this account holds an unexpired BlacklistEntry, the recipient is blacklisted
     #[account(
-        seeds = [b"blacklist", hook_data.mint.as_ref(), source_token.owner.as_ref()],
-        bump
+        seeds = [b"blacklist", config.key().as_ref(), dest_token.owner.as_ref()],
+        bump,
+        seeds::program = sss_token::ID
     )]
-    pub sender_blacklist: UncheckedAccount<'info>,
+    pub recipient_blacklist: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"transfer_hook", mint.key().as_ref()],
+        bump = hook_data.bump,
+        has_one = authority @ TransferHookError::InvalidTransferHookAccount
+    )]
+    pub hook_data: Account<'info, TransferHookData>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
     
-    /// CHECK: Optional account for recipient blacklist check
-    /// If this account exists and has data, the recipient is blacklisted
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
     #[account(
-        seeds = [b"blacklist", hook_data.mint.as_ref(), dest_token.owner.as_ref()],
-        bump
+        mut,
+        seeds = [b"transfer_hook", mint.key().as_ref()],
+        bump = hook_data.bump
     )]
-    pub recipient_blacklist: UncheckedAccount<'info>,
+    pub hook_data: Account<'info, TransferHookData>,
     
     pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// The proposed authority - must match pending_authority
+    pub new_authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct UpdateAuthority<'info> {
+pub struct CancelAuthorityTransfer<'info> {
     #[account(
         mut,
         seeds = [b"transfer_hook", mint.key().as_ref()],
@@ -259,6 +414,5 @@ pub struct UpdateAuthority<'info> {
     
     pub mint: InterfaceAccount<'info, Mint>,
     
-    #[account(mut)]
     pub authority: Signer<'info>,
 }
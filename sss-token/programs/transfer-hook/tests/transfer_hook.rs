@@ -1,12 +1,9 @@
 use anchor_client::solana_sdk::signature::{Keypair, Signer};
-use anchor_client::solana_sdk::system_program;
-use anchor_client::{Client, Program};
-use std::rc::Rc;
 
 // Note: These are unit-style tests that demonstrate test structure
 // Real integration tests would use ProgramTest framework
 
-fn get_pda(program_id: &str, seeds: &[&[u8]]) -> (solana_sdk::pubkey::Pubkey, u8) {
+fn get_pda(_program_id: &str, _seeds: &[&[u8]]) -> (solana_sdk::pubkey::Pubkey, u8) {
     // For unit tests, we just return a dummy PDA
     // Real tests would compute actual PDA using program ID
     let dummy_pubkey = solana_sdk::pubkey::Pubkey::new_unique();
@@ -41,8 +38,6 @@ fn test_initialize_transfer_hook() {
 
 #[test]
 fn test_pause_and_unpause_hook() {
-    let payer = Keypair::new();
-    
     let mint_keypair = Keypair::new();
     let mint_pubkey = mint_keypair.pubkey();
     
@@ -55,25 +50,15 @@ fn test_pause_and_unpause_hook() {
     println!("Config PDA: {}", config_pda);
     
     // The test would verify:
-    // - Authority can pause the hook
-    // - When paused, all transfers are rejected
-    // - Authority can unpause the hook
+    // - Pauser calls sss_token::pause(PAUSE_TRANSFER)
+    // - When paused, all transfers are rejected by the hook
+    // - Pauser calls sss_token::unpause(PAUSE_TRANSFER)
     // - Transfers resume after unpause
-    // - Only authority can pause/unpause
+    // - The hook reads the pause state from the StablecoinConfig account
 }
 
 #[test]
 fn test_execute_transfer_hook_normal_transfer() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
     let source_owner = Keypair::new();
     let dest_owner = Keypair::new();
     
@@ -93,10 +78,68 @@ fn test_execute_transfer_hook_normal_transfer() {
     // - Transfer completes successfully
 }
 
+#[test]
+fn test_extra_account_metas_resolve_stablecoin_pdas() {
+    use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+    use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+    use solana_sdk::pubkey::Pubkey;
+
+    let mint = Pubkey::new_unique();
+    let source_owner = Pubkey::new_unique();
+    let dest_owner = Pubkey::new_unique();
+
+    // Token accounts store the mint in bytes 0..32 and the owner in bytes 32..64
+    let token_account_data = |owner: &Pubkey| [mint.to_bytes(), owner.to_bytes()].concat();
+    let source_data = token_account_data(&source_owner);
+    let dest_data = token_account_data(&dest_owner);
+
+    let (validation, _) = Pubkey::find_program_address(
+        &[b"extra-account-metas", mint.as_ref()],
+        &transfer_hook::ID,
+    );
+    let mut keys = vec![
+        Pubkey::new_unique(),
+        mint,
+        Pubkey::new_unique(),
+        source_owner,
+        validation,
+    ];
+
+    let metas = transfer_hook::extra_account_metas().unwrap();
+    let mut validation_data = vec![0u8; ExtraAccountMetaList::size_of(metas.len()).unwrap()];
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut validation_data, &metas).unwrap();
+
+    for meta in &metas {
+        let resolved = {
+            let lookup = |index: usize| {
+                let data = match index {
+                    0 => Some(source_data.as_slice()),
+                    2 => Some(dest_data.as_slice()),
+                    _ => None,
+                };
+                keys.get(index).map(|key| (key, data))
+            };
+            meta.resolve(&[], &transfer_hook::ID, lookup).unwrap()
+        };
+        assert!(!resolved.is_signer);
+        assert!(!resolved.is_writable);
+        keys.push(resolved.pubkey);
+    }
+
+    let (config, _) = Pubkey::find_program_address(&[b"config", mint.as_ref()], &sss_token::ID);
+    let (sender_blacklist, _) = Pubkey::find_program_address(
+        &[b"blacklist", config.as_ref(), source_owner.as_ref()],
+        &sss_token::ID,
+    );
+    let (recipient_blacklist, _) = Pubkey::find_program_address(
+        &[b"blacklist", config.as_ref(), dest_owner.as_ref()],
+        &sss_token::ID,
+    );
+    assert_eq!(keys[5..], [sss_token::ID, config, sender_blacklist, recipient_blacklist]);
+}
+
 #[test]
 fn test_execute_transfer_hook_blacklisted_source() {
-    let payer = Keypair::new();
-    
     let mint_keypair = Keypair::new();
     let mint_pubkey = mint_keypair.pubkey();
     
@@ -106,7 +149,6 @@ fn test_execute_transfer_hook_blacklisted_source() {
     );
     
     let blacklisted_owner = Keypair::new();
-    let dest_owner = Keypair::new();
     
     let (blacklist_entry_pda, _blacklist_bump) = get_pda(
         "dummy",
@@ -126,8 +168,6 @@ fn test_execute_transfer_hook_blacklisted_source() {
 
 #[test]
 fn test_execute_transfer_hook_blacklisted_destination() {
-    let payer = Keypair::new();
-    
     let mint_keypair = Keypair::new();
     let mint_pubkey = mint_keypair.pubkey();
     
@@ -136,7 +176,6 @@ fn test_execute_transfer_hook_blacklisted_destination() {
         &[b"config", mint_pubkey.as_ref()],
     );
     
-    let source_owner = Keypair::new();
     let blacklisted_dest = Keypair::new();
     
     let (blacklist_entry_pda, _blacklist_bump) = get_pda(
@@ -156,9 +195,7 @@ fn test_execute_transfer_hook_blacklisted_destination() {
 }
 
 #[test]
-fn test_execute_transfer_hook_paused() {
-    let payer = Keypair::new();
-    
+fn test_execute_transfer_hook_expired_blacklist_entry() {
     let mint_keypair = Keypair::new();
     let mint_pubkey = mint_keypair.pubkey();
     
@@ -167,24 +204,23 @@ fn test_execute_transfer_hook_paused() {
         &[b"config", mint_pubkey.as_ref()],
     );
     
-    let source_owner = Keypair::new();
-    let dest_owner = Keypair::new();
+    let held_owner = Keypair::new();
     
-    println!("Test: Execute transfer hook - paused");
-    println!("Config PDA: {}", config_pda);
+    let (blacklist_entry_pda, _blacklist_bump) = get_pda(
+        "dummy",
+        &[b"blacklist", config_pda.as_ref(), held_owner.pubkey().as_ref()],
+    );
+    
+    println!("Test: Execute transfer hook - expired blacklist entry");
+    println!("Blacklist Entry PDA: {}", blacklist_entry_pda);
     
     // The test would verify:
-    // - When hook is paused, all transfers are rejected
-    // - Transfer is rejected with Paused error
-    // - No tokens are transferred
-    // - Error message indicates pause is active
+    // - Entry with expires_at in the past does not block the transfer
+    // - Transfer succeeds before purge_expired_blacklist_entry is called
 }
 
 #[test]
-fn test_update_hook_authority() {
-    let payer = Keypair::new();
-    let payer_pubkey = payer.pubkey();
-    
+fn test_execute_transfer_hook_paused() {
     let mint_keypair = Keypair::new();
     let mint_pubkey = mint_keypair.pubkey();
     
@@ -193,6 +229,21 @@ fn test_update_hook_authority() {
         &[b"config", mint_pubkey.as_ref()],
     );
     
+    println!("Test: Execute transfer hook - paused");
+    println!("Config PDA: {}", config_pda);
+    
+    // The test would verify:
+    // - When the stablecoin has PAUSE_TRANSFER set, all transfers are rejected
+    // - Transfer is rejected with TransferPaused error
+    // - No tokens are transferred
+    // - Error message indicates pause is active
+}
+
+#[test]
+fn test_update_hook_authority() {
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+    
     let new_authority = Keypair::new();
     
     println!("Test: Update transfer hook authority");
@@ -200,8 +251,9 @@ fn test_update_hook_authority() {
     println!("New authority: {}", new_authority.pubkey());
     
     // The test would verify:
-    // - Only current authority can transfer control
-    // - New authority is set correctly
+    // - Only current authority can propose a new authority
+    // - New authority is only set after it signs accept_authority
+    // - Pending transfer can be cancelled by the current authority
     // - Old authority can no longer manage hook
     // - New authority can now pause/unpause and update config
 }
@@ -231,17 +283,8 @@ fn test_full_transfer_hook_workflow() {
 
 #[test]
 fn test_initialize_hook_unauthorized() {
-    let payer = Keypair::new();
     let unauthorized_user = Keypair::new();
     
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
     println!("Test: Initialize transfer hook by unauthorized user (negative)");
     println!("Unauthorized: {}", unauthorized_user.pubkey());
     println!("Expected error: Unauthorized or signer check failure");
@@ -254,27 +297,11 @@ fn test_initialize_hook_unauthorized() {
 
 #[test]
 fn test_execute_transfer_when_paused() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let source_owner = Keypair::new();
-    let dest_owner = Keypair::new();
-    
-    let source_token = Keypair::new();
-    let dest_token = Keypair::new();
-    
     println!("Test: Execute transfer when hook is paused (negative)");
     println!("Expected error: Paused");
     
     // The test would verify:
-    // - Pre-condition: Transfer hook is paused
+    // - Pre-condition: Stablecoin is paused with PAUSE_TRANSFER
     // - Transfer is attempted
     // - Transfer fails with Paused error
     // - No tokens are transferred
@@ -282,23 +309,7 @@ fn test_execute_transfer_when_paused() {
 
 #[test]
 fn test_execute_transfer_blacklisted_source() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
     let blacklisted_owner = Keypair::new();
-    let dest_owner = Keypair::new();
-    
-    let (blacklist_entry_pda, _blacklist_bump) = get_pda(
-        "dummy",
-        &[b"blacklist", config_pda.as_ref(), blacklisted_owner.pubkey().as_ref()],
-    );
     
     println!("Test: Execute transfer from blacklisted source (negative)");
     println!("Blacklisted Source: {}", blacklisted_owner.pubkey());
@@ -313,24 +324,8 @@ fn test_execute_transfer_blacklisted_source() {
 
 #[test]
 fn test_execute_transfer_blacklisted_destination() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let source_owner = Keypair::new();
     let blacklisted_dest = Keypair::new();
     
-    let (blacklist_entry_pda, _blacklist_bump) = get_pda(
-        "dummy",
-        &[b"blacklist", config_pda.as_ref(), blacklisted_dest.pubkey().as_ref()],
-    );
-    
     println!("Test: Execute transfer to blacklisted destination (negative)");
     println!("Blacklisted Destination: {}", blacklisted_dest.pubkey());
     println!("Expected error: Blacklisted / AccountFrozen");
@@ -344,16 +339,6 @@ fn test_execute_transfer_blacklisted_destination() {
 
 #[test]
 fn test_pause_hook_by_unauthorized() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
     let unauthorized_user = Keypair::new();
     
     println!("Test: Pause transfer hook by unauthorized user (negative)");
@@ -361,23 +346,13 @@ fn test_pause_hook_by_unauthorized() {
     println!("Expected error: Unauthorized");
     
     // The test would verify:
-    // - Non-authority attempts to pause hook
+    // - Non-pauser attempts sss_token::pause(PAUSE_TRANSFER)
     // - Transaction fails with Unauthorized error
-    // - Hook remains unpaused
+    // - Transfers through the hook remain unpaused
 }
 
 #[test]
 fn test_unpause_hook_by_unauthorized() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
     let unauthorized_user = Keypair::new();
     
     println!("Test: Unpause transfer hook by unauthorized user (negative)");
@@ -385,10 +360,10 @@ fn test_unpause_hook_by_unauthorized() {
     println!("Expected error: Unauthorized");
     
     // The test would verify:
-    // - Pre-condition: Hook is paused
-    // - Non-authority attempts to unpause hook
+    // - Pre-condition: Stablecoin is paused with PAUSE_TRANSFER
+    // - Non-pauser attempts sss_token::unpause(PAUSE_TRANSFER)
     // - Transaction fails with Unauthorized error
-    // - Hook remains paused
+    // - Transfers through the hook remain paused
 }
 
 #[test]
@@ -396,16 +371,7 @@ fn test_update_hook_authority_by_unauthorized() {
     let payer = Keypair::new();
     let payer_pubkey = payer.pubkey();
     
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
     let unauthorized_user = Keypair::new();
-    let new_authority = Keypair::new();
     
     println!("Test: Update hook authority by unauthorized user (negative)");
     println!("Current authority: {}", payer_pubkey);
@@ -420,21 +386,7 @@ fn test_update_hook_authority_by_unauthorized() {
 
 #[test]
 fn test_execute_transfer_frozen_source_account() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let source_owner = Keypair::new();
-    let dest_owner = Keypair::new();
-    
     let source_token = Keypair::new();
-    let dest_token = Keypair::new();
     
     println!("Test: Execute transfer from frozen source account (negative)");
     println!("Source Token Account: {}", source_token.pubkey());
@@ -449,20 +401,6 @@ fn test_execute_transfer_frozen_source_account() {
 
 #[test]
 fn test_execute_transfer_frozen_destination_account() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let source_owner = Keypair::new();
-    let dest_owner = Keypair::new();
-    
-    let source_token = Keypair::new();
     let dest_token = Keypair::new();
     
     println!("Test: Execute transfer to frozen destination account (negative)");
@@ -478,22 +416,6 @@ fn test_execute_transfer_frozen_destination_account() {
 
 #[test]
 fn test_execute_transfer_insufficient_balance() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let source_owner = Keypair::new();
-    let dest_owner = Keypair::new();
-    
-    let source_token = Keypair::new();
-    let dest_token = Keypair::new();
-    
     println!("Test: Execute transfer with insufficient balance (negative)");
     println!("Expected error: InsufficientFunds (SPL Token)");
     
@@ -506,19 +428,6 @@ fn test_execute_transfer_insufficient_balance() {
 
 #[test]
 fn test_execute_transfer_wrong_mint() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let wrong_mint_keypair = Keypair::new();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_keypair.pubkey().as_ref()],
-    );
-    
-    let source_owner = Keypair::new();
-    let dest_owner = Keypair::new();
-    
     println!("Test: Execute transfer with wrong mint (negative)");
     println!("Expected error: InvalidMint / AccountMismatch");
     
@@ -530,17 +439,6 @@ fn test_execute_transfer_wrong_mint() {
 
 #[test]
 fn test_initialize_hook_twice() {
-    let payer = Keypair::new();
-    let payer_pubkey = payer.pubkey();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
     println!("Test: Initialize transfer hook twice (negative)");
     println!("Expected error: Account already exists (Anchor)");
     
@@ -552,61 +450,28 @@ fn test_initialize_hook_twice() {
 
 #[test]
 fn test_double_pause() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
     println!("Test: Double pause transfer hook (negative)");
     println!("Expected error: AlreadyPaused (if implemented) or no-op");
     
     // The test would verify:
-    // - Pre-condition: Hook is already paused
+    // - Pre-condition: PAUSE_TRANSFER is already set
     // - Attempt to pause again
-    // - Either fails with AlreadyPaused or succeeds as no-op
+    // - Succeeds as no-op (bit stays set)
 }
 
 #[test]
 fn test_double_unpause() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
     println!("Test: Double unpause transfer hook (negative)");
     println!("Expected error: NotPaused (if implemented) or no-op");
     
     // The test would verify:
-    // - Pre-condition: Hook is already unpaused
+    // - Pre-condition: PAUSE_TRANSFER is already clear
     // - Attempt to unpause again
-    // - Either fails with NotPaused or succeeds as no-op
+    // - Succeeds as no-op (bit stays clear)
 }
 
 #[test]
 fn test_transfer_self_to_self() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let owner = Keypair::new();
-    let token_account = Keypair::new();
-    
     println!("Test: Transfer from account to same account (negative)");
     println!("Expected error: SameSourceAndDestination or no-op");
     
@@ -618,19 +483,6 @@ fn test_transfer_self_to_self() {
 
 #[test]
 fn test_execute_transfer_zero_amount() {
-    let payer = Keypair::new();
-    
-    let mint_keypair = Keypair::new();
-    let mint_pubkey = mint_keypair.pubkey();
-    
-    let (config_pda, _config_bump) = get_pda(
-        "dummy",
-        &[b"config", mint_pubkey.as_ref()],
-    );
-    
-    let source_owner = Keypair::new();
-    let dest_owner = Keypair::new();
-    
     println!("Test: Execute transfer with zero amount (negative)");
     println!("Expected error: InvalidAmount or succeeds");
     
@@ -20,7 +20,8 @@ fn test_config_pda_derivation() {
         &sss_token::ID,
     );
     
-    assert!(bump <= 255);
+    let rederived = Pubkey::create_program_address(&[b"config", mint.as_ref(), &[bump]], &sss_token::ID).unwrap();
+    assert_eq!(rederived, config_pda);
     println!("✓ Config PDA derivation: config = {}, bump = {}", config_pda, bump);
 }
 
@@ -36,10 +37,32 @@ fn test_blacklist_pda_derivation() {
         &sss_token::ID,
     );
     
-    assert!(bump <= 255);
+    let rederived = Pubkey::create_program_address(&[b"blacklist", config.as_ref(), user.as_ref(), &[bump]], &sss_token::ID).unwrap();
+    assert_eq!(rederived, blacklist_pda);
     println!("✓ Blacklist PDA derivation: entry = {}, bump = {}", blacklist_pda, bump);
 }
 
+#[test]
+fn test_hook_blacklist_pda_matches_sss_token() {
+    // The hook checks blacklist entries created by sss_token::add_to_blacklist,
+    // so both must derive the same address: ["blacklist", config, owner] under sss_token
+    let mint = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    let (config_pda, _) = Pubkey::find_program_address(&[b"config", mint.as_ref()], &sss_token::ID);
+    let (sss_entry, _) = Pubkey::find_program_address(
+        &[b"blacklist", config_pda.as_ref(), owner.as_ref()],
+        &sss_token::ID,
+    );
+    let (mint_seeded_entry, _) = Pubkey::find_program_address(
+        &[b"blacklist", mint.as_ref(), owner.as_ref()],
+        &transfer_hook::ID,
+    );
+
+    assert_ne!(sss_entry, mint_seeded_entry);
+    println!("✓ Hook blacklist PDA: entry = {}", sss_entry);
+}
+
 #[test]
 fn test_transfer_hook_pda_derivation() {
     // Test transfer hook data PDA derivation
@@ -51,10 +74,19 @@ fn test_transfer_hook_pda_derivation() {
         &transfer_hook::ID,
     );
     
-    assert!(bump <= 255);
+    let rederived = Pubkey::create_program_address(&[b"transfer_hook", mint.as_ref(), &[bump]], &transfer_hook::ID).unwrap();
+    assert_eq!(rederived, hook_data_pda);
     println!("✓ Transfer hook PDA derivation: hook_data = {}, bump = {}", hook_data_pda, bump);
 }
 
+#[test]
+fn test_mint_transfer_hook_points_at_hook_program() {
+    // SSS-2 mints created by sss_token::initialize point their TransferHook
+    // extension at this program
+    assert_eq!(sss_token::TRANSFER_HOOK_PROGRAM_ID, transfer_hook::ID);
+    println!("✓ Transfer hook program ID: {}", transfer_hook::ID);
+}
+
 // ============================================
 // KEYPAIR GENERATION TESTS
 // ============================================
@@ -98,7 +130,7 @@ fn test_authority_roles() {
     let seizer = Keypair::new();
     
     // Verify all roles have unique pubkeys
-    let pubkeys = vec![
+    let pubkeys = [
         master_authority.pubkey(),
         minter.pubkey(),
         pauser.pubkey(),
@@ -258,7 +290,6 @@ fn test_paused_transfer_structure() {
 
 #[test]
 fn test_multi_transfer_sequence() {
-    let payer = Keypair::new();
     let mint = Keypair::new();
     
     let (config_pda, _bump) = Pubkey::find_program_address(
@@ -290,14 +321,6 @@ fn test_multi_transfer_sequence() {
 
 #[test]
 fn test_large_amount_transfer() {
-    let payer = Keypair::new();
-    let mint = Keypair::new();
-    
-    let (config_pda, _bump) = Pubkey::find_program_address(
-        &[b"config", mint.pubkey().as_ref()],
-        &sss_token::ID,
-    );
-    
     let source_owner = Keypair::new();
     let dest_owner = Keypair::new();
     
@@ -318,14 +341,6 @@ fn test_large_amount_transfer() {
 
 #[test]
 fn test_concurrent_transfer_validation() {
-    let payer = Keypair::new();
-    let mint = Keypair::new();
-    
-    let (config_pda, _bump) = Pubkey::find_program_address(
-        &[b"config", mint.pubkey().as_ref()],
-        &sss_token::ID,
-    );
-    
     // Simulate concurrent transfer scenario
     let sender1 = Keypair::new();
     let sender2 = Keypair::new();
@@ -378,15 +393,9 @@ fn test_blacklist_add_remove_cycle() {
 #[test]
 fn test_transfer_hook_with_frozen_account() {
     let authority = Keypair::new();
-    let mint = Keypair::new();
     let source_owner = Keypair::new();
     let dest_owner = Keypair::new();
     
-    let (config_pda, _bump) = Pubkey::find_program_address(
-        &[b"config", mint.pubkey().as_ref()],
-        &sss_token::ID,
-    );
-    
     println!("Test: Transfer hook with frozen account");
     println!("  Authority: {}", authority.pubkey());
     println!("  Source Owner: {}", source_owner.pubkey());
@@ -425,9 +434,6 @@ fn test_authority_transfer_workflow() {
 
 #[test]
 fn test_decimal_precision_transfer() {
-    let payer = Keypair::new();
-    let mint = Keypair::new();
-    
     let source_owner = Keypair::new();
     let dest_owner = Keypair::new();
     
@@ -506,12 +512,6 @@ fn test_transfer_to_self() {
 fn test_zero_amount_transfer() {
     let source_owner = Keypair::new();
     let dest_owner = Keypair::new();
-    let mint = Keypair::new();
-    
-    let (config_pda, _bump) = Pubkey::find_program_address(
-        &[b"config", mint.pubkey().as_ref()],
-        &sss_token::ID,
-    );
     
     println!("Test: Zero amount transfer");
     println!("  Source: {}", source_owner.pubkey());
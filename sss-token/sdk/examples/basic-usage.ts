@@ -7,7 +7,6 @@ import {
   SSSTokenClient, 
   AnchorProvider,
   createTokenAccount,
-  createTokenMint,
   findConfigPDA
 } from '../src/index';
 import { Keypair, Connection, LAMPORTS_PER_SOL, Transaction } from '@solana/web3.js';
@@ -68,27 +67,22 @@ async function main() {
   console.log('  Pauser:', pauser.publicKey.toString());
   console.log('  Seizer:', seizer.publicKey.toString());
 
-  // 3. Create token mint with freeze authority
-  console.log('\nCreating token mint...');
-  const mint = await createTokenMint(
-    connection,
-    authority,
-    authority.publicKey, // mint authority
-    seizer.publicKey, // freeze authority (for compliance)
-    6 // decimals
-  );
-  console.log('Mint created:', mint.toString());
+  // 3. Generate the mint keypair (the program creates the mint and owns its authorities)
+  const mintKeypair = Keypair.generate();
+  const mint = mintKeypair.publicKey;
+  console.log('Mint address:', mint.toString());
 
   // 4. Initialize stablecoin with SSS-2 features
   console.log('\nInitializing stablecoin...');
-  const initTx = await sdk.initialize(mint, authority, {
+  const initTx = await sdk.initialize(mintKeypair, authority, {
     name: "Example Stablecoin",
     symbol: "EXST",
     uri: "https://example.com/exst.json",
     decimals: 6,
     enablePermanentDelegate: true, // Enable token seizure
     enableTransferHook: true, // Enable compliance checking
-    defaultAccountFrozen: false
+    defaultAccountFrozen: false,
+    maxSupply: new BN(0) // Uncapped
   });
   console.log('Initialize transaction:', initTx);
 
@@ -142,8 +136,7 @@ async function main() {
   const mintAmount = new BN(1_000_000); // 1 token
   const mintTx = await sdk.mintTokens(
     mint,
-    authority,
-    minter.publicKey,
+    minter,
     userTokenAccount,
     { amount: mintAmount }
   );
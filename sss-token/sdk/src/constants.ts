@@ -7,15 +7,23 @@
  */
 export const SSS_TOKEN_PROGRAM_ID = "Hf1s4EvjS79S6kcHdKhaZHVQsnsjqMbJgBEFZfaGDPmw";
 
+/**
+ * The transfer hook program ID (set on SSS-2 mints)
+ */
+export const TRANSFER_HOOK_PROGRAM_ID = "8Zaw1qJer6QPw15yg2bBgkAEB5rP73QisHVv6djANpSB";
+
 /**
  * Seed values for PDA derivation
  */
 export const PDA_SEEDS = {
   CONFIG: "config",
+  MINT_AUTHORITY: "mint_authority",
   MINTER: "minter",
   BLACKLIST: "blacklist",
   PERMANENT_DELEGATE: "permanent_delegate",
   FREEZE_AUTHORITY: "freeze_authority",
+  RESERVES: "reserves",
+  EXTRA_ACCOUNT_METAS: "extra-account-metas",
 } as const;
 
 /**
@@ -11,7 +11,8 @@
  * const provider = AnchorProvider.env();
  * const sdk = new SSSTokenClient({ provider });
  * 
- * // Initialize stablecoin
+ * // Initialize stablecoin (the program creates the mint, so it signs)
+ * const mint = Keypair.generate();
  * await sdk.initialize(mint, authority, {
  *   name: "My Stablecoin",
  *   symbol: "MYST",
@@ -19,7 +20,8 @@
  *   decimals: 6,
  *   enablePermanentDelegate: true,
  *   enableTransferHook: true,
- *   defaultAccountFrozen: false
+ *   defaultAccountFrozen: false,
+ *   maxSupply: new BN(0) // uncapped
  * });
  * ```
  */
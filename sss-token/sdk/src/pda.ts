@@ -3,7 +3,7 @@
  */
 
 import { PublicKey } from "@solana/web3.js";
-import { PDA_SEEDS, SSS_TOKEN_PROGRAM_ID } from "./constants";
+import { PDA_SEEDS, SSS_TOKEN_PROGRAM_ID, TRANSFER_HOOK_PROGRAM_ID } from "./constants";
 import { PDAResult } from "./types";
 
 /**
@@ -23,6 +23,24 @@ export function findConfigPDA(
   return { pda, bump };
 }
 
+/**
+ * Find the Mint Authority PDA for a given mint
+ * This PDA is the mint authority of every stablecoin mint
+ * @param mint - The mint public key
+ * @param programId - The program ID (defaults to SSS_TOKEN_PROGRAM_ID)
+ * @returns The mint authority PDA and bump
+ */
+export function findMintAuthorityPDA(
+  mint: PublicKey,
+  programId: PublicKey = new PublicKey(SSS_TOKEN_PROGRAM_ID)
+): PDAResult {
+  const [pda, bump] = PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.MINT_AUTHORITY), mint.toBuffer()],
+    programId
+  );
+  return { pda, bump };
+}
+
 /**
  * Find the MinterInfo PDA for a given config and minter
  * @param config - The config PDA
@@ -97,6 +115,41 @@ export function findFreezeAuthorityPDA(
   return { pda, bump };
 }
 
+/**
+ * Find the Reserve Attestation PDA for a given config
+ * @param config - The config PDA
+ * @param programId - The program ID (defaults to SSS_TOKEN_PROGRAM_ID)
+ * @returns The reserve attestation PDA and bump
+ */
+export function findReserveAttestationPDA(
+  config: PublicKey,
+  programId: PublicKey = new PublicKey(SSS_TOKEN_PROGRAM_ID)
+): PDAResult {
+  const [pda, bump] = PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.RESERVES), config.toBuffer()],
+    programId
+  );
+  return { pda, bump };
+}
+
+/**
+ * Find the transfer hook ExtraAccountMetaList PDA for a given mint
+ * Token-2022 reads the accounts the hook needs on every transfer from this account
+ * @param mint - The mint public key
+ * @param hookProgramId - The transfer hook program ID (defaults to TRANSFER_HOOK_PROGRAM_ID)
+ * @returns The extra account meta list PDA and bump
+ */
+export function findExtraAccountMetaListPDA(
+  mint: PublicKey,
+  hookProgramId: PublicKey = new PublicKey(TRANSFER_HOOK_PROGRAM_ID)
+): PDAResult {
+  const [pda, bump] = PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.EXTRA_ACCOUNT_METAS), mint.toBuffer()],
+    hookProgramId
+  );
+  return { pda, bump };
+}
+
 /**
  * Find all PDAs for a stablecoin configuration
  * @param mint - The mint public key
@@ -6,6 +6,7 @@ import {
   PublicKey,
   Signer,
   SystemProgram,
+  TransactionInstruction,
 } from "@solana/web3.js";
 import {
   getAssociatedTokenAddress,
@@ -14,7 +15,11 @@ import {
 import { Program, AnchorProvider, Wallet } from "@coral-xyz/anchor";
 import BN from "bn.js";
 import idl from "./idl.json";
-import { SSS_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID as TOKEN_2022_PROGRAM_ID_STR } from "./constants";
+import {
+  SSS_TOKEN_PROGRAM_ID,
+  TRANSFER_HOOK_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID as TOKEN_2022_PROGRAM_ID_STR,
+} from "./constants";
 import {
   SSSTokenSDKConfig,
   StablecoinConfig,
@@ -32,7 +37,23 @@ import {
   SeizeParams,
   TransferAuthorityParams,
 } from "./types";
-import { findConfigPDA, findMinterInfoPDA, findBlacklistEntryPDA, findPermanentDelegatePDA, findFreezeAuthorityPDA } from "./pda";
+import {
+  findConfigPDA,
+  findMintAuthorityPDA,
+  findMinterInfoPDA,
+  findBlacklistEntryPDA,
+  findPermanentDelegatePDA,
+  findFreezeAuthorityPDA,
+  findReserveAttestationPDA,
+  findExtraAccountMetaListPDA,
+} from "./pda";
+
+/**
+ * Anchor discriminator of the transfer hook's initialize_extra_account_meta_list instruction
+ */
+const INITIALIZE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR = Buffer.from([
+  92, 197, 174, 197, 41, 124, 19, 3,
+]);
 
 /**
  * SSS Token SDK Client
@@ -60,13 +81,23 @@ export class SSSTokenClient {
 
   /**
    * Initialize a new stablecoin
+   * The program creates the mint, so `mint` must be a fresh keypair that signs.
+   * SSS-2 mints also get their transfer hook ExtraAccountMetaList in the same transaction.
    */
   async initialize(
-    mint: PublicKey,
+    mint: Signer,
     authority: Signer,
     params: InitializeParams
   ): Promise<string> {
-    const { pda: configPda } = findConfigPDA(mint, this.programId);
+    const mintKey = mint.publicKey;
+    const { pda: configPda } = findConfigPDA(mintKey, this.programId);
+    const { pda: mintAuthorityPda } = findMintAuthorityPDA(mintKey, this.programId);
+    const { pda: freezeAuthorityPda } = findFreezeAuthorityPDA(mintKey, this.programId);
+    const { pda: permanentDelegatePda } = findPermanentDelegatePDA(mintKey, this.programId);
+
+    const postInstructions = params.enableTransferHook
+      ? [this.initializeExtraAccountMetaListInstruction(mintKey, authority.publicKey)]
+      : [];
 
     const tx = await this.program.methods
       .initialize(
@@ -76,46 +107,79 @@ export class SSSTokenClient {
         params.decimals,
         params.enablePermanentDelegate,
         params.enableTransferHook,
-        params.defaultAccountFrozen
+        params.defaultAccountFrozen,
+        params.maxSupply ?? new BN(0)
       )
       .accounts({
         config: configPda,
-        mint: mint,
+        mint: mintKey,
         authority: authority.publicKey,
+        mintAuthority: mintAuthorityPda,
+        freezeAuthority: freezeAuthorityPda,
+        permanentDelegate: permanentDelegatePda,
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
       })
-      .signers([authority])
+      .postInstructions(postInstructions)
+      .signers([mint, authority])
       .rpc();
 
     return tx;
   }
 
+  /**
+   * Build the transfer hook instruction that creates the mint's ExtraAccountMetaList
+   * Token-2022 transfers of SSS-2 mints fail until this account exists
+   */
+  initializeExtraAccountMetaListInstruction(
+    mint: PublicKey,
+    payer: PublicKey
+  ): TransactionInstruction {
+    const hookProgramId = new PublicKey(TRANSFER_HOOK_PROGRAM_ID);
+    const { pda: extraAccountMetaListPda } = findExtraAccountMetaListPDA(mint, hookProgramId);
+
+    return new TransactionInstruction({
+      programId: hookProgramId,
+      keys: [
+        { pubkey: payer, isSigner: true, isWritable: true },
+        { pubkey: extraAccountMetaListPda, isSigner: false, isWritable: true },
+        { pubkey: mint, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      ],
+      data: INITIALIZE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR,
+    });
+  }
+
   /**
    * Mint tokens to a recipient account
+   * The mint authority is a program PDA; the minter signs. The reserve
+   * attestation is passed when one has been published for this stablecoin.
    */
   async mintTokens(
     mint: PublicKey,
-    mintAuthority: Signer,
-    minter: PublicKey,
+    minter: Signer,
     tokenAccount: PublicKey,
     params: MintTokensParams
   ): Promise<string> {
     const { pda: configPda } = findConfigPDA(mint, this.programId);
-    const { pda: minterInfoPda } = findMinterInfoPDA(configPda, minter, this.programId);
+    const { pda: mintAuthorityPda } = findMintAuthorityPDA(mint, this.programId);
+    const { pda: minterInfoPda } = findMinterInfoPDA(configPda, minter.publicKey, this.programId);
+    const { pda: reserveAttestationPda } = findReserveAttestationPDA(configPda, this.programId);
+    const reserveAttestation = await this.connection.getAccountInfo(reserveAttestationPda);
 
     const tx = await this.program.methods
       .mintTokens(params.amount)
       .accounts({
         config: configPda,
         mint: mint,
-        mintAuthority: mintAuthority.publicKey,
-        minter: minter,
+        mintAuthority: mintAuthorityPda,
         minterInfo: minterInfoPda,
+        minter: minter.publicKey,
         tokenAccount: tokenAccount,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
+        reserveAttestation: reserveAttestation ? reserveAttestationPda : null,
       })
-      .signers([mintAuthority])
+      .signers([minter])
       .rpc();
 
     return tx;
@@ -148,17 +212,19 @@ export class SSSTokenClient {
   }
 
   /**
-   * Freeze a token account using the freeze authority keypair
+   * Freeze a token account
+   * Same as freezeTokenAccountPda: the mint's freeze authority is always the freeze authority PDA
    * @param mint - The mint public key
    * @param tokenAccount - The token account to freeze
-   * @param freezeAuthority - The freeze authority signer (must match mint's freeze authority)
+   * @param seizer - The seizer signer (must be authorized in config.seizer role)
    */
   async freezeTokenAccount(
     mint: PublicKey,
     tokenAccount: PublicKey,
-    freezeAuthority: Signer
+    seizer: Signer
   ): Promise<string> {
     const { pda: configPda } = findConfigPDA(mint, this.programId);
+    const { pda: freezeAuthorityPda } = findFreezeAuthorityPDA(mint, this.programId);
 
     const tx = await this.program.methods
       .freezeTokenAccount()
@@ -166,27 +232,30 @@ export class SSSTokenClient {
         config: configPda,
         mint: mint,
         tokenAccount: tokenAccount,
-        freezeAuthority: freezeAuthority.publicKey,
+        seizer: seizer.publicKey,
+        freezeAuthority: freezeAuthorityPda,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
       })
-      .signers([freezeAuthority])
+      .signers([seizer])
       .rpc();
 
     return tx;
   }
 
   /**
-   * Thaw (unfreeze) a token account using the freeze authority keypair
+   * Thaw (unfreeze) a token account
+   * Same as thawTokenAccountPda: the mint's freeze authority is always the freeze authority PDA
    * @param mint - The mint public key
    * @param tokenAccount - The token account to thaw
-   * @param freezeAuthority - The freeze authority signer (must match mint's freeze authority)
+   * @param seizer - The seizer signer (must be authorized in config.seizer role)
    */
   async thawTokenAccount(
     mint: PublicKey,
     tokenAccount: PublicKey,
-    freezeAuthority: Signer
+    seizer: Signer
   ): Promise<string> {
     const { pda: configPda } = findConfigPDA(mint, this.programId);
+    const { pda: freezeAuthorityPda } = findFreezeAuthorityPDA(mint, this.programId);
 
     const tx = await this.program.methods
       .thawTokenAccount()
@@ -194,10 +263,11 @@ export class SSSTokenClient {
         config: configPda,
         mint: mint,
         tokenAccount: tokenAccount,
-        freezeAuthority: freezeAuthority.publicKey,
+        seizer: seizer.publicKey,
+        freezeAuthority: freezeAuthorityPda,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
       })
-      .signers([freezeAuthority])
+      .signers([seizer])
       .rpc();
 
     return tx;
@@ -63,6 +63,8 @@ export interface CreateStablecoinOptions {
   decimals?: number;
   /** Override preset configuration */
   overrideConfig?: Partial<PresetConfig>;
+  /** Global supply cap in base units (default: uncapped) */
+  maxSupply?: BN;
 }
 
 /**
@@ -128,25 +130,25 @@ export class ComplianceAPI {
   /**
    * Freeze a token account
    * @param tokenAccount - The token account to freeze
-   * @param freezeAuthority - The freeze authority signer
+   * @param seizer - The seizer signer
    */
   async freeze(
     tokenAccount: PublicKey,
-    freezeAuthority: Signer
+    seizer: Signer
   ): Promise<string> {
-    return this.client.freezeTokenAccount(this.mint, tokenAccount, freezeAuthority);
+    return this.client.freezeTokenAccount(this.mint, tokenAccount, seizer);
   }
 
   /**
    * Thaw (unfreeze) a token account
    * @param tokenAccount - The token account to thaw
-   * @param freezeAuthority - The freeze authority signer
+   * @param seizer - The seizer signer
    */
   async thaw(
     tokenAccount: PublicKey,
-    freezeAuthority: Signer
+    seizer: Signer
   ): Promise<string> {
-    return this.client.thawTokenAccount(this.mint, tokenAccount, freezeAuthority);
+    return this.client.thawTokenAccount(this.mint, tokenAccount, seizer);
   }
 
   /**
@@ -236,18 +238,16 @@ export class MintingAPI {
 
   /**
    * Mint tokens to an account
-   * @param mintAuthority - The mint authority signer
-   * @param minter - The minter address (for quota tracking)
+   * @param minter - The minter signer (for quota tracking)
    * @param tokenAccount - Destination token account
    * @param amount - Amount to mint
    */
   async mintTokens(
-    mintAuthority: Signer,
-    minter: PublicKey,
+    minter: Signer,
     tokenAccount: PublicKey,
     amount: BN | number
   ): Promise<string> {
-    return this.client.mintTokens(this.mintAddress, mintAuthority, minter, tokenAccount, {
+    return this.client.mintTokens(this.mintAddress, minter, tokenAccount, {
       amount: typeof amount === "number" ? new BN(amount) : amount,
     });
   }
@@ -460,10 +460,11 @@ export class SolanaStablecoin {
       enablePermanentDelegate: config.enablePermanentDelegate,
       enableTransferHook: config.enableTransferHook,
       defaultAccountFrozen: config.defaultAccountFrozen,
+      maxSupply: options.maxSupply,
     };
     
     // Initialize the stablecoin
-    await client.initialize(mintKeypair.publicKey, authority as any, initParams);
+    await client.initialize(mintKeypair, authority as any, initParams);
     
     return new SolanaStablecoin(client, mintKeypair.publicKey);
   }
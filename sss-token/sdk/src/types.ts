@@ -108,6 +108,8 @@ export interface InitializeParams {
   enablePermanentDelegate: boolean;
   enableTransferHook: boolean;
   defaultAccountFrozen: boolean;
+  /** Global supply cap in base units (0 or omitted = uncapped) */
+  maxSupply?: BN;
 }
 
 /**
@@ -208,8 +210,11 @@ export interface PDAResult {
  */
 export interface InitializeAccounts {
   config: PublicKey;
-  mint: PublicKey;
+  mint: Signer;
   authority: Signer;
+  mintAuthority: PublicKey;
+  freezeAuthority: PublicKey;
+  permanentDelegate: PublicKey;
 }
 
 /**
@@ -218,10 +223,11 @@ export interface InitializeAccounts {
 export interface MintTokensAccounts {
   config: PublicKey;
   mint: PublicKey;
-  mintAuthority: Signer;
-  minter: PublicKey;
+  mintAuthority: PublicKey;
+  minter: Signer;
   minterInfo: PublicKey;
   tokenAccount: PublicKey;
+  reserveAttestation: PublicKey | null;
 }
 
 /**
@@ -241,7 +247,8 @@ export interface FreezeTokenAccountAccounts {
   config: PublicKey;
   mint: PublicKey;
   tokenAccount: PublicKey;
-  freezeAuthority: Signer;
+  seizer: Signer;
+  freezeAuthority: PublicKey;
 }
 
 /**
@@ -251,7 +258,8 @@ export interface ThawTokenAccountAccounts {
   config: PublicKey;
   mint: PublicKey;
   tokenAccount: PublicKey;
-  freezeAuthority: Signer;
+  seizer: Signer;
+  freezeAuthority: PublicKey;
 }
 
 /**
@@ -4,16 +4,11 @@
  */
 
 import { expect } from "chai";
-import { Keypair, PublicKey, Connection, LAMPORTS_PER_SOL, Transaction, SystemProgram, sendAndConfirmTransaction } from "@solana/web3.js";
+import { Keypair, PublicKey, Connection, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import {
-  createMint,
   getAccount,
   getOrCreateAssociatedTokenAccount,
   TOKEN_2022_PROGRAM_ID,
-  ExtensionType,
-  getMintLen,
-  createInitializePermanentDelegateInstruction,
-  createInitializeMintInstruction,
   getMint,
 } from "@solana/spl-token";
 import { AnchorProvider, Wallet } from "@coral-xyz/anchor";
@@ -24,72 +19,9 @@ import {
   PRESET_CONFIGS,
   SSSTokenClient,
   findPermanentDelegatePDA,
-  findFreezeAuthorityPDA,
   SSS_TOKEN_PROGRAM_ID,
 } from "../src/index";
 
-/**
- * Helper function to create a Token-2022 mint with the PermanentDelegate extension
- * This allows the program's permanent delegate PDA to transfer tokens from any account
- * 
- * IMPORTANT: The freeze authority is set to the program's freeze authority PDA,
- * which allows the seize instruction to thaw frozen accounts using PDA signing.
- */
-async function createMintWithPermanentDelegate(
-  connection: Connection,
-  payer: Keypair,
-  mintAuthority: PublicKey,
-  decimals: number,
-  programId: PublicKey
-): Promise<PublicKey> {
-  // Generate mint keypair first to derive PDAs
-  const mintKeypair = Keypair.generate();
-  
-  // Derive both PDAs from the mint address
-  const { pda: permanentDelegate } = findPermanentDelegatePDA(mintKeypair.publicKey, programId);
-  const { pda: freezeAuthority } = findFreezeAuthorityPDA(mintKeypair.publicKey, programId);
-
-  // Calculate mint size with PermanentDelegate extension
-  const extensions = [ExtensionType.PermanentDelegate];
-  const mintLen = getMintLen(extensions);
-
-  // Calculate minimum balance for rent exemption
-  const lamports = await connection.getMinimumBalanceForRentExemption(mintLen);
-
-  // Build transaction to create mint with extension
-  const transaction = new Transaction().add(
-    SystemProgram.createAccount({
-      fromPubkey: payer.publicKey,
-      newAccountPubkey: mintKeypair.publicKey,
-      space: mintLen,
-      lamports,
-      programId: TOKEN_2022_PROGRAM_ID,
-    }),
-    createInitializePermanentDelegateInstruction(
-      mintKeypair.publicKey,
-      permanentDelegate, // Our program's PDA as the permanent delegate
-      TOKEN_2022_PROGRAM_ID
-    ),
-    createInitializeMintInstruction(
-      mintKeypair.publicKey,
-      decimals,
-      mintAuthority,
-      freezeAuthority, // Program's PDA as freeze authority (for seize operation)
-      TOKEN_2022_PROGRAM_ID
-    )
-  );
-
-  await sendAndConfirmTransaction(connection, transaction, [payer, mintKeypair], {
-    commitment: "confirmed",
-  });
-
-  console.log("Created mint with permanent delegate:", mintKeypair.publicKey.toString());
-  console.log("  Permanent delegate PDA:", permanentDelegate.toString());
-  console.log("  Freeze authority PDA:", freezeAuthority.toString());
-  
-  return mintKeypair.publicKey;
-}
-
 describe("SolanaStablecoin Enhanced SDK Tests", function () {
   this.timeout(100000);
 
@@ -165,19 +97,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
 
     before(async () => {
       // Create and initialize a mint using the low-level API
-      mint = await createMint(
-        connection,
-        payer,
-        authority.publicKey,
-        authority.publicKey,
-        6,
-        undefined,
-        undefined,
-        TOKEN_2022_PROGRAM_ID
-      );
+      const mintKeypair = Keypair.generate();
+      mint = mintKeypair.publicKey;
 
       const client = new SSSTokenClient({ provider });
-      const initTx = await client.initialize(mint, authority, {
+      const initTx = await client.initialize(mintKeypair, authority, {
         name: "Connect Test Stablecoin",
         symbol: "CONN",
         uri: "https://example.com/connect.json",
@@ -221,19 +145,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
 
     before(async () => {
       // Create mint with compliance features
-      mint = await createMint(
-        connection,
-        payer,
-        authority.publicKey,
-        authority.publicKey,
-        6,
-        undefined,
-        undefined,
-        TOKEN_2022_PROGRAM_ID
-      );
+      const mintKeypair = Keypair.generate();
+      mint = mintKeypair.publicKey;
 
       const client = new SSSTokenClient({ provider });
-      const initTx = await client.initialize(mint, authority, {
+      const initTx = await client.initialize(mintKeypair, authority, {
         name: "Compliance Test Stablecoin",
         symbol: "COMP",
         uri: "https://example.com/compliance.json",
@@ -340,19 +256,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
     let stable: SolanaStablecoin;
 
     before(async () => {
-      mint = await createMint(
-        connection,
-        payer,
-        authority.publicKey,
-        authority.publicKey,
-        6,
-        undefined,
-        undefined,
-        TOKEN_2022_PROGRAM_ID
-      );
+      const mintKeypair = Keypair.generate();
+      mint = mintKeypair.publicKey;
 
       const client = new SSSTokenClient({ provider });
-      const initTx = await client.initialize(mint, authority, {
+      const initTx = await client.initialize(mintKeypair, authority, {
         name: "Minting Test Stablecoin",
         symbol: "MINT",
         uri: "https://example.com/minting.json",
@@ -405,8 +313,7 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
       );
 
       const tx = await stable.minting.mintTokens(
-        authority,
-        minter.publicKey,
+        minter,
         tokenAccount.address,
         new BN(500_000)
       );
@@ -436,19 +343,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
     let stable: SolanaStablecoin;
 
     before(async () => {
-      mint = await createMint(
-        connection,
-        payer,
-        authority.publicKey,
-        authority.publicKey,
-        6,
-        undefined,
-        undefined,
-        TOKEN_2022_PROGRAM_ID
-      );
+      const mintKeypair = Keypair.generate();
+      mint = mintKeypair.publicKey;
 
       const client = new SSSTokenClient({ provider });
-      await client.initialize(mint, authority, {
+      await client.initialize(mintKeypair, authority, {
         name: "Burning Test Stablecoin",
         symbol: "BURN",
         uri: "https://example.com/burning.json",
@@ -482,7 +381,7 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
 
       // Mint some tokens first
       const client = new SSSTokenClient({ provider });
-      await client.mintTokens(mint, authority, minter.publicKey, tokenAccount.address, {
+      await client.mintTokens(mint, minter, tokenAccount.address, {
         amount: new BN(1_000_000),
       });
 
@@ -500,19 +399,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
     let stable: SolanaStablecoin;
 
     before(async () => {
-      mint = await createMint(
-        connection,
-        payer,
-        authority.publicKey,
-        authority.publicKey,
-        6,
-        undefined,
-        undefined,
-        TOKEN_2022_PROGRAM_ID
-      );
+      const mintKeypair = Keypair.generate();
+      mint = mintKeypair.publicKey;
 
       const client = new SSSTokenClient({ provider });
-      await client.initialize(mint, authority, {
+      await client.initialize(mintKeypair, authority, {
         name: "Pause Test Stablecoin",
         symbol: "PAUS",
         uri: "https://example.com/pause.json",
@@ -554,19 +445,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
     let stable: SolanaStablecoin;
 
     before(async () => {
-      mint = await createMint(
-        connection,
-        payer,
-        authority.publicKey,
-        authority.publicKey,
-        6,
-        undefined,
-        undefined,
-        TOKEN_2022_PROGRAM_ID
-      );
+      const mintKeypair = Keypair.generate();
+      mint = mintKeypair.publicKey;
 
       const client = new SSSTokenClient({ provider });
-      const initTx = await client.initialize(mint, authority, {
+      const initTx = await client.initialize(mintKeypair, authority, {
         name: "Authority Test Stablecoin",
         symbol: "AUTH",
         uri: "https://example.com/auth.json",
@@ -614,19 +497,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
 
   describe("SolanaStablecoin.fromClient() Tests", () => {
     it("should create enhanced SDK from existing client", async () => {
-      const mint = await createMint(
-        connection,
-        payer,
-        authority.publicKey,
-        authority.publicKey,
-        6,
-        undefined,
-        undefined,
-        TOKEN_2022_PROGRAM_ID
-      );
+      const mintKeypair = Keypair.generate();
+      const mint = mintKeypair.publicKey;
 
       const client = new SSSTokenClient({ provider });
-      await client.initialize(mint, authority, {
+      await client.initialize(mintKeypair, authority, {
         name: "From Client Test",
         symbol: "FCLT",
         uri: "https://example.com/fromclient.json",
@@ -649,20 +524,12 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
       console.log("Starting enhanced SDK workflow test...");
 
       // Create mint
-      const mint = await createMint(
-        connection,
-        payer,
-        authority.publicKey,
-        authority.publicKey,
-        6,
-        undefined,
-        undefined,
-        TOKEN_2022_PROGRAM_ID
-      );
+      const mintKeypair = Keypair.generate();
+      const mint = mintKeypair.publicKey;
 
       // Initialize using low-level API (create() requires mint keypair signing)
       const client = new SSSTokenClient({ provider });
-      const initTx = await client.initialize(mint, authority, {
+      const initTx = await client.initialize(mintKeypair, authority, {
         name: "Workflow Enhanced",
         symbol: "WFLE",
         uri: "https://example.com/workflow.json",
@@ -705,8 +572,7 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
       );
 
       const mintTokensTx = await stable.minting.mintTokens(
-        authority,
-        minter.publicKey,
+        minter,
         userTokenAccount.address,
         new BN(1_000_000)
       );
@@ -756,22 +622,16 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
     it("should freeze and thaw token account using PDA-based freeze authority", async () => {
       console.log("Starting freeze/thaw PDA test...");
 
-      // 1. Create mint with PDA freeze authority (no permanent delegate needed for just freeze/thaw)
-      console.log("Step 1: Creating mint with PDA freeze authority...");
-      const programId = new PublicKey(SSS_TOKEN_PROGRAM_ID);
-      const freezeMint = await createMintWithPermanentDelegate(
-        connection,
-        payer,
-        authority.publicKey,
-        6,
-        programId
-      );
-      console.log("Mint with PDA freeze authority created:", freezeMint.toString());
+      // 1. Generate the mint; initialize creates it with the PDA freeze authority
+      console.log("Step 1: Generating mint keypair (the program creates the mint)...");
+      const freezeMintKeypair = Keypair.generate();
+      const freezeMint = freezeMintKeypair.publicKey;
+      console.log("Mint address:", freezeMint.toString());
 
       // 2. Initialize the stablecoin config
       console.log("Step 2: Initializing stablecoin config...");
       const client = new SSSTokenClient({ provider });
-      const initTx = await client.initialize(freezeMint, authority, {
+      const initTx = await client.initialize(freezeMintKeypair, authority, {
         name: "Freeze Thaw Test",
         symbol: "FRTH",
         uri: "https://example.com/freezethaw.json",
@@ -832,19 +692,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
   describe("Negative Test Cases - Enhanced SDK", () => {
     describe("Compliance API Negative Cases", () => {
       it("should fail to blacklist when unauthorized", async () => {
-        const mint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const mintKeypair = Keypair.generate();
+        const mint = mintKeypair.publicKey;
 
         const client = new SSSTokenClient({ provider });
-        await client.initialize(mint, authority, {
+        await client.initialize(mintKeypair, authority, {
           name: "Negative Test",
           symbol: "NEG",
           uri: "https://example.com",
@@ -878,19 +730,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
       });
 
       it("should fail to blacklist with reason too long", async () => {
-        const mint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const mintKeypair = Keypair.generate();
+        const mint = mintKeypair.publicKey;
 
         const client = new SSSTokenClient({ provider });
-        await client.initialize(mint, authority, {
+        await client.initialize(mintKeypair, authority, {
           name: "Negative Test",
           symbol: "NEG",
           uri: "https://example.com",
@@ -924,19 +768,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
       });
 
       it("should fail to remove from blacklist when unauthorized", async () => {
-        const mint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const mintKeypair = Keypair.generate();
+        const mint = mintKeypair.publicKey;
 
         const client = new SSSTokenClient({ provider });
-        await client.initialize(mint, authority, {
+        await client.initialize(mintKeypair, authority, {
           name: "Negative Test",
           symbol: "NEG",
           uri: "https://example.com",
@@ -972,19 +808,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
       });
 
       it("should fail to freeze when unauthorized", async () => {
-        const mint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const mintKeypair = Keypair.generate();
+        const mint = mintKeypair.publicKey;
 
         const client = new SSSTokenClient({ provider });
-        await client.initialize(mint, authority, {
+        await client.initialize(mintKeypair, authority, {
           name: "Negative Test",
           symbol: "NEG",
           uri: "https://example.com",
@@ -1018,19 +846,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
       });
 
       it("should fail to thaw when unauthorized", async () => {
-        const mint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const mintKeypair = Keypair.generate();
+        const mint = mintKeypair.publicKey;
 
         const client = new SSSTokenClient({ provider });
-        await client.initialize(mint, authority, {
+        await client.initialize(mintKeypair, authority, {
           name: "Negative Test",
           symbol: "NEG",
           uri: "https://example.com",
@@ -1072,19 +892,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
 
     describe("Minting API Negative Cases", () => {
       it("should fail to add minter when unauthorized", async () => {
-        const mint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const mintKeypair = Keypair.generate();
+        const mint = mintKeypair.publicKey;
 
         const client = new SSSTokenClient({ provider });
-        const initTx = await client.initialize(mint, authority, {
+        const initTx = await client.initialize(mintKeypair, authority, {
           name: "Negative Test",
           symbol: "NEG",
           uri: "https://example.com",
@@ -1113,19 +925,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
       });
 
       it("should fail to update quota when unauthorized", async () => {
-        const mint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const mintKeypair = Keypair.generate();
+        const mint = mintKeypair.publicKey;
 
         const client = new SSSTokenClient({ provider });
-        await client.initialize(mint, authority, {
+        await client.initialize(mintKeypair, authority, {
           name: "Negative Test",
           symbol: "NEG",
           uri: "https://example.com",
@@ -1158,19 +962,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
       });
 
       it("should fail to remove minter when unauthorized", async () => {
-        const mint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const mintKeypair = Keypair.generate();
+        const mint = mintKeypair.publicKey;
 
         const client = new SSSTokenClient({ provider });
-        await client.initialize(mint, authority, {
+        await client.initialize(mintKeypair, authority, {
           name: "Negative Test",
           symbol: "NEG",
           uri: "https://example.com",
@@ -1199,19 +995,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
       });
 
       it("should fail to mint over quota", async () => {
-        const mint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const mintKeypair = Keypair.generate();
+        const mint = mintKeypair.publicKey;
 
         const client = new SSSTokenClient({ provider });
-        await client.initialize(mint, authority, {
+        await client.initialize(mintKeypair, authority, {
           name: "Negative Test",
           symbol: "NEG",
           uri: "https://example.com",
@@ -1243,8 +1031,7 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
         try {
           // Try to mint more than quota
           await stable.minting.mintTokens(
-            authority,
-            minter.publicKey,
+            minter,
             tokenAccount.address,
             new BN(1_000) // More than quota of 100
           );
@@ -1258,19 +1045,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
 
     describe("Pause API Negative Cases", () => {
       it("should fail to pause when unauthorized", async () => {
-        const mint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const mintKeypair = Keypair.generate();
+        const mint = mintKeypair.publicKey;
 
         const client = new SSSTokenClient({ provider });
-        await client.initialize(mint, authority, {
+        await client.initialize(mintKeypair, authority, {
           name: "Negative Test",
           symbol: "NEG",
           uri: "https://example.com",
@@ -1299,19 +1078,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
       });
 
       it("should fail to unpause when unauthorized", async () => {
-        const mint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const mintKeypair = Keypair.generate();
+        const mint = mintKeypair.publicKey;
 
         const client = new SSSTokenClient({ provider });
-        await client.initialize(mint, authority, {
+        await client.initialize(mintKeypair, authority, {
           name: "Negative Test",
           symbol: "NEG",
           uri: "https://example.com",
@@ -1348,19 +1119,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
 
     describe("Authority API Negative Cases", () => {
       it("should fail to transfer authority when unauthorized", async () => {
-        const mint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const mintKeypair = Keypair.generate();
+        const mint = mintKeypair.publicKey;
 
         const client = new SSSTokenClient({ provider });
-        const initTx = await client.initialize(mint, authority, {
+        const initTx = await client.initialize(mintKeypair, authority, {
           name: "Negative Test",
           symbol: "NEG",
           uri: "https://example.com",
@@ -1385,19 +1148,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
       });
 
       it("should fail to update roles when unauthorized", async () => {
-        const mint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const mintKeypair = Keypair.generate();
+        const mint = mintKeypair.publicKey;
 
         const client = new SSSTokenClient({ provider });
-        const initTx = await client.initialize(mint, authority, {
+        const initTx = await client.initialize(mintKeypair, authority, {
           name: "Negative Test",
           symbol: "NEG",
           uri: "https://example.com",
@@ -1427,19 +1182,11 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
 
     describe("Burning API Negative Cases", () => {
       it("should fail to burn more than balance", async () => {
-        const mint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const mintKeypair = Keypair.generate();
+        const mint = mintKeypair.publicKey;
 
         const client = new SSSTokenClient({ provider });
-        await client.initialize(mint, authority, {
+        await client.initialize(mintKeypair, authority, {
           name: "Negative Test",
           symbol: "NEG",
           uri: "https://example.com",
@@ -1469,7 +1216,7 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
         );
 
         // Mint only 100 tokens
-        await stable.minting.mintTokens(authority, minter.publicKey, tokenAccount.address, new BN(100));
+        await stable.minting.mintTokens(minter, tokenAccount.address, new BN(100));
 
         try {
           // Try to burn 1000 tokens
@@ -1511,17 +1258,12 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
     it("should create mint with permanent delegate and seize tokens from frozen account", async () => {
       console.log("Starting seize test with permanent delegate extension...");
 
-      // 1. Create mint with PermanentDelegate extension
-      console.log("Step 1: Creating mint with permanent delegate extension...");
+      // 1. Generate the mint; initialize creates it with the PermanentDelegate extension
+      console.log("Step 1: Generating mint keypair (the program creates the mint)...");
       const programId = new PublicKey(SSS_TOKEN_PROGRAM_ID);
-      const seizeMint = await createMintWithPermanentDelegate(
-        connection,
-        payer,
-        authority.publicKey,  // mint authority (freeze authority is derived as PDA)
-        6,
-        programId
-      );
-      console.log("Mint with permanent delegate created:", seizeMint.toString());
+      const seizeMintKeypair = Keypair.generate();
+      const seizeMint = seizeMintKeypair.publicKey;
+      console.log("Mint address:", seizeMint.toString());
 
       // Verify the permanent delegate is set
       const mintInfo = await getMint(connection, seizeMint, undefined, TOKEN_2022_PROGRAM_ID);
@@ -1533,7 +1275,7 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
       // 2. Initialize the stablecoin config
       console.log("Step 2: Initializing stablecoin config...");
       const client = new SSSTokenClient({ provider });
-      const initTx = await client.initialize(seizeMint, authority, {
+      const initTx = await client.initialize(seizeMintKeypair, authority, {
         name: "Seize Test Stablecoin",
         symbol: "SEIZ",
         uri: "https://example.com/seize.json",
@@ -1575,7 +1317,7 @@ describe("SolanaStablecoin Enhanced SDK Tests", function () {
       );
       console.log("User token account created:", userTokenAccount.address.toString());
 
-      const mintTokensTx = await client.mintTokens(seizeMint, authority, minter.publicKey, userTokenAccount.address, {
+      const mintTokensTx = await client.mintTokens(seizeMint, minter, userTokenAccount.address, {
         amount: new BN(1_000_000),
       });
       await connection.confirmTransaction(mintTokensTx, "confirmed");
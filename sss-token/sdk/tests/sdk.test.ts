@@ -6,7 +6,6 @@
 import { expect } from "chai";
 import { Keypair, PublicKey, Connection, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import {
-  createMint,
   getAccount,
   getMint,
   getOrCreateAssociatedTokenAccount,
@@ -73,20 +72,12 @@ describe("SSS Token SDK Tests", function () {
 
     // Create token mint using Token-2022 program with freeze authority
     console.log("Creating token mint...");
-    mint = await createMint(
-      connection,
-      payer,
-      authority.publicKey,
-      authority.publicKey, // freeze authority
-      6,
-      undefined,
-      undefined,
-      TOKEN_2022_PROGRAM_ID
-    );
+    const mintKeypair = Keypair.generate();
+    mint = mintKeypair.publicKey;
     console.log("Mint created:", mint.toString());
 
     // Initialize the shared mint for use by other tests
-    const initTx = await sdk.initialize(mint, authority, {
+    const initTx = await sdk.initialize(mintKeypair, authority, {
       name: "Test Stablecoin",
       symbol: "TST",
       uri: "https://example.com/metadata.json",
@@ -110,21 +101,13 @@ describe("SSS Token SDK Tests", function () {
   describe("test_initialize_sss1_minimal_stablecoin", () => {
     it("should initialize a new stablecoin with minimal configuration", async () => {
       // Create a fresh mint for this test
-      const testMint = await createMint(
-        connection,
-        payer,
-        authority.publicKey,
-        authority.publicKey,
-        6,
-        undefined,
-        undefined,
-        TOKEN_2022_PROGRAM_ID
-      );
+      const testMintKeypair = Keypair.generate();
+      const testMint = testMintKeypair.publicKey;
 
       const configPDA = findConfigPDA(testMint);
       console.log("Config PDA:", configPDA.pda.toString());
 
-      const tx = await sdk.initialize(testMint, authority, {
+      const tx = await sdk.initialize(testMintKeypair, authority, {
         name: "Test Stablecoin",
         symbol: "TST",
         uri: "https://example.com/metadata.json",
@@ -286,8 +269,7 @@ describe("SSS Token SDK Tests", function () {
 
       const tx = await sdk.mintTokens(
         mint,
-        authority,
-        minter.publicKey,
+        minter,
         userTokenAccount,
         { amount }
       );
@@ -322,8 +304,7 @@ describe("SSS Token SDK Tests", function () {
       try {
         await sdk.mintTokens(
           mint,
-          authority,
-          minter.publicKey,
+          minter,
           userTokenAccount,
           { amount }
         );
@@ -353,8 +334,7 @@ describe("SSS Token SDK Tests", function () {
       // First, mint some tokens
       const mintTx = await sdk.mintTokens(
         mint,
-        authority,
-        minter.publicKey,
+        minter,
         userTokenAccount,
         { amount: new BN(1_000_000) }
       );
@@ -543,8 +523,7 @@ describe("SSS Token SDK Tests", function () {
       // Mint tokens to source
       const mintTx = await sdk.mintTokens(
         mint,
-        authority,
-        minter.publicKey,
+        minter,
         sourceTokenAccount,
         { amount: new BN(1_000_000) }
       );
@@ -580,21 +559,13 @@ describe("SSS Token SDK Tests", function () {
   describe("Negative Test Cases", () => {
     describe("Initialization Validation", () => {
       it("should fail to initialize with name too long", async () => {
-        const testMint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const testMintKeypair = Keypair.generate();
+        const testMint = testMintKeypair.publicKey;
 
         const longName = "x".repeat(101); // Max 100 chars
 
         try {
-          await sdk.initialize(testMint, authority, {
+          await sdk.initialize(testMintKeypair, authority, {
             name: longName,
             symbol: "TEST",
             uri: "https://example.com",
@@ -611,21 +582,13 @@ describe("SSS Token SDK Tests", function () {
       });
 
       it("should fail to initialize with symbol too long", async () => {
-        const testMint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const testMintKeypair = Keypair.generate();
+        const testMint = testMintKeypair.publicKey;
 
         const longSymbol = "x".repeat(11); // Max 10 chars
 
         try {
-          await sdk.initialize(testMint, authority, {
+          await sdk.initialize(testMintKeypair, authority, {
             name: "Test Token",
             symbol: longSymbol,
             uri: "https://example.com",
@@ -642,21 +605,13 @@ describe("SSS Token SDK Tests", function () {
       });
 
       it("should fail to initialize with URI too long", async () => {
-        const testMint = await createMint(
-          connection,
-          payer,
-          authority.publicKey,
-          authority.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const testMintKeypair = Keypair.generate();
+        const testMint = testMintKeypair.publicKey;
 
         const longUri = "https://example.com/" + "x".repeat(200); // Max 200 chars total
 
         try {
-          await sdk.initialize(testMint, authority, {
+          await sdk.initialize(testMintKeypair, authority, {
             name: "Test Token",
             symbol: "TEST",
             uri: longUri,
@@ -875,8 +830,7 @@ describe("SSS Token SDK Tests", function () {
         try {
           await sdk.mintTokens(
             mint,
-            authority,
-            minter.publicKey,
+            minter,
             tokenAccount.address,
             { amount: new BN(100) }
           );
@@ -908,8 +862,7 @@ describe("SSS Token SDK Tests", function () {
         // Mint some tokens first
         await sdk.mintTokens(
           mint,
-          authority,
-          minter.publicKey,
+          minter,
           tokenAccount.address,
           { amount: new BN(1_000) }
         );
@@ -947,8 +900,7 @@ describe("SSS Token SDK Tests", function () {
         // Mint only 100 tokens
         await sdk.mintTokens(
           mint,
-          authority,
-          minter.publicKey,
+          minter,
           tokenAccount.address,
           { amount: new BN(100) }
         );
@@ -1108,18 +1060,10 @@ describe("SSS Token SDK Tests", function () {
 
       // 1. Initialize stablecoin with SSS-2 features
       console.log("Step 1: Initialize stablecoin");
-      const workflowMint = await createMint(
-        connection,
-        payer,
-        authority.publicKey,
-        authority.publicKey,  // freeze authority
-        6,
-        undefined,
-        undefined,
-        TOKEN_2022_PROGRAM_ID
-      );
+      const workflowMintKeypair = Keypair.generate();
+      const workflowMint = workflowMintKeypair.publicKey;
 
-      const initTx = await sdk.initialize(workflowMint, authority, {
+      const initTx = await sdk.initialize(workflowMintKeypair, authority, {
         name: "Workflow Stablecoin",
         symbol: "WORK",
         uri: "https://example.com/workflow.json",
@@ -1164,8 +1108,7 @@ describe("SSS Token SDK Tests", function () {
 
       const mintTx = await sdk.mintTokens(
         workflowMint,
-        authority,
-        minter.publicKey,
+        minter,
         userTokenAccount,
         { amount: new BN(1_000_000) }
       );
@@ -22,20 +22,12 @@ import {
   Connection,
   LAMPORTS_PER_SOL,
   TransactionSignature,
-  Transaction,
-  SystemProgram,
-  sendAndConfirmTransaction,
 } from "@solana/web3.js";
 import {
-  createMint,
   getAccount,
   getMint,
   getOrCreateAssociatedTokenAccount,
   TOKEN_2022_PROGRAM_ID,
-  ExtensionType,
-  getMintLen,
-  createInitializePermanentDelegateInstruction,
-  createInitializeMintInstruction,
 } from "@solana/spl-token";
 import { Program, AnchorProvider, Wallet, Idl } from "@coral-xyz/anchor";
 import BN from "bn.js";
@@ -43,78 +35,8 @@ import {
   SSSTokenClient,
   SSS_TOKEN_PROGRAM_ID,
   findConfigPDA,
-  findPermanentDelegatePDA,
-  findFreezeAuthorityPDA,
 } from "../sdk/src/index";
 
-/**
- * Helper function to create a Token-2022 mint with the PermanentDelegate extension
- * This allows the program's permanent delegate PDA to transfer tokens from any account
- * 
- * IMPORTANT: The freeze authority is set to the program's freeze authority PDA,
- * which allows the seize instruction to thaw frozen accounts using PDA signing.
- */
-async function createMintWithPermanentDelegate(
-  connection: Connection,
-  payer: Keypair,
-  mintAuthority: PublicKey,
-  decimals: number,
-  programId: PublicKey
-): Promise<PublicKey> {
-  // Generate mint keypair first to derive PDAs
-  const mintKeypair = Keypair.generate();
-  
-  // Derive both PDAs from the mint address
-  const { pda: permanentDelegate } = findPermanentDelegatePDA(mintKeypair.publicKey, programId);
-  const { pda: freezeAuthority } = findFreezeAuthorityPDA(mintKeypair.publicKey, programId);
-
-  // Calculate mint size with PermanentDelegate extension
-  const extensions = [ExtensionType.PermanentDelegate];
-  const mintLen = getMintLen(extensions);
-
-  // Calculate minimum balance for rent exemption
-  const lamports = await connection.getMinimumBalanceForRentExemption(mintLen);
-
-  // Build transaction to create mint with extension
-  const transaction = new Transaction().add(
-    SystemProgram.createAccount({
-      fromPubkey: payer.publicKey,
-      newAccountPubkey: mintKeypair.publicKey,
-      space: mintLen,
-      lamports,
-      programId: TOKEN_2022_PROGRAM_ID,
-    }),
-    createInitializePermanentDelegateInstruction(
-      mintKeypair.publicKey,
-      permanentDelegate, // Our program's PDA as the permanent delegate
-      TOKEN_2022_PROGRAM_ID
-    ),
-    createInitializeMintInstruction(
-      mintKeypair.publicKey,
-      decimals,
-      mintAuthority,
-      freezeAuthority, // Program's PDA as freeze authority (for seize operation)
-      TOKEN_2022_PROGRAM_ID
-    )
-  );
-
-  await sendAndConfirmTransaction(connection, transaction, [payer, mintKeypair], {
-    commitment: "confirmed",
-  });
-
-  console.log("Created mint with permanent delegate:", mintKeypair.publicKey.toString());
-  console.log("  Permanent delegate PDA:", permanentDelegate.toString());
-  console.log("  Freeze authority PDA:", freezeAuthority.toString());
-  
-  return mintKeypair.publicKey;
-}
-
-// NOTE: For seizure to work properly, the mint must be created with the Token-2022
-// PermanentDelegate extension initialized with our program's permanent delegate PDA.
-// The standard createMint function doesn't support extensions, so seizure tests
-// will fail unless the mint is created with the proper extension.
-// See: https://spl.solana.com/token-2022/extensions#permanent-delegate
-
 // Proof documentation interface
 interface ProofEntry {
   test: string;
@@ -339,22 +261,14 @@ describe("SSS Token Devnet Tests", function () {
       const testName = "Initialize SSS-2 Stablecoin";
 
       try {
-        // Create Token-2022 mint with required extensions
-        mint = await createMint(
-          connection,
-          payer,
-          payer.publicKey, // mint authority
-          payer.publicKey, // freeze authority
-          6, // decimals
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        // The program creates the Token-2022 mint with its extensions during initialize
+        const mintKeypair = Keypair.generate();
+        mint = mintKeypair.publicKey;
 
-        console.log(`\n📝 Mint created: ${mint.toString()}`);
+        console.log(`\n📝 Mint address: ${mint.toString()}`);
 
         // Initialize stablecoin
-        const tx = await sdk.initialize(mint, payer, {
+        const tx = await sdk.initialize(mintKeypair, payer, {
           name: "Devnet USD",
           symbol: "DUSD",
           uri: "https://example.com/dusd.json",
@@ -500,8 +414,7 @@ describe("SSS Token Devnet Tests", function () {
 
         const tx = await sdk.mintTokens(
           mint,
-          payer,
-          minter.publicKey,
+          minter,
           payerTokenAccount.address,
           { amount }
         );
@@ -809,23 +722,15 @@ describe("SSS Token Devnet Tests", function () {
       const testName = "Seize Tokens";
 
       try {
-        // Create a special mint with PermanentDelegate extension for seizure
-        // This is required because seize needs:
-        // 1. Permanent delegate to transfer tokens from any account
-        // 2. PDA-based freeze authority to thaw during seize
+        // Seize needs the PermanentDelegate extension and the PDA freeze authority,
+        // both of which initialize sets up on the mint it creates
         console.log("\n   Creating mint with permanent delegate extension...");
-        const programId = new PublicKey(SSS_TOKEN_PROGRAM_ID);
-        const seizeMint = await createMintWithPermanentDelegate(
-          connection,
-          payer,
-          payer.publicKey, // mint authority
-          6,
-          programId
-        );
-        console.log(`   Seize mint created: ${seizeMint.toString()}`);
+        const seizeMintKeypair = Keypair.generate();
+        const seizeMint = seizeMintKeypair.publicKey;
+        console.log(`   Seize mint address: ${seizeMint.toString()}`);
 
         // Initialize stablecoin config for the seize mint
-        const initTx = await sdk.initialize(seizeMint, payer, {
+        const initTx = await sdk.initialize(seizeMintKeypair, payer, {
           name: "Seize Test USD",
           symbol: "SUSD",
           uri: "https://example.com/susd.json",
@@ -869,8 +774,7 @@ describe("SSS Token Devnet Tests", function () {
         // Mint some tokens to this account
         await sdk.mintTokens(
           seizeMint,
-          payer,
-          seizeMinter.publicKey,
+          seizeMinter,
           seizeUserTokenAccount.address,
           { amount: new BN(1_000_000) }
         );
@@ -1079,19 +983,11 @@ describe("SSS Token Devnet Tests", function () {
         const signatures: string[] = [];
 
         // Create new mint for workflow
-        const workflowMint = await createMint(
-          connection,
-          payer,
-          payer.publicKey,
-          payer.publicKey,
-          6,
-          undefined,
-          undefined,
-          TOKEN_2022_PROGRAM_ID
-        );
+        const workflowMintKeypair = Keypair.generate();
+        const workflowMint = workflowMintKeypair.publicKey;
 
         // 1. Initialize
-        const initTx = await sdk.initialize(workflowMint, payer, {
+        const initTx = await sdk.initialize(workflowMintKeypair, payer, {
           name: "Workflow Test Coin",
           symbol: "WTC",
           uri: "https://example.com/wtc.json",
@@ -1139,8 +1035,7 @@ describe("SSS Token Devnet Tests", function () {
 
         const mintTx = await sdk.mintTokens(
           workflowMint,
-          payer,
-          workflowMinter.publicKey,
+          workflowMinter,
           userTokenAccount.address,
           { amount: new BN(10_000_000) }
         );